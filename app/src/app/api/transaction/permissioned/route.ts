@@ -1,7 +1,7 @@
 import { VersionedTransaction } from '@solana/web3.js';
 import { NextRequest, NextResponse } from 'next/server';
 import {
-  ADMIN_KEYPAIR,
+  OPERATOR_KEYPAIR,
   CONNECTION,
   sendTx,
   validateProgramIx,
@@ -32,7 +32,7 @@ export async function POST(req: NextRequest) {
       );
     }
 
-    tx.sign([ADMIN_KEYPAIR]);
+    tx.sign([OPERATOR_KEYPAIR]);
 
     const res = await sendTx(v0TxToBase64(tx));
 
@@ -130,6 +130,8 @@ export class SplurgeClient extends ProgramClient<Splurge> {
 
   async listItemIx({
     price,
+    pricingMode,
+    referralBps,
     inventoryCount,
     name,
     image,
@@ -141,6 +143,8 @@ export class SplurgeClient extends ProgramClient<Splurge> {
     return await this.program.methods
       .listItem({
         price,
+        pricingMode,
+        referralBps,
         inventoryCount,
         name,
         image,
@@ -154,6 +158,8 @@ export class SplurgeClient extends ProgramClient<Splurge> {
 
   async updateItemIx({
     price,
+    pricingMode,
+    referralBps,
     inventoryCount,
     authority,
     itemPda,
@@ -166,6 +172,8 @@ export class SplurgeClient extends ProgramClient<Splurge> {
     return await this.program.methods
       .updateItem({
         price,
+        pricingMode,
+        referralBps,
         inventoryCount,
       })
       .accountsPartial({
@@ -195,41 +203,79 @@ export class SplurgeClient extends ProgramClient<Splurge> {
       .instruction();
   }
 
+  async getNextTask(
+    tuktukProgram: Program<Tuktuk>
+  ): Promise<{ taskId: number; taskPda: PublicKey }> {
+    const taskQueueAcc =
+      await tuktukProgram.account.taskQueueV0.fetchNullable(TASK_QUEUE);
+    if (!taskQueueAcc) throw new Error('Task queue not found.');
+    const taskId = nextAvailableTaskIds(taskQueueAcc.taskBitmap, 1, false)[0];
+    const [taskPda] = taskKey(TASK_QUEUE, taskId, tuktukProgram.programId);
+
+    return { taskId, taskPda };
+  }
+
   async createOrderIx({
     amount,
+    maxPayment,
     authority,
     storePda,
     itemPda,
     priceUpdateV2,
+    referrer,
     paymentMint,
     tokenProgram,
+    shippingDeadlineSecs,
+    tuktukProgram,
   }: {
     amount: number;
+    maxPayment: BN;
     authority: Address;
     storePda: Address;
     itemPda: Address;
-    priceUpdateV2: Address;
+    priceUpdateV2: Address | null;
+    referrer?: Address;
     paymentMint: Address;
     tokenProgram: Address;
+    shippingDeadlineSecs: number;
+    tuktukProgram: Program<Tuktuk>;
   }): Promise<TransactionInstruction> {
     // timestamp is deducted by 1 second to provide an approximation buffer for clock drift
     const timestamp = Math.floor(Date.now() / 1000 - 1);
 
+    // expiry task is only queued if the config sets a shipping deadline
+    const expiryTask =
+      shippingDeadlineSecs > 0 ? await this.getNextTask(tuktukProgram) : null;
+    const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
+      TASK_QUEUE,
+      SplurgeClient.configPda
+    );
+
     return await this.program.methods
-      .createOrder(amount, new BN(timestamp))
+      .createOrder(
+        amount,
+        new BN(timestamp),
+        maxPayment,
+        expiryTask ? expiryTask.taskId : null
+      )
       .accountsPartial({
         authority,
         store: storePda,
         item: itemPda,
         priceUpdateV2,
+        referrer: referrer ?? null,
         paymentMint,
         tokenProgram,
+        tuktuk: expiryTask ? tuktukProgram.programId : null,
+        taskQueue: expiryTask ? TASK_QUEUE : null,
+        task: expiryTask ? expiryTask.taskPda : null,
+        taskQueueAuthority: expiryTask ? taskQueueAuthorityPda : null,
       })
       .instruction();
   }
 
   async shipOrderIx({
-    admin,
+    shipper,
     orderPda,
     authority,
     itemPda,
@@ -239,7 +285,7 @@ export class SplurgeClient extends ProgramClient<Splurge> {
     tokenProgram,
     tuktukProgram,
   }: {
-    admin: PublicKey;
+    shipper: PublicKey;
     orderPda: PublicKey;
     authority: Address;
     itemPda: Address;
@@ -261,17 +307,16 @@ export class SplurgeClient extends ProgramClient<Splurge> {
       true,
       tokenProgram
     );
-    const taskQueueAcc =
-      await tuktukProgram.account.taskQueueV0.fetchNullable(TASK_QUEUE);
-    if (!taskQueueAcc) throw new Error('Task queue not found.');
-    const taskId = nextAvailableTaskIds(taskQueueAcc.taskBitmap, 1, false)[0];
-    const [taskPda] = taskKey(TASK_QUEUE, taskId, tuktukProgram.programId);
-    const [taskQueueAuthorityPda] = taskQueueAuthorityKey(TASK_QUEUE, admin);
+    const { taskId, taskPda } = await this.getNextTask(tuktukProgram);
+    const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
+      TASK_QUEUE,
+      SplurgeClient.configPda
+    );
 
     return await this.program.methods
       .shipOrder(taskId)
       .accountsPartial({
-        admin,
+        shipper,
         order: orderPda,
         authority,
         item: itemPda,
@@ -290,14 +335,16 @@ export class SplurgeClient extends ProgramClient<Splurge> {
   }
 
   async cancelOrderIx({
-    admin,
+    operator,
     orderPda,
+    itemPda,
     paymentMint,
     shopperPda,
     tokenProgram,
   }: {
-    admin: Address;
+    operator: Address;
     orderPda: Address;
+    itemPda: Address;
     paymentMint: Address;
     shopperPda: Address;
     tokenProgram: Address;
@@ -305,8 +352,9 @@ export class SplurgeClient extends ProgramClient<Splurge> {
     return this.program.methods
       .cancelOrder()
       .accountsPartial({
-        admin,
+        operator,
         order: orderPda,
+        item: itemPda,
         paymentMint,
         shopper: shopperPda,
         tokenProgram,
@@ -339,22 +387,27 @@ export class SplurgeClient extends ProgramClient<Splurge> {
   }
 
   async withdrawEarningsIx({
+    amount,
     authority,
+    destination,
     storePda,
     paymentMint,
     tokenProgram,
   }: {
+    amount: BN | null;
     authority: Address;
+    destination: Address;
     storePda: Address;
     paymentMint: Address;
     tokenProgram: Address;
   }): Promise<TransactionInstruction> {
     return await this.program.methods
-      .withdrawEarnings()
+      .withdrawEarnings(amount)
       .accountsPartial({
         authority,
         store: storePda,
         paymentMint,
+        destinationTokenAccount: destination,
         tokenProgram,
       })
       .instruction();
@@ -19,6 +19,10 @@ import { atomicToUsd } from '@/lib/utils';
 import { useEarnings } from '@/providers/EarningsProvider';
 import { useConnection, useUnifiedWallet } from '@jup-ag/wallet-adapter';
 import { PublicKey } from '@solana/web3.js';
+import {
+  createAssociatedTokenAccountIdempotentInstruction,
+  getAssociatedTokenAddressSync,
+} from '@solana/spl-token';
 import { HandCoins } from 'lucide-react';
 import { useCallback, useMemo, useState } from 'react';
 import { toast } from 'sonner';
@@ -83,24 +87,46 @@ export function EarningsSection({ prices }: { prices: Price[] }) {
 
         let tx = await buildTx(
           connection,
-          await Promise.all(
-            earningsData
-              .filter(({ amount }) => amount > 0)
-              .map(async ({ mint }) => {
-                const metadata = ACCEPTED_MINTS_METADATA.get(mint);
-
-                if (!metadata) {
-                  throw new Error(`Metadata not found for mint: ${mint}`);
-                }
-
-                return await SPLURGE_CLIENT.withdrawEarningsIx({
-                  authority: publicKey,
-                  paymentMint: new PublicKey(mint),
-                  storePda: SplurgeClient.getStorePda(publicKey),
-                  tokenProgram: metadata.owner,
-                });
-              })
-          ),
+          (
+            await Promise.all(
+              earningsData
+                .filter(({ amount }) => amount > 0)
+                .map(async ({ mint }) => {
+                  const metadata = ACCEPTED_MINTS_METADATA.get(mint);
+
+                  if (!metadata) {
+                    throw new Error(`Metadata not found for mint: ${mint}`);
+                  }
+
+                  const paymentMint = new PublicKey(mint);
+                  const destination = getAssociatedTokenAddressSync(
+                    paymentMint,
+                    publicKey,
+                    false,
+                    metadata.owner
+                  );
+
+                  return [
+                    createAssociatedTokenAccountIdempotentInstruction(
+                      publicKey,
+                      destination,
+                      publicKey,
+                      paymentMint,
+                      metadata.owner
+                    ),
+                    // amount is omitted to withdraw the full balance
+                    await SPLURGE_CLIENT.withdrawEarningsIx({
+                      amount: null,
+                      authority: publicKey,
+                      destination,
+                      paymentMint,
+                      storePda: SplurgeClient.getStorePda(publicKey),
+                      tokenProgram: metadata.owner,
+                    }),
+                  ];
+                })
+            )
+          ).flat(),
           publicKey,
           [],
           priorityFee
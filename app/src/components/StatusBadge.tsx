@@ -11,6 +11,8 @@ const badgeVariants = cva('flex w-fit items-center gap-2', {
       shipping: 'bg-shipping hover:bg-shipping',
       completed: 'bg-completed hover:bg-completed',
       cancelled: 'bg-cancelled hover:bg-cancelled',
+      disputed: 'bg-cancelled hover:bg-cancelled',
+      resolved: 'bg-completed hover:bg-completed',
     },
   },
 });
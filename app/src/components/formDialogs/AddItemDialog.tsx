@@ -98,6 +98,8 @@ export function AddItemDialog({ storePda }: { storePda: string }) {
                   [
                     await SPLURGE_CLIENT.listItemIx({
                       price: new BN(data.price * 10 ** MINT_DECIMALS),
+                      pricingMode: { oracle: {} },
+                      referralBps: 0,
                       inventoryCount: data.inventoryCount,
                       name: data.name,
                       image: imageUri,
@@ -6,9 +6,10 @@ import { useConnection } from '@solana/wallet-adapter-react';
 import { ReactNode, useCallback, useState } from 'react';
 import { useForm, useWatch } from 'react-hook-form';
 import { TransactionToast } from '../TransactionToast';
-import { buildTx, SPLURGE_CLIENT } from '@/lib/client/solana';
+import { buildTx, SPLURGE_CLIENT, TUKTUK_CLIENT } from '@/lib/client/solana';
 import { toast } from 'sonner';
 import { PublicKey } from '@solana/web3.js';
+import { BN } from '@coral-xyz/anchor';
 import { Dialog, DialogHeader, DialogTrigger } from '../ui/dialog';
 import { WalletGuardButton } from '../WalletGuardButton';
 import {
@@ -29,7 +30,11 @@ import {
   SelectValue,
 } from '../ui/select';
 import { z } from 'zod';
-import { ACCEPTED_MINTS_METADATA } from '@/lib/constants';
+import {
+  ACCEPTED_MINTS_METADATA,
+  MAX_PAYMENT_SLIPPAGE_BPS,
+  PAUSE_ORDERING,
+} from '@/lib/constants';
 import { useShopper } from '@/providers/ShopperProvider';
 import { atomicToUsd, removeTrailingZeroes } from '@/lib/utils';
 import { MAX_FEE_BASIS_POINTS } from '@solana/spl-token';
@@ -119,7 +124,7 @@ export function CheckoutDialog({
             throw new Error('Shopper account not created.');
           }
 
-          if (config.isPaused) {
+          if (config.pausedOperations & PAUSE_ORDERING) {
             throw new Error(
               'Platform is currently paused. No new orders can be created.'
             );
@@ -141,12 +146,21 @@ export function CheckoutDialog({
                 [
                   await SPLURGE_CLIENT.createOrderIx({
                     amount: data.amount,
+                    maxPayment: new BN(
+                      Math.ceil(
+                        ((orderSubtotal + platformFee) *
+                          (MAX_FEE_BASIS_POINTS + MAX_PAYMENT_SLIPPAGE_BPS)) /
+                          MAX_FEE_BASIS_POINTS
+                      )
+                    ),
                     authority: publicKey,
                     storePda: new PublicKey(store.publicKey),
                     itemPda: new PublicKey(item.publicKey),
                     priceUpdateV2: token.priceUpdateV2,
                     paymentMint: new PublicKey(data.paymentMint),
                     tokenProgram: token.owner,
+                    shippingDeadlineSecs: config.shippingDeadlineSecs,
+                    tuktukProgram: TUKTUK_CLIENT.program,
                   }),
                 ],
                 publicKey,
@@ -237,6 +251,7 @@ export function CheckoutDialog({
       pythSolanaReceiver,
       getUpdatePriceFeedTx,
       orderSubtotal,
+      platformFee,
       closeAndReset,
       getTransactionLink,
       priorityFee,
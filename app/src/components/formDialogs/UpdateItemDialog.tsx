@@ -71,6 +71,8 @@ export function UpdateItemDialog({
             [
               await SPLURGE_CLIENT.updateItemIx({
                 price: new BN(Number(data.price.toFixed(2))),
+                pricingMode: null,
+                referralBps: null,
                 inventoryCount: data.inventoryCount,
                 authority: publicKey,
                 itemPda: new PublicKey(item.publicKey),
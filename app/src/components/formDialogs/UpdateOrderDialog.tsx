@@ -73,7 +73,7 @@ export function UpdateOrderDialog({
 
           setIsSubmitting(true);
 
-          const admin = new PublicKey(config.admin);
+          const operator = new PublicKey(config.operator);
           const authorityPubkey = new PublicKey(shopper.authority);
           const orderPdaPubkey = new PublicKey(order.publicKey);
           const shopperPda = SplurgeClient.getShopperPda(authorityPubkey);
@@ -92,7 +92,7 @@ export function UpdateOrderDialog({
             [
               status === 'shipping'
                 ? await SPLURGE_CLIENT.shipOrderIx({
-                    admin,
+                    shipper: operator,
                     orderPda: orderPdaPubkey,
                     authority: authorityPubkey,
                     itemPda: new PublicKey(item.publicKey),
@@ -103,14 +103,15 @@ export function UpdateOrderDialog({
                     tuktukProgram: TUKTUK_CLIENT.program,
                   })
                 : await SPLURGE_CLIENT.cancelOrderIx({
-                    admin,
+                    operator,
                     orderPda: orderPdaPubkey,
+                    itemPda: new PublicKey(item.publicKey),
                     paymentMint: paymentMintPubkey,
                     shopperPda,
                     tokenProgram,
                   }),
             ],
-            admin,
+            operator,
             [],
             priorityFee
           );
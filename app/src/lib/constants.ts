@@ -50,4 +50,9 @@ export const ACCEPTED_MINTS_METADATA = new Map<
 
 export const DISCRIMINATOR_SIZE = 8;
 
+export const PAUSE_ORDERING = 1;
+
+// Buffer on the quoted payment for oracle price movement before the order lands
+export const MAX_PAYMENT_SLIPPAGE_BPS = 100;
+
 export const MINT_DECIMALS = 6;
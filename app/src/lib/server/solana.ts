@@ -20,8 +20,8 @@ export const CONNECTION = new Connection(
 );
 export const SPLURGE_CLIENT = new SplurgeClient(CONNECTION);
 
-export const ADMIN_KEYPAIR = Keypair.fromSecretKey(
-  new Uint8Array(JSON.parse(process.env.ADMIN_KEYPAIR as string))
+export const OPERATOR_KEYPAIR = Keypair.fromSecretKey(
+  new Uint8Array(JSON.parse(process.env.OPERATOR_KEYPAIR as string))
 );
 
 export async function validateProgramIx(
@@ -25,14 +25,16 @@ export interface ParsedProgramAccount {
 
 interface ParsedAcceptedMint {
   mint: string;
-  priceUpdateV2: string;
+  isPaused: boolean;
 }
 
 export interface ParsedConfig extends ParsedProgramAccount {
   orderFeeBps: number;
   admin: string;
-  isPaused: boolean;
+  operator: string;
+  pausedOperations: number;
   acceptedMints: ParsedAcceptedMint[];
+  shippingDeadlineSecs: number;
 }
 
 export interface ParsedShopper extends ParsedProgramAccount {
@@ -95,21 +97,25 @@ function parseAcceptedMints(
 ): ParsedAcceptedMint[] {
   return acceptedMints.map((mint) => ({
     mint: parsePublicKey(mint.mint),
-    priceUpdateV2: parsePublicKey(mint.priceUpdateV2),
+    isPaused: mint.isPaused,
   }));
 }
 
 export function parseConfig({
   admin,
-  isPaused,
+  operator,
+  pausedOperations,
   orderFeeBps,
   acceptedMints,
+  shippingDeadlineSecs,
 }: Config): Omit<ParsedConfig, 'publicKey'> {
   return {
     admin: parsePublicKey(admin),
-    isPaused,
+    operator: parsePublicKey(operator),
+    pausedOperations,
     orderFeeBps,
     acceptedMints: parseAcceptedMints(acceptedMints),
+    shippingDeadlineSecs,
   };
 }
 
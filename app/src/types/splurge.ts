@@ -14,20 +14,101 @@ export type Splurge = {
   },
   "instructions": [
     {
-      "name": "cancelOrder",
+      "name": "acceptAdmin",
       "discriminator": [
-        95,
-        129,
-        237,
+        112,
+        42,
+        45,
+        90,
+        116,
+        181,
+        13,
+        170
+      ],
+      "accounts": [
+        {
+          "name": "pendingAdmin",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelAdminTransfer",
+      "discriminator": [
+        38,
+        131,
+        157,
+        31,
         240,
-        8,
-        49,
-        223,
-        132
+        137,
+        44,
+        215
       ],
       "accounts": [
         {
           "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelCartOrder",
+      "discriminator": [
+        247,
+        59,
+        181,
+        173,
+        87,
+        21,
+        195,
+        193
+      ],
+      "accounts": [
+        {
+          "name": "operator",
           "writable": true,
           "signer": true,
           "relations": [
@@ -83,13 +164,18 @@ export type Splurge = {
           "name": "shopper"
         },
         {
-          "name": "order",
+          "name": "cartOrder",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  99,
+                  97,
+                  114,
+                  116,
+                  95,
                   111,
                   114,
                   100,
@@ -103,13 +189,8 @@ export type Splurge = {
               },
               {
                 "kind": "account",
-                "path": "order.item",
-                "account": "order"
-              },
-              {
-                "kind": "account",
-                "path": "order.timestamp",
-                "account": "order"
+                "path": "cart_order.timestamp",
+                "account": "cartOrder"
               }
             ]
           }
@@ -175,13 +256,13 @@ export type Splurge = {
           }
         },
         {
-          "name": "orderTokenAccount",
+          "name": "cartOrderTokenAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "order"
+                "path": "cartOrder"
               },
               {
                 "kind": "account",
@@ -303,26 +384,46 @@ export type Splurge = {
       "args": []
     },
     {
-      "name": "completeOrder",
+      "name": "cancelOrder",
       "discriminator": [
-        73,
-        78,
-        89,
-        7,
-        140,
-        132,
-        17,
-        97
+        95,
+        129,
+        237,
+        240,
+        8,
+        49,
+        223,
+        132
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "operator",
           "writable": true,
           "signer": true,
           "relations": [
             "config"
           ]
         },
+        {
+          "name": "treasury",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "authority",
           "writable": true,
@@ -352,32 +453,8 @@ export type Splurge = {
           "name": "shopper"
         },
         {
-          "name": "store",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  115,
-                  116,
-                  111,
-                  114,
-                  101
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "store.authority",
-                "account": "store"
-              }
-            ]
-          },
-          "relations": [
-            "item"
-          ]
-        },
-        {
-          "name": "item"
+          "name": "item",
+          "writable": true
         },
         {
           "name": "order",
@@ -411,10 +488,64 @@ export type Splurge = {
           }
         },
         {
-          "name": "paymentMint",
-          "relations": [
-            "order"
-          ]
+          "name": "paymentMint"
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
         },
         {
           "name": "orderTokenAccount",
@@ -474,13 +605,13 @@ export type Splurge = {
           }
         },
         {
-          "name": "storeTokenAccount",
+          "name": "authorityTokenAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "store"
+                "path": "authority"
               },
               {
                 "kind": "account",
@@ -545,16 +676,16 @@ export type Splurge = {
       "args": []
     },
     {
-      "name": "createOrder",
+      "name": "closeCartOrder",
       "discriminator": [
-        141,
-        54,
-        37,
-        207,
-        237,
-        210,
-        250,
-        215
+        70,
+        144,
+        197,
+        39,
+        148,
+        135,
+        126,
+        163
       ],
       "accounts": [
         {
@@ -563,42 +694,80 @@ export type Splurge = {
           "signer": true
         },
         {
-          "name": "treasury",
+          "name": "shopper",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  116,
-                  114,
-                  101,
-                  97,
                   115,
-                  117,
-                  114,
-                  121
+                  104,
+                  111,
+                  112,
+                  112,
+                  101,
+                  114
                 ]
-              }
-            ]
-          }
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
         },
         {
-          "name": "config",
+          "name": "cartOrder",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
                   99,
+                  97,
+                  114,
+                  116,
+                  95,
                   111,
-                  110,
-                  102,
-                  105,
-                  103
+                  114,
+                  100,
+                  101,
+                  114
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "shopper"
+              },
+              {
+                "kind": "account",
+                "path": "cart_order.timestamp",
+                "account": "cartOrder"
               }
             ]
           }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeOrder",
+      "discriminator": [
+        90,
+        103,
+        209,
+        28,
+        7,
+        63,
+        168,
+        4
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
         },
         {
           "name": "shopper",
@@ -624,42 +793,102 @@ export type Splurge = {
           }
         },
         {
-          "name": "store",
+          "name": "order",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  115,
-                  116,
                   111,
                   114,
-                  101
+                  100,
+                  101,
+                  114
                 ]
               },
               {
                 "kind": "account",
-                "path": "store.authority",
-                "account": "store"
+                "path": "shopper"
+              },
+              {
+                "kind": "account",
+                "path": "order.item",
+                "account": "order"
+              },
+              {
+                "kind": "account",
+                "path": "order.timestamp",
+                "account": "order"
               }
             ]
-          },
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "completeCartOrder",
+      "discriminator": [
+        243,
+        68,
+        85,
+        231,
+        172,
+        135,
+        3,
+        114
+      ],
+      "accounts": [
+        {
+          "name": "operator",
+          "writable": true,
+          "signer": true,
           "relations": [
-            "item"
+            "config"
           ]
         },
         {
-          "name": "item",
-          "writable": true
+          "name": "authority",
+          "writable": true,
+          "relations": [
+            "shopper"
+          ]
         },
         {
-          "name": "order",
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "shopper"
+        },
+        {
+          "name": "cartOrder",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  99,
+                  97,
+                  114,
+                  116,
+                  95,
                   111,
                   114,
                   100,
@@ -673,29 +902,23 @@ export type Splurge = {
               },
               {
                 "kind": "account",
-                "path": "item"
-              },
-              {
-                "kind": "arg",
-                "path": "timestamp"
+                "path": "cart_order.timestamp",
+                "account": "cartOrder"
               }
             ]
           }
         },
-        {
-          "name": "priceUpdateV2"
-        },
         {
           "name": "paymentMint"
         },
         {
-          "name": "authorityTokenAccount",
+          "name": "cartOrderTokenAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "cartOrder"
               },
               {
                 "kind": "account",
@@ -746,70 +969,18 @@ export type Splurge = {
           }
         },
         {
-          "name": "treasuryTokenAccount",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "treasury"
-              },
-              {
-                "kind": "account",
-                "path": "tokenProgram"
-              },
-              {
-                "kind": "account",
-                "path": "paymentMint"
-              }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
-          }
+          "name": "referrer",
+          "optional": true
         },
         {
-          "name": "orderTokenAccount",
+          "name": "referrerTokenAccount",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "order"
+                "path": "referrer"
               },
               {
                 "kind": "account",
@@ -871,276 +1042,350 @@ export type Splurge = {
           "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         }
       ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u32"
-        },
-        {
-          "name": "timestamp",
-          "type": "i64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "createReview",
+      "name": "completeOrder",
       "discriminator": [
-        69,
-        237,
-        87,
-        43,
-        238,
-        125,
-        40,
-        1
+        73,
+        78,
+        89,
+        7,
+        140,
+        132,
+        17,
+        97
       ],
       "accounts": [
+        {
+          "name": "operator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
         {
           "name": "authority",
           "writable": true,
-          "signer": true
+          "relations": [
+            "shopper"
+          ]
         },
         {
-          "name": "shopper",
+          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  115,
-                  104,
+                  99,
                   111,
-                  112,
-                  112,
-                  101,
-                  114
+                  110,
+                  102,
+                  105,
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "authority"
               }
             ]
           }
         },
         {
-          "name": "order",
+          "name": "shopper"
+        },
+        {
+          "name": "store",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  115,
+                  116,
                   111,
                   114,
-                  100,
-                  101,
-                  114
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "shopper"
-              },
-              {
-                "kind": "account",
-                "path": "order.item",
-                "account": "order"
-              },
-              {
-                "kind": "account",
-                "path": "order.timestamp",
-                "account": "order"
+                "path": "store.authority",
+                "account": "store"
               }
             ]
-          }
+          },
+          "relations": [
+            "item"
+          ]
         },
         {
-          "name": "review",
+          "name": "item"
+        },
+        {
+          "name": "order",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  111,
                   114,
+                  100,
                   101,
-                  118,
-                  105,
-                  101,
-                  119
+                  114
                 ]
               },
               {
                 "kind": "account",
-                "path": "order"
+                "path": "shopper"
+              },
+              {
+                "kind": "account",
+                "path": "item"
+              },
+              {
+                "kind": "account",
+                "path": "order.timestamp",
+                "account": "order"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": {
-              "name": "createReviewArgs"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "initializeConfig",
-      "discriminator": [
-        208,
-        127,
-        21,
-        1,
-        194,
-        190,
-        196,
-        70
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
+          "name": "paymentMint",
+          "relations": [
+            "order"
+          ]
         },
         {
-          "name": "treasury",
+          "name": "orderTokenAccount",
+          "writable": true,
           "pda": {
             "seeds": [
               {
-                "kind": "const",
-                "value": [
-                  116,
-                  114,
-                  101,
-                  97,
-                  115,
-                  117,
-                  114,
-                  121
-                ]
+                "kind": "account",
+                "path": "order"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
               }
-            ]
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
           }
         },
         {
-          "name": "config",
+          "name": "storeTokenAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
-                "kind": "const",
-                "value": [
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
+                "kind": "account",
+                "path": "store"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
               }
-            ]
-          }
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": {
-              "name": "initializeConfigArgs"
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
             }
           }
-        }
-      ]
-    },
-    {
-      "name": "initializeShopper",
-      "discriminator": [
-        177,
-        113,
-        87,
-        95,
-        53,
-        90,
-        67,
-        129
-      ],
-      "accounts": [
+        },
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true
+          "name": "referrer",
+          "optional": true
         },
         {
-          "name": "shopper",
+          "name": "referrerTokenAccount",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
-                "kind": "const",
-                "value": [
-                  115,
-                  104,
-                  111,
-                  112,
-                  112,
-                  101,
-                  114
-                ]
+                "kind": "account",
+                "path": "referrer"
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
               }
-            ]
-          }
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": {
-              "name": "initializeShopperArgs"
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
             }
           }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "initializeStore",
+      "name": "configureStore",
       "discriminator": [
-        109,
-        149,
-        210,
-        214,
-        188,
-        126,
-        220,
-        140
+        117,
+        84,
+        27,
+        8,
+        105,
+        93,
+        197,
+        216
       ],
       "accounts": [
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
         },
         {
           "name": "store",
@@ -1159,14 +1404,11 @@ export type Splurge = {
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "store.authority",
+                "account": "store"
               }
             ]
           }
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
@@ -1174,70 +1416,62 @@ export type Splurge = {
           "name": "args",
           "type": {
             "defined": {
-              "name": "initializeStoreArgs"
+              "name": "configureStoreArgs"
             }
           }
         }
       ]
     },
     {
-      "name": "listItem",
+      "name": "confirmDelivery",
       "discriminator": [
-        174,
-        245,
-        22,
-        211,
-        228,
-        103,
-        121,
-        13
+        11,
+        109,
+        227,
+        53,
+        179,
+        190,
+        88,
+        155
       ],
       "accounts": [
         {
           "name": "authority",
           "writable": true,
-          "signer": true,
-          "relations": [
-            "store"
-          ]
+          "signer": true
         },
         {
-          "name": "item",
-          "writable": true,
+          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  99,
+                  111,
+                  110,
+                  102,
                   105,
-                  116,
-                  101,
-                  109
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "store"
-              },
-              {
-                "kind": "arg",
-                "path": "args.name"
               }
             ]
           }
         },
         {
-          "name": "store",
+          "name": "shopper",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
                   115,
-                  116,
+                  104,
                   111,
-                  114,
-                  101
+                  112,
+                  112,
+                  101,
+                  114
                 ]
               },
               {
@@ -1248,59 +1482,33 @@ export type Splurge = {
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": {
-              "name": "listItemArgs"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "shipOrder",
-      "discriminator": [
-        2,
-        191,
-        151,
-        45,
-        16,
-        248,
-        97,
-        142
-      ],
-      "accounts": [
-        {
-          "name": "admin",
+          "name": "store",
           "writable": true,
-          "signer": true,
-          "relations": [
-            "config"
-          ]
-        },
-        {
-          "name": "config",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
+                  115,
+                  116,
                   111,
-                  110,
-                  102,
-                  105,
-                  103
+                  114,
+                  101
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "store.authority",
+                "account": "store"
               }
             ]
-          }
+          },
+          "relations": [
+            "item"
+          ]
+        },
+        {
+          "name": "item"
         },
         {
           "name": "order",
@@ -1333,38 +1541,17 @@ export type Splurge = {
             ]
           }
         },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
-        {
-          "name": "authority",
-          "writable": true
-        },
-        {
-          "name": "item"
-        },
-        {
-          "name": "orderTokenAccount",
-          "writable": true
-        },
         {
           "name": "paymentMint"
         },
         {
-          "name": "shopper"
-        },
-        {
-          "name": "store"
-        },
-        {
-          "name": "storeTokenAccount",
+          "name": "orderTokenAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "store"
+                "path": "order"
               },
               {
                 "kind": "account",
@@ -1414,269 +1601,6 @@ export type Splurge = {
             }
           }
         },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "tokenProgram"
-        },
-        {
-          "name": "tuktuk"
-        },
-        {
-          "name": "taskQueue",
-          "writable": true
-        },
-        {
-          "name": "task",
-          "writable": true
-        },
-        {
-          "name": "taskQueueAuthority"
-        }
-      ],
-      "args": [
-        {
-          "name": "taskId",
-          "type": "u16"
-        }
-      ]
-    },
-    {
-      "name": "unlistItem",
-      "discriminator": [
-        170,
-        45,
-        195,
-        119,
-        162,
-        155,
-        42,
-        94
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true,
-          "relations": [
-            "store"
-          ]
-        },
-        {
-          "name": "item",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  105,
-                  116,
-                  101,
-                  109
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "store"
-              },
-              {
-                "kind": "account",
-                "path": "item.name",
-                "account": "item"
-              }
-            ]
-          }
-        },
-        {
-          "name": "store"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "updateConfig",
-      "discriminator": [
-        29,
-        158,
-        252,
-        191,
-        10,
-        83,
-        219,
-        99
-      ],
-      "accounts": [
-        {
-          "name": "admin",
-          "writable": true,
-          "signer": true,
-          "relations": [
-            "config"
-          ]
-        },
-        {
-          "name": "config",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": {
-              "name": "updateConfigArgs"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "updateItem",
-      "discriminator": [
-        28,
-        222,
-        44,
-        175,
-        216,
-        228,
-        171,
-        184
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "signer": true,
-          "relations": [
-            "store"
-          ]
-        },
-        {
-          "name": "item",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  105,
-                  116,
-                  101,
-                  109
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "store"
-              },
-              {
-                "kind": "account",
-                "path": "item.name",
-                "account": "item"
-              }
-            ]
-          }
-        },
-        {
-          "name": "store"
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": {
-              "name": "updateItemArgs"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "withdrawEarnings",
-      "discriminator": [
-        6,
-        132,
-        233,
-        254,
-        241,
-        87,
-        247,
-        185
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "config",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "store",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  115,
-                  116,
-                  111,
-                  114,
-                  101
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "authority"
-              }
-            ]
-          }
-        },
-        {
-          "name": "paymentMint"
-        },
         {
           "name": "storeTokenAccount",
           "writable": true,
@@ -1735,13 +1659,18 @@ export type Splurge = {
           }
         },
         {
-          "name": "authorityTokenAccount",
+          "name": "referrer",
+          "optional": true
+        },
+        {
+          "name": "referrerTokenAccount",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "referrer"
               },
               {
                 "kind": "account",
@@ -1801,31 +1730,50 @@ export type Splurge = {
         {
           "name": "associatedTokenProgram",
           "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "withdrawTreasury",
-      "discriminator": [
-        40,
-        63,
-        122,
-        158,
-        144,
-        216,
-        83,
-        96
-      ],
-      "accounts": [
+        },
         {
-          "name": "admin",
+          "name": "tuktuk",
+          "address": "tuktukUrfhXT6ZT77QTU8RQtvgL967uRuVagWF57zVA"
+        },
+        {
+          "name": "taskQueue",
+          "writable": true
+        },
+        {
+          "name": "task",
           "writable": true,
-          "signer": true,
           "relations": [
-            "config"
+            "order"
           ]
         },
+        {
+          "name": "taskQueueAuthority"
+        },
+        {
+          "name": "rentRefund",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createCartOrder",
+      "discriminator": [
+        126,
+        125,
+        132,
+        54,
+        123,
+        34,
+        40,
+        129
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
         {
           "name": "treasury",
           "pda": {
@@ -1864,9 +1812,128 @@ export type Splurge = {
             ]
           }
         },
+        {
+          "name": "shopper",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  104,
+                  111,
+                  112,
+                  112,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "cartOrder",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  114,
+                  116,
+                  95,
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shopper"
+              },
+              {
+                "kind": "arg",
+                "path": "timestamp"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priceUpdateV2",
+          "optional": true
+        },
+        {
+          "name": "referrer",
+          "optional": true
+        },
         {
           "name": "paymentMint"
         },
+        {
+          "name": "authorityTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
         {
           "name": "treasuryTokenAccount",
           "writable": true,
@@ -1925,13 +1992,13 @@ export type Splurge = {
           }
         },
         {
-          "name": "adminTokenAccount",
+          "name": "cartOrderTokenAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "admin"
+                "path": "cartOrder"
               },
               {
                 "kind": "account",
@@ -1993,375 +2060,5180 @@ export type Splurge = {
           "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         }
       ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "config",
-      "discriminator": [
-        155,
-        12,
-        170,
-        224,
-        30,
-        250,
-        204,
-        130
-      ]
-    },
-    {
-      "name": "item",
-      "discriminator": [
-        92,
-        157,
-        163,
-        130,
-        72,
-        254,
-        86,
-        216
-      ]
-    },
-    {
-      "name": "order",
-      "discriminator": [
-        134,
-        173,
-        223,
-        185,
-        77,
-        86,
-        28,
-        51
-      ]
-    },
-    {
-      "name": "priceUpdateV2",
-      "discriminator": [
-        34,
-        241,
-        35,
-        99,
-        157,
-        126,
-        244,
-        205
-      ]
-    },
-    {
-      "name": "review",
-      "discriminator": [
-        124,
-        63,
-        203,
-        215,
-        226,
-        30,
-        222,
-        15
-      ]
-    },
-    {
-      "name": "shopper",
-      "discriminator": [
-        24,
-        132,
-        213,
-        128,
-        71,
-        32,
-        190,
-        246
+      "args": [
+        {
+          "name": "amounts",
+          "type": {
+            "vec": "u32"
+          }
+        },
+        {
+          "name": "timestamp",
+          "type": "i64"
+        },
+        {
+          "name": "maxPayment",
+          "type": "u64"
+        }
       ]
     },
     {
-      "name": "store",
+      "name": "createOrder",
       "discriminator": [
-        130,
-        48,
-        247,
-        244,
-        182,
-        191,
-        30,
-        26
-      ]
+        141,
+        54,
+        37,
+        207,
+        237,
+        210,
+        250,
+        215
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "shopper",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  104,
+                  111,
+                  112,
+                  112,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "store",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  111,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "store.authority",
+                "account": "store"
+              }
+            ]
+          },
+          "relations": [
+            "item"
+          ]
+        },
+        {
+          "name": "item",
+          "writable": true
+        },
+        {
+          "name": "order",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shopper"
+              },
+              {
+                "kind": "account",
+                "path": "item"
+              },
+              {
+                "kind": "arg",
+                "path": "timestamp"
+              }
+            ]
+          }
+        },
+        {
+          "name": "priceUpdateV2",
+          "optional": true
+        },
+        {
+          "name": "referrer",
+          "optional": true
+        },
+        {
+          "name": "paymentMint"
+        },
+        {
+          "name": "authorityTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "orderTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "order"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tuktuk",
+          "optional": true,
+          "address": "tuktukUrfhXT6ZT77QTU8RQtvgL967uRuVagWF57zVA"
+        },
+        {
+          "name": "taskQueue",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "task",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "taskQueueAuthority",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u32"
+        },
+        {
+          "name": "timestamp",
+          "type": "i64"
+        },
+        {
+          "name": "maxPayment",
+          "type": "u64"
+        },
+        {
+          "name": "taskId",
+          "type": {
+            "option": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createReview",
+      "discriminator": [
+        69,
+        237,
+        87,
+        43,
+        238,
+        125,
+        40,
+        1
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "shopper",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  104,
+                  111,
+                  112,
+                  112,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "order",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shopper"
+              },
+              {
+                "kind": "account",
+                "path": "order.item",
+                "account": "order"
+              },
+              {
+                "kind": "account",
+                "path": "order.timestamp",
+                "account": "order"
+              }
+            ]
+          }
+        },
+        {
+          "name": "review",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "order"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "createReviewArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "expireOrder",
+      "discriminator": [
+        174,
+        27,
+        85,
+        247,
+        105,
+        245,
+        220,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "treasury",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "relations": [
+            "shopper"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "shopper"
+        },
+        {
+          "name": "item",
+          "writable": true
+        },
+        {
+          "name": "order",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shopper"
+              },
+              {
+                "kind": "account",
+                "path": "item"
+              },
+              {
+                "kind": "account",
+                "path": "order.timestamp",
+                "account": "order"
+              }
+            ]
+          }
+        },
+        {
+          "name": "paymentMint"
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "orderTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "authorityTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeConfig",
+      "discriminator": [
+        208,
+        127,
+        21,
+        1,
+        194,
+        190,
+        196,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "initializeConfigArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeShopper",
+      "discriminator": [
+        177,
+        113,
+        87,
+        95,
+        53,
+        90,
+        67,
+        129
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "shopper",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  104,
+                  111,
+                  112,
+                  112,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "initializeShopperArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeStore",
+      "discriminator": [
+        109,
+        149,
+        210,
+        214,
+        188,
+        126,
+        220,
+        140
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "store",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  111,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "initializeStoreArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "listItem",
+      "discriminator": [
+        174,
+        245,
+        22,
+        211,
+        228,
+        103,
+        121,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "store"
+          ]
+        },
+        {
+          "name": "item",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "store"
+              },
+              {
+                "kind": "arg",
+                "path": "args.name"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "store",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  111,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "listItemArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "openDispute",
+      "discriminator": [
+        137,
+        25,
+        99,
+        119,
+        23,
+        223,
+        161,
+        42
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "shopper",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  104,
+                  111,
+                  112,
+                  112,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "order",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shopper"
+              },
+              {
+                "kind": "account",
+                "path": "order.item",
+                "account": "order"
+              },
+              {
+                "kind": "account",
+                "path": "order.timestamp",
+                "account": "order"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "partiallyFulfillOrder",
+      "discriminator": [
+        143,
+        90,
+        214,
+        5,
+        176,
+        252,
+        129,
+        252
+      ],
+      "accounts": [
+        {
+          "name": "shipper",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "order",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shopper"
+              },
+              {
+                "kind": "account",
+                "path": "item"
+              },
+              {
+                "kind": "account",
+                "path": "order.timestamp",
+                "account": "order"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "relations": [
+            "shopper"
+          ]
+        },
+        {
+          "name": "item",
+          "writable": true
+        },
+        {
+          "name": "shopper"
+        },
+        {
+          "name": "store",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  111,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "store.authority",
+                "account": "store"
+              }
+            ]
+          },
+          "relations": [
+            "item"
+          ]
+        },
+        {
+          "name": "paymentMint"
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "orderTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "order"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "authorityTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "storeTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "store"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tuktuk",
+          "address": "tuktukUrfhXT6ZT77QTU8RQtvgL967uRuVagWF57zVA"
+        },
+        {
+          "name": "taskQueue",
+          "writable": true
+        },
+        {
+          "name": "task",
+          "writable": true
+        },
+        {
+          "name": "taskQueueAuthority"
+        }
+      ],
+      "args": [
+        {
+          "name": "shipAmount",
+          "type": "u32"
+        },
+        {
+          "name": "taskId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "quoteOrder",
+      "discriminator": [
+        91,
+        60,
+        245,
+        254,
+        40,
+        165,
+        230,
+        58
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "store",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  111,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "store.authority",
+                "account": "store"
+              }
+            ]
+          },
+          "relations": [
+            "item"
+          ]
+        },
+        {
+          "name": "item"
+        },
+        {
+          "name": "paymentMint"
+        },
+        {
+          "name": "priceUpdateV2",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u32"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "orderQuote"
+        }
+      }
+    },
+    {
+      "name": "resolveDispute",
+      "discriminator": [
+        231,
+        6,
+        202,
+        6,
+        96,
+        103,
+        12,
+        230
+      ],
+      "accounts": [
+        {
+          "name": "operator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "relations": [
+            "shopper"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "shopper"
+        },
+        {
+          "name": "store",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  111,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "store.authority",
+                "account": "store"
+              }
+            ]
+          },
+          "relations": [
+            "item"
+          ]
+        },
+        {
+          "name": "item"
+        },
+        {
+          "name": "order",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shopper"
+              },
+              {
+                "kind": "account",
+                "path": "item"
+              },
+              {
+                "kind": "account",
+                "path": "order.timestamp",
+                "account": "order"
+              }
+            ]
+          }
+        },
+        {
+          "name": "paymentMint"
+        },
+        {
+          "name": "orderTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "order"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "authorityTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "storeTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "store"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "resolveDisputeArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setMintPaused",
+      "discriminator": [
+        122,
+        112,
+        56,
+        49,
+        172,
+        101,
+        49,
+        228
+      ],
+      "accounts": [
+        {
+          "name": "pauser",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "mint",
+          "type": "pubkey"
+        },
+        {
+          "name": "isPaused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setPaused",
+      "discriminator": [
+        91,
+        60,
+        125,
+        192,
+        176,
+        225,
+        166,
+        218
+      ],
+      "accounts": [
+        {
+          "name": "pauser",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "pausedOperations",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setPayoutRecipients",
+      "discriminator": [
+        7,
+        78,
+        56,
+        112,
+        155,
+        117,
+        119,
+        240
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "store"
+          ]
+        },
+        {
+          "name": "store",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  111,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "payoutRecipients",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "payoutRecipient"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "shipCartOrder",
+      "discriminator": [
+        185,
+        36,
+        240,
+        25,
+        236,
+        199,
+        172,
+        9
+      ],
+      "accounts": [
+        {
+          "name": "operator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "relations": [
+            "shopper"
+          ]
+        },
+        {
+          "name": "shopper"
+        },
+        {
+          "name": "cartOrder",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  114,
+                  116,
+                  95,
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shopper"
+              },
+              {
+                "kind": "account",
+                "path": "cart_order.timestamp",
+                "account": "cartOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "paymentMint"
+        },
+        {
+          "name": "cartOrderTokenAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "cartOrder"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "tuktuk",
+          "address": "tuktukUrfhXT6ZT77QTU8RQtvgL967uRuVagWF57zVA"
+        },
+        {
+          "name": "taskQueue",
+          "writable": true
+        },
+        {
+          "name": "task",
+          "writable": true
+        },
+        {
+          "name": "taskQueueAuthority"
+        }
+      ],
+      "args": [
+        {
+          "name": "taskId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "shipOrder",
+      "discriminator": [
+        2,
+        191,
+        151,
+        45,
+        16,
+        248,
+        97,
+        142
+      ],
+      "accounts": [
+        {
+          "name": "shipper",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "order",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shopper"
+              },
+              {
+                "kind": "account",
+                "path": "item"
+              },
+              {
+                "kind": "account",
+                "path": "order.timestamp",
+                "account": "order"
+              }
+            ]
+          }
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "relations": [
+            "shopper"
+          ]
+        },
+        {
+          "name": "item"
+        },
+        {
+          "name": "orderTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "paymentMint"
+        },
+        {
+          "name": "shopper"
+        },
+        {
+          "name": "store",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  111,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "store.authority",
+                "account": "store"
+              }
+            ]
+          },
+          "relations": [
+            "item"
+          ]
+        },
+        {
+          "name": "storeTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "store"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "tuktuk",
+          "address": "tuktukUrfhXT6ZT77QTU8RQtvgL967uRuVagWF57zVA"
+        },
+        {
+          "name": "taskQueue",
+          "writable": true
+        },
+        {
+          "name": "task",
+          "writable": true
+        },
+        {
+          "name": "taskQueueAuthority"
+        }
+      ],
+      "args": [
+        {
+          "name": "taskId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "shopperCancelOrder",
+      "discriminator": [
+        59,
+        244,
+        162,
+        15,
+        84,
+        222,
+        108,
+        84
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "treasury",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "shopper",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  104,
+                  111,
+                  112,
+                  112,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "item",
+          "writable": true
+        },
+        {
+          "name": "order",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shopper"
+              },
+              {
+                "kind": "account",
+                "path": "item"
+              },
+              {
+                "kind": "account",
+                "path": "order.timestamp",
+                "account": "order"
+              }
+            ]
+          }
+        },
+        {
+          "name": "paymentMint"
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "orderTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "order"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "authorityTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unlistItem",
+      "discriminator": [
+        170,
+        45,
+        195,
+        119,
+        162,
+        155,
+        42,
+        94
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "store"
+          ]
+        },
+        {
+          "name": "item",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "store"
+              },
+              {
+                "kind": "account",
+                "path": "item.name",
+                "account": "item"
+              }
+            ]
+          }
+        },
+        {
+          "name": "store"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateConfig",
+      "discriminator": [
+        29,
+        158,
+        252,
+        191,
+        10,
+        83,
+        219,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "updateConfigArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateItem",
+      "discriminator": [
+        28,
+        222,
+        44,
+        175,
+        216,
+        228,
+        171,
+        184
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "store"
+          ]
+        },
+        {
+          "name": "item",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  116,
+                  101,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "store"
+              },
+              {
+                "kind": "account",
+                "path": "item.name",
+                "account": "item"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "store"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "updateItemArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateRoles",
+      "discriminator": [
+        220,
+        152,
+        205,
+        233,
+        177,
+        123,
+        219,
+        125
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "updateRolesArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawEarnings",
+      "discriminator": [
+        6,
+        132,
+        233,
+        254,
+        241,
+        87,
+        247,
+        185
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "store",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  111,
+                  114,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "paymentMint"
+        },
+        {
+          "name": "storeTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "store"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "destinationTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawTreasury",
+      "discriminator": [
+        40,
+        63,
+        122,
+        158,
+        144,
+        216,
+        83,
+        96
+      ],
+      "accounts": [
+        {
+          "name": "treasurer",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "treasury",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "paymentMint"
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "paymentMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "destinationTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "cartOrder",
+      "discriminator": [
+        205,
+        231,
+        28,
+        174,
+        65,
+        38,
+        193,
+        108
+      ]
+    },
+    {
+      "name": "config",
+      "discriminator": [
+        155,
+        12,
+        170,
+        224,
+        30,
+        250,
+        204,
+        130
+      ]
+    },
+    {
+      "name": "item",
+      "discriminator": [
+        92,
+        157,
+        163,
+        130,
+        72,
+        254,
+        86,
+        216
+      ]
+    },
+    {
+      "name": "order",
+      "discriminator": [
+        134,
+        173,
+        223,
+        185,
+        77,
+        86,
+        28,
+        51
+      ]
+    },
+    {
+      "name": "priceUpdateV2",
+      "discriminator": [
+        34,
+        241,
+        35,
+        99,
+        157,
+        126,
+        244,
+        205
+      ]
+    },
+    {
+      "name": "review",
+      "discriminator": [
+        124,
+        63,
+        203,
+        215,
+        226,
+        30,
+        222,
+        15
+      ]
+    },
+    {
+      "name": "shopper",
+      "discriminator": [
+        24,
+        132,
+        213,
+        128,
+        71,
+        32,
+        190,
+        246
+      ]
+    },
+    {
+      "name": "store",
+      "discriminator": [
+        130,
+        48,
+        247,
+        244,
+        182,
+        191,
+        30,
+        26
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "acceptedMintPauseUpdated",
+      "discriminator": [
+        103,
+        215,
+        178,
+        96,
+        1,
+        158,
+        28,
+        68
+      ]
+    },
+    {
+      "name": "adminTransferAccepted",
+      "discriminator": [
+        79,
+        229,
+        204,
+        202,
+        134,
+        43,
+        177,
+        26
+      ]
+    },
+    {
+      "name": "adminTransferCancelled",
+      "discriminator": [
+        93,
+        23,
+        69,
+        55,
+        216,
+        128,
+        106,
+        56
+      ]
+    },
+    {
+      "name": "adminTransferStarted",
+      "discriminator": [
+        31,
+        82,
+        117,
+        177,
+        147,
+        168,
+        210,
+        177
+      ]
+    },
+    {
+      "name": "cartOrderCancelled",
+      "discriminator": [
+        73,
+        41,
+        65,
+        234,
+        40,
+        183,
+        85,
+        3
+      ]
+    },
+    {
+      "name": "cartOrderClosed",
+      "discriminator": [
+        235,
+        132,
+        34,
+        37,
+        67,
+        73,
+        96,
+        73
+      ]
+    },
+    {
+      "name": "cartOrderCompleted",
+      "discriminator": [
+        164,
+        210,
+        116,
+        1,
+        140,
+        207,
+        67,
+        120
+      ]
+    },
+    {
+      "name": "cartOrderCreated",
+      "discriminator": [
+        11,
+        151,
+        175,
+        220,
+        36,
+        128,
+        182,
+        101
+      ]
+    },
+    {
+      "name": "cartOrderShipped",
+      "discriminator": [
+        153,
+        184,
+        244,
+        214,
+        188,
+        53,
+        194,
+        211
+      ]
+    },
+    {
+      "name": "disputeOpened",
+      "discriminator": [
+        239,
+        222,
+        102,
+        235,
+        193,
+        85,
+        1,
+        214
+      ]
+    },
+    {
+      "name": "disputeResolved",
+      "discriminator": [
+        121,
+        64,
+        249,
+        153,
+        139,
+        128,
+        236,
+        187
+      ]
+    },
+    {
+      "name": "earningsWithdrawn",
+      "discriminator": [
+        2,
+        155,
+        160,
+        28,
+        85,
+        112,
+        127,
+        79
+      ]
+    },
+    {
+      "name": "feeTiersIncreaseStaged",
+      "discriminator": [
+        46,
+        94,
+        155,
+        52,
+        61,
+        207,
+        145,
+        72
+      ]
+    },
+    {
+      "name": "itemListed",
+      "discriminator": [
+        51,
+        193,
+        103,
+        51,
+        201,
+        26,
+        211,
+        113
+      ]
+    },
+    {
+      "name": "maxOrderFeeIncreaseStaged",
+      "discriminator": [
+        223,
+        66,
+        230,
+        243,
+        156,
+        31,
+        5,
+        72
+      ]
+    },
+    {
+      "name": "orderCancelled",
+      "discriminator": [
+        108,
+        56,
+        128,
+        68,
+        168,
+        113,
+        168,
+        239
+      ]
+    },
+    {
+      "name": "orderClosed",
+      "discriminator": [
+        237,
+        77,
+        101,
+        123,
+        72,
+        43,
+        149,
+        123
+      ]
+    },
+    {
+      "name": "orderCompleted",
+      "discriminator": [
+        90,
+        77,
+        52,
+        248,
+        56,
+        233,
+        110,
+        197
+      ]
+    },
+    {
+      "name": "orderCreated",
+      "discriminator": [
+        224,
+        1,
+        229,
+        63,
+        254,
+        60,
+        190,
+        159
+      ]
+    },
+    {
+      "name": "orderExpired",
+      "discriminator": [
+        241,
+        55,
+        48,
+        196,
+        160,
+        51,
+        40,
+        213
+      ]
+    },
+    {
+      "name": "orderFeeIncreaseStaged",
+      "discriminator": [
+        180,
+        138,
+        235,
+        247,
+        226,
+        12,
+        8,
+        20
+      ]
+    },
+    {
+      "name": "orderPartiallyFulfilled",
+      "discriminator": [
+        150,
+        205,
+        252,
+        15,
+        171,
+        146,
+        174,
+        149
+      ]
+    },
+    {
+      "name": "orderShipped",
+      "discriminator": [
+        200,
+        225,
+        83,
+        123,
+        179,
+        86,
+        221,
+        24
+      ]
+    },
+    {
+      "name": "pausedOperationsUpdated",
+      "discriminator": [
+        65,
+        84,
+        220,
+        65,
+        248,
+        123,
+        198,
+        154
+      ]
+    },
+    {
+      "name": "referralPaid",
+      "discriminator": [
+        70,
+        190,
+        133,
+        42,
+        145,
+        213,
+        87,
+        197
+      ]
+    },
+    {
+      "name": "rolesUpdated",
+      "discriminator": [
+        81,
+        37,
+        176,
+        32,
+        30,
+        204,
+        251,
+        246
+      ]
+    },
+    {
+      "name": "shopperInitialized",
+      "discriminator": [
+        245,
+        82,
+        153,
+        46,
+        179,
+        7,
+        235,
+        6
+      ]
+    },
+    {
+      "name": "storeInitialized",
+      "discriminator": [
+        227,
+        199,
+        199,
+        58,
+        219,
+        60,
+        250,
+        31
+      ]
+    },
+    {
+      "name": "storeOrderFeeIncreaseStaged",
+      "discriminator": [
+        46,
+        185,
+        182,
+        188,
+        34,
+        242,
+        62,
+        221
+      ]
+    },
+    {
+      "name": "treasuryWithdrawn",
+      "discriminator": [
+        143,
+        181,
+        157,
+        169,
+        87,
+        155,
+        170,
+        46
+      ]
     }
   ],
-  "events": [
+  "errors": [
+    {
+      "code": 6000,
+      "name": "invalidAddress",
+      "msg": "Address cannot be default pubkey"
+    },
+    {
+      "code": 6001,
+      "name": "invalidTimestamp",
+      "msg": "Timestamp cannot be in the future"
+    },
+    {
+      "code": 6002,
+      "name": "emptyAcceptedMints",
+      "msg": "Whitelist cannot be empty"
+    },
+    {
+      "code": 6003,
+      "name": "unauthorizedAdmin",
+      "msg": "Signer not authorized as config admin"
+    },
+    {
+      "code": 6004,
+      "name": "noPendingAdmin",
+      "msg": "No admin transfer is pending"
+    },
+    {
+      "code": 6005,
+      "name": "unauthorizedPendingAdmin",
+      "msg": "Signer is not the pending admin"
+    },
+    {
+      "code": 6006,
+      "name": "unauthorizedOperator",
+      "msg": "Signer not authorized as config operator"
+    },
+    {
+      "code": 6007,
+      "name": "unauthorizedTreasurer",
+      "msg": "Signer not authorized as config treasurer"
+    },
+    {
+      "code": 6008,
+      "name": "unauthorizedPauser",
+      "msg": "Signer not authorized as config pauser"
+    },
+    {
+      "code": 6009,
+      "name": "unauthorizedShipper",
+      "msg": "Signer not authorized to ship order"
+    },
+    {
+      "code": 6010,
+      "name": "orderingPaused",
+      "msg": "Ordering paused, no new orders can be created"
+    },
+    {
+      "code": 6011,
+      "name": "shippingPaused",
+      "msg": "Shipping paused, orders cannot be shipped"
+    },
+    {
+      "code": 6012,
+      "name": "completionPaused",
+      "msg": "Completion paused, orders cannot be completed"
+    },
+    {
+      "code": 6013,
+      "name": "withdrawalsPaused",
+      "msg": "Withdrawals paused, funds cannot be withdrawn"
+    },
+    {
+      "code": 6014,
+      "name": "listingPaused",
+      "msg": "Listing paused, items cannot be listed or updated"
+    },
+    {
+      "code": 6015,
+      "name": "invalidPauseFlags",
+      "msg": "Pause flags contain unknown operations"
+    },
+    {
+      "code": 6016,
+      "name": "paymentMintPaused",
+      "msg": "Payment mint is paused"
+    },
+    {
+      "code": 6017,
+      "name": "storeSuspended",
+      "msg": "Store is suspended"
+    },
+    {
+      "code": 6018,
+      "name": "paymentMintNotAccepted",
+      "msg": "Payment mint is not accepted"
+    },
+    {
+      "code": 6019,
+      "name": "invalidPriceFeedId",
+      "msg": "Price update feed ID does not match accepted mint"
+    },
+    {
+      "code": 6020,
+      "name": "insufficientVerificationLevel",
+      "msg": "Price update verification level is insufficient"
+    },
+    {
+      "code": 6021,
+      "name": "invalidCompletionDelay",
+      "msg": "Completion delay is out of bounds"
+    },
+    {
+      "code": 6022,
+      "name": "shopperNameRequired",
+      "msg": "Shopper name is required"
+    },
+    {
+      "code": 6023,
+      "name": "shopperNameTooLong",
+      "msg": "Shopper name exceeded maximum length"
+    },
+    {
+      "code": 6024,
+      "name": "shopperAddressRequired",
+      "msg": "Shopper address is required"
+    },
+    {
+      "code": 6025,
+      "name": "invalidShopperAuthority",
+      "msg": "Shopper authority does not match provided authority"
+    },
+    {
+      "code": 6026,
+      "name": "storeNameRequired",
+      "msg": "Store name is required"
+    },
+    {
+      "code": 6027,
+      "name": "storeNameTooLong",
+      "msg": "Store name exceeded maximum length"
+    },
+    {
+      "code": 6028,
+      "name": "itemNameRequired",
+      "msg": "Store item name is required"
+    },
+    {
+      "code": 6029,
+      "name": "itemNameTooLong",
+      "msg": "Store item name exceeded maximum length"
+    },
     {
-      "name": "itemListed",
-      "discriminator": [
-        51,
-        193,
-        103,
-        51,
-        201,
-        26,
-        211,
-        113
-      ]
+      "code": 6030,
+      "name": "insufficientInventory",
+      "msg": "Store item has insufficient inventory to fulfill order"
     },
     {
-      "name": "orderCancelled",
-      "discriminator": [
-        108,
-        56,
-        128,
-        68,
-        168,
-        113,
-        168,
-        239
-      ]
+      "code": 6031,
+      "name": "orderAlreadyFinalized",
+      "msg": "Order already finalized"
     },
     {
-      "name": "orderCompleted",
-      "discriminator": [
-        90,
-        77,
-        52,
-        248,
-        56,
-        233,
-        110,
-        197
-      ]
+      "code": 6032,
+      "name": "orderNotPending",
+      "msg": "Order status is not pending"
     },
     {
-      "name": "orderCreated",
-      "discriminator": [
-        224,
-        1,
-        229,
-        63,
-        254,
-        60,
-        190,
-        159
-      ]
+      "code": 6033,
+      "name": "cancellationWindowElapsed",
+      "msg": "Shopper cancellation window has elapsed"
     },
     {
-      "name": "orderShipped",
-      "discriminator": [
-        200,
-        225,
-        83,
-        123,
-        179,
-        86,
-        221,
-        24
-      ]
+      "code": 6034,
+      "name": "invalidFulfillmentAmount",
+      "msg": "Fulfilled amount must be above 0 and below order amount"
     },
     {
-      "name": "shopperInitialized",
-      "discriminator": [
-        245,
-        82,
-        153,
-        46,
-        179,
-        7,
-        235,
-        6
-      ]
+      "code": 6035,
+      "name": "orderNotExpired",
+      "msg": "Order shipping deadline has not elapsed"
+    },
+    {
+      "code": 6036,
+      "name": "missingExpiryTask",
+      "msg": "Tuktuk accounts and task ID are required to queue order expiry"
+    },
+    {
+      "code": 6037,
+      "name": "invalidOrderTokenAccount",
+      "msg": "Order token account does not match order"
+    },
+    {
+      "code": 6038,
+      "name": "orderNotBeingShipped",
+      "msg": "Order status is not shipping"
+    },
+    {
+      "code": 6039,
+      "name": "orderNotDisputed",
+      "msg": "Order status is not disputed"
+    },
+    {
+      "code": 6040,
+      "name": "invalidRefundBps",
+      "msg": "Refund basis points cannot exceed 10000"
+    },
+    {
+      "code": 6041,
+      "name": "invalidFeeBps",
+      "msg": "Fee basis points exceed maximum order fee"
+    },
+    {
+      "code": 6042,
+      "name": "invalidFeeTiers",
+      "msg": "Fee tiers must be ascending by volume and within the maximum count"
+    },
+    {
+      "code": 6043,
+      "name": "invalidPayoutRecipients",
+      "msg": "Payout shares must sum to 10000 across unique recipients within the maximum count"
+    },
+    {
+      "code": 6044,
+      "name": "invalidPayoutTokenAccounts",
+      "msg": "Payout token accounts do not match store payout recipients"
+    },
+    {
+      "code": 6045,
+      "name": "invalidReferralBps",
+      "msg": "Referral basis points cannot exceed 10000"
+    },
+    {
+      "code": 6046,
+      "name": "selfReferral",
+      "msg": "Shopper cannot refer their own order"
+    },
+    {
+      "code": 6047,
+      "name": "missingReferrerTokenAccount",
+      "msg": "Referrer token account is required to complete a referred order"
+    },
+    {
+      "code": 6048,
+      "name": "invalidReferrerTokenAccount",
+      "msg": "Referrer token account does not match order referrer"
+    },
+    {
+      "code": 6049,
+      "name": "invalidWithdrawalAmount",
+      "msg": "Withdrawal amount must be above 0 and within balance"
+    },
+    {
+      "code": 6050,
+      "name": "orderAlreadyCompleted",
+      "msg": "Order already completed"
+    },
+    {
+      "code": 6051,
+      "name": "orderNotCompleted",
+      "msg": "Order not completed"
+    },
+    {
+      "code": 6052,
+      "name": "invalidOrderTask",
+      "msg": "Task does not match order completion task"
+    },
+    {
+      "code": 6053,
+      "name": "invalidOrderStatus",
+      "msg": "Order completion must be done through complete_order instruction"
+    },
+    {
+      "code": 6054,
+      "name": "invalidOrderPaymentMint",
+      "msg": "Order payment mint does not match provided mint"
+    },
+    {
+      "code": 6055,
+      "name": "emptyCart",
+      "msg": "Cart must contain at least one item"
+    },
+    {
+      "code": 6056,
+      "name": "cartTooLarge",
+      "msg": "Cart exceeded maximum number of items"
+    },
+    {
+      "code": 6057,
+      "name": "cartAccountsMismatch",
+      "msg": "Number of cart accounts does not match number of amounts"
+    },
+    {
+      "code": 6058,
+      "name": "duplicateCartItem",
+      "msg": "Cart contains duplicate items"
+    },
+    {
+      "code": 6059,
+      "name": "invalidCartSubtotal",
+      "msg": "Cart subtotal or fee does not match sum of line items"
+    },
+    {
+      "code": 6060,
+      "name": "invalidStoreTokenAccount",
+      "msg": "Store token account does not match line item store"
+    },
+    {
+      "code": 6061,
+      "name": "invalidCartItem",
+      "msg": "Item does not match line item"
+    },
+    {
+      "code": 6062,
+      "name": "invalidCartStore",
+      "msg": "Store does not match line item store"
+    },
+    {
+      "code": 6063,
+      "name": "orderNotFinalized",
+      "msg": "Order must be completed, cancelled or resolved to be closed"
+    },
+    {
+      "code": 6064,
+      "name": "expiryTaskPending",
+      "msg": "Order cannot be closed before its shipping deadline"
+    },
+    {
+      "code": 6065,
+      "name": "reviewWindowOpen",
+      "msg": "Order review window has not elapsed"
+    },
+    {
+      "code": 6066,
+      "name": "orderHasReview",
+      "msg": "Order with a review cannot be closed"
+    },
+    {
+      "code": 6067,
+      "name": "invalidRating",
+      "msg": "Rating must be between 1 and 5"
+    },
+    {
+      "code": 6068,
+      "name": "mathOverflow",
+      "msg": "Math operation overflow"
+    },
+    {
+      "code": 6069,
+      "name": "slippageExceeded",
+      "msg": "Payment exceeds maximum payment"
+    },
+    {
+      "code": 6070,
+      "name": "invalidPrice",
+      "msg": "Oracle price must be above 0"
+    },
+    {
+      "code": 6071,
+      "name": "stalePrice",
+      "msg": "Oracle price is older than maximum staleness"
+    },
+    {
+      "code": 6072,
+      "name": "priceConfidenceTooWide",
+      "msg": "Oracle confidence interval exceeds maximum ratio"
+    },
+    {
+      "code": 6073,
+      "name": "priceBelowMinimum",
+      "msg": "Oracle price is below minimum accepted price"
+    },
+    {
+      "code": 6074,
+      "name": "priceAboveMaximum",
+      "msg": "Oracle price is above maximum accepted price"
+    },
+    {
+      "code": 6075,
+      "name": "invalidOracleConfig",
+      "msg": "Accepted mint oracle config is invalid"
+    },
+    {
+      "code": 6076,
+      "name": "missingPriceUpdate",
+      "msg": "Price update is required for oracle-priced items"
+    },
+    {
+      "code": 6077,
+      "name": "fixedPriceMintMismatch",
+      "msg": "Fixed-price item must be paid in its price mint"
+    },
+    {
+      "code": 6078,
+      "name": "invalidReferrer",
+      "msg": "Referrer does not match order referrer"
+    },
+    {
+      "code": 6079,
+      "name": "priceRequired",
+      "msg": "Price is required when changing pricing mode"
+    },
+    {
+      "code": 6080,
+      "name": "invalidReferralFee",
+      "msg": "Referral fee exceeds payment subtotal"
+    }
+  ],
+  "types": [
+    {
+      "name": "acceptedMint",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the stablecoin"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "feedId",
+            "docs": [
+              "Pyth price feed ID of the stablecoin"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "verificationLevel",
+            "docs": [
+              "Minimum verification level of accepted price updates"
+            ],
+            "type": {
+              "defined": {
+                "name": "priceVerificationLevel"
+              }
+            }
+          },
+          {
+            "name": "maxStalenessSecs",
+            "docs": [
+              "Maximum age of the oracle price in seconds"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "maxConfBps",
+            "docs": [
+              "Maximum oracle confidence interval in basis points of price"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "minPrice",
+            "docs": [
+              "Minimum accepted oracle price in atomic units with 6 decimals"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxPrice",
+            "docs": [
+              "Maximum accepted oracle price in atomic units with 6 decimals"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "isPaused",
+            "docs": [
+              "Boolean indicating if the mint is temporarily not accepted for new orders"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "acceptedMintPauseUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "isPaused",
+            "type": "bool"
+          },
+          {
+            "name": "updatedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "storeInitialized",
-      "discriminator": [
-        227,
-        199,
-        199,
-        58,
-        219,
-        60,
-        250,
-        31
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "invalidAddress",
-      "msg": "Address cannot be default pubkey"
+      "name": "actorRole",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "operator"
+          },
+          {
+            "name": "shopper"
+          },
+          {
+            "name": "store"
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "invalidTimestamp",
-      "msg": "Timestamp cannot be in the future"
+      "name": "adminTransferAccepted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "emptyAcceptedMints",
-      "msg": "Whitelist cannot be empty"
+      "name": "adminTransferCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "unauthorizedAdmin",
-      "msg": "Signer not authorized as config admin"
+      "name": "adminTransferStarted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "platformPaused",
-      "msg": "Platform paused, no new orders can be created"
+      "name": "cartOrder",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "shopper",
+            "docs": [
+              "PDA of shopper account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "docs": [
+              "Unix time of order creation"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Order status"
+            ],
+            "type": {
+              "defined": {
+                "name": "orderStatus"
+              }
+            }
+          },
+          {
+            "name": "paymentSubtotal",
+            "docs": [
+              "Payment subtotal of all line items in atomic units of mint"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "platformFee",
+            "docs": [
+              "Platform fee of all line items in atomic units of mint"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paymentMint",
+            "docs": [
+              "Address of stablecoin mint used for payment"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump used for seed derivation"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "task",
+            "docs": [
+              "Tuktuk task queued to complete the order, default until shipped"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "referrer",
+            "docs": [
+              "Address credited with referring the order"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "finalizedAt",
+            "docs": [
+              "Unix time the order reached a final status, zero until then"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lineItems",
+            "docs": [
+              "Items purchased in this order"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "lineItem"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "paymentMintNotAccepted",
-      "msg": "Payment mint is not accepted"
+      "name": "cartOrderCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cartOrder",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "invalidPriceUpdateV2",
-      "msg": "Price update v2 does not match with any accepted mint"
+      "name": "cartOrderClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cartOrder",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "shopperNameRequired",
-      "msg": "Shopper name is required"
+      "name": "cartOrderCompleted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cartOrder",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "shopperNameTooLong",
-      "msg": "Shopper name exceeded maximum length"
+      "name": "cartOrderCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cartOrder",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "shopperAddressRequired",
-      "msg": "Shopper address is required"
+      "name": "cartOrderShipped",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cartOrder",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "invalidShopperAuthority",
-      "msg": "Shopper authority does not match provided authority"
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "orderFeeBps",
+            "docs": [
+              "Fee charged on each order in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "admin",
+            "docs": [
+              "Address that has authority over the config and roles"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pausedOperations",
+            "docs": [
+              "Bitmask of paused operations, see `PAUSE_*` constants"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump used for seed derivation"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "treasuryBump",
+            "docs": [
+              "Bump used for seed derivation"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "acceptedMints",
+            "docs": [
+              "List of stablecoin mints accepted as payment"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "acceptedMint"
+                }
+              }
+            }
+          },
+          {
+            "name": "shopperCancelWindowSecs",
+            "docs": [
+              "Seconds after order creation a shopper can still cancel a pending order, 0 for no limit"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "completionDelaySecs",
+            "docs": [
+              "Seconds after shipping an order is held in escrow before completion"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "shippingDeadlineSecs",
+            "docs": [
+              "Seconds after order creation a pending order is expired and refunded, 0 for no deadline"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "feeTiers",
+            "docs": [
+              "Reduced order fees by store completed volume, ascending by volume"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeTier"
+                }
+              }
+            }
+          },
+          {
+            "name": "maxOrderFeeBps",
+            "docs": [
+              "Upper bound on any order fee in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "pendingOrderFee",
+            "docs": [
+              "Order fee increase that takes effect once its timelock has elapsed"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "pendingOrderFee"
+                }
+              }
+            }
+          },
+          {
+            "name": "pendingAdmin",
+            "docs": [
+              "Address nominated to become admin once it accepts"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "operator",
+            "docs": [
+              "Address allowed to ship, cancel, complete and resolve orders"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "treasurer",
+            "docs": [
+              "Address allowed to withdraw platform fees from the treasury"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pauser",
+            "docs": [
+              "Address allowed to pause and unpause the platform"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingFeeTiers",
+            "docs": [
+              "Fee tier change that takes effect once its timelock has elapsed"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "pendingFeeTiers"
+                }
+              }
+            }
+          },
+          {
+            "name": "pendingMaxOrderFee",
+            "docs": [
+              "Maximum order fee increase that takes effect once its timelock has elapsed"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "pendingMaxOrderFee"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "storeNameRequired",
-      "msg": "Store name is required"
+      "name": "configureStoreArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "completionDelaySecs",
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "orderFeeBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "isSuspended",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "storeNameTooLong",
-      "msg": "Store name exceeded maximum length"
+      "name": "createReviewArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "text",
+            "type": "string"
+          },
+          {
+            "name": "rating",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "itemNameRequired",
-      "msg": "Store item name is required"
+      "name": "disputeOpened",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "order",
+            "type": "pubkey"
+          },
+          {
+            "name": "shopper",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "itemNameTooLong",
-      "msg": "Store item name exceeded maximum length"
+      "name": "disputeResolved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "order",
+            "type": "pubkey"
+          },
+          {
+            "name": "refundBps",
+            "type": "u16"
+          },
+          {
+            "name": "refundAmount",
+            "type": "u64"
+          },
+          {
+            "name": "payoutAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "insufficientInventory",
-      "msg": "Store item has insufficient inventory to fulfill order"
+      "name": "earningsWithdrawn",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "store",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "destination",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "orderAlreadyFinalized",
-      "msg": "Order already finalized"
+      "name": "feeTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minVolume",
+            "docs": [
+              "Minimum store completed volume in atomic units with 6 decimals"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "orderFeeBps",
+            "docs": [
+              "Fee charged on each order in basis points"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "orderNotPending",
-      "msg": "Order status is not pending"
+      "name": "feeTiersIncreaseStaged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "effectiveTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6018,
-      "name": "orderNotBeingShipped",
-      "msg": "Order status is not shipping"
+      "name": "initializeConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "orderFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "acceptedMints",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "acceptedMint"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6019,
-      "name": "orderAlreadyCompleted",
-      "msg": "Order already completed"
+      "name": "initializeShopperArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "image",
+            "type": "string"
+          },
+          {
+            "name": "address",
+            "type": "string"
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "orderNotCompleted",
-      "msg": "Order not completed"
+      "name": "initializeStoreArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "image",
+            "type": "string"
+          },
+          {
+            "name": "about",
+            "type": "string"
+          }
+        ]
+      }
     },
     {
-      "code": 6021,
-      "name": "invalidOrderStatus",
-      "msg": "Order completion must be done through complete_order instruction"
+      "name": "item",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "store",
+            "docs": [
+              "PDA of store account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "docs": [
+              "Price in USD with 6 decimals, or in atomic units of the price mint if set"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "priceMint",
+            "docs": [
+              "Mint the price is denominated in, charged exactly without oracle conversion"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "referralBps",
+            "docs": [
+              "Share of the payment subtotal paid to the order referrer in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "inventoryCount",
+            "docs": [
+              "Remaining inventory count"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump used for seed derivation"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "name",
+            "docs": [
+              "Display name"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "image",
+            "docs": [
+              "Display image"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "description",
+            "docs": [
+              "Item description"
+            ],
+            "type": "string"
+          }
+        ]
+      }
     },
     {
-      "code": 6022,
-      "name": "invalidOrderPaymentMint",
-      "msg": "Order payment mint does not match provided mint"
+      "name": "itemListed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "item",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6023,
-      "name": "invalidRating",
-      "msg": "Rating must be between 1 and 5"
+      "name": "lineItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "item",
+            "docs": [
+              "PDA of item account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "store",
+            "docs": [
+              "PDA of store account the item belongs to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Amount of item purchased"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "paymentSubtotal",
+            "docs": [
+              "Payment subtotal owed to the store in atomic units of mint"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "orderFeeBps",
+            "docs": [
+              "Fee rate charged on the line item in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "platformFee",
+            "docs": [
+              "Platform fee in atomic units of mint"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "referralFee",
+            "docs": [
+              "Referral commission paid from the payment subtotal on completion"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "payoutRecipients",
+            "docs": [
+              "Store payout recipients at the time the order was shipped"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "payoutRecipient"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6024,
-      "name": "mathOverflow",
-      "msg": "Math operation overflow"
+      "name": "listItemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "pricingMode",
+            "type": {
+              "defined": {
+                "name": "pricingMode"
+              }
+            }
+          },
+          {
+            "name": "referralBps",
+            "type": "u16"
+          },
+          {
+            "name": "inventoryCount",
+            "type": "u32"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "image",
+            "type": "string"
+          },
+          {
+            "name": "description",
+            "type": "string"
+          }
+        ]
+      }
     },
     {
-      "code": 6025,
-      "name": "invalidPrice",
-      "msg": "Oracle price must be above 0"
-    }
-  ],
-  "types": [
-    {
-      "name": "acceptedMint",
+      "name": "maxOrderFeeIncreaseStaged",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "mint",
-            "docs": [
-              "Mint address of the stablecoin"
-            ],
-            "type": "pubkey"
+            "name": "maxOrderFeeBps",
+            "type": "u16"
           },
           {
-            "name": "priceUpdateV2",
-            "docs": [
-              "Pyth price feed account address of the stablecoin"
-            ],
-            "type": "pubkey"
+            "name": "effectiveTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "config",
+      "name": "order",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "orderFeeBps",
+            "name": "shopper",
             "docs": [
-              "Fee charged on each order in basis points"
+              "PDA of shopper account"
             ],
-            "type": "u16"
+            "type": "pubkey"
           },
           {
-            "name": "admin",
+            "name": "item",
             "docs": [
-              "Address that has authority over the config"
+              "PDA of item account"
             ],
             "type": "pubkey"
           },
           {
-            "name": "isPaused",
+            "name": "timestamp",
             "docs": [
-              "Boolean indicating if new orders can be created"
+              "Unix time of order creation"
             ],
-            "type": "bool"
+            "type": "i64"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Order status"
+            ],
+            "type": {
+              "defined": {
+                "name": "orderStatus"
+              }
+            }
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Amount of item purchased"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "shippedAmount",
+            "docs": [
+              "Amount of item shipped to the shopper"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "refundedAmount",
+            "docs": [
+              "Amount of item refunded to the shopper"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "paymentSubtotal",
+            "docs": [
+              "Payment subtotal in atomic units of mint"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "platformFee",
+            "docs": [
+              "Platform fee in atomic units of mint"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "orderFeeBps",
+            "docs": [
+              "Fee rate charged on the order in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "paymentMint",
+            "docs": [
+              "Address of stablecoin mint used for payment"
+            ],
+            "type": "pubkey"
           },
           {
             "name": "bump",
@@ -2371,186 +7243,141 @@ export type Splurge = {
             "type": "u8"
           },
           {
-            "name": "treasuryBump",
+            "name": "task",
             "docs": [
-              "Bump used for seed derivation"
+              "Tuktuk task queued to complete the order, default until shipped"
             ],
-            "type": "u8"
+            "type": "pubkey"
           },
           {
-            "name": "acceptedMints",
+            "name": "shippingDeadline",
             "docs": [
-              "List of stablecoin mints accepted as payment"
+              "Unix time after which a pending order can be expired, 0 for no deadline"
             ],
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "acceptedMint"
-                }
-              }
-            }
+            "type": "i64"
           },
           {
-            "name": "reserved",
+            "name": "referrer",
             "docs": [
-              "Reserved for future upgrades"
+              "Address credited with referring the order"
             ],
             "type": {
-              "array": [
-                "u8",
-                64
-              ]
+              "option": "pubkey"
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "createReviewArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "text",
-            "type": "string"
+            "name": "referralFee",
+            "docs": [
+              "Referral commission paid from the payment subtotal on completion"
+            ],
+            "type": "u64"
           },
           {
-            "name": "rating",
-            "type": "u8"
+            "name": "finalizedAt",
+            "docs": [
+              "Unix time the order was completed or resolved, 0 until then"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "payoutRecipients",
+            "docs": [
+              "Store payout recipients at the time the order was shipped"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "payoutRecipient"
+                }
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "initializeConfigArgs",
+      "name": "orderCancelled",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "admin",
+            "name": "order",
             "type": "pubkey"
           },
           {
-            "name": "orderFeeBps",
-            "type": "u16"
+            "name": "cancelledBy",
+            "type": "pubkey"
           },
           {
-            "name": "acceptedMints",
+            "name": "role",
             "type": {
-              "vec": {
-                "defined": {
-                  "name": "acceptedMint"
-                }
+              "defined": {
+                "name": "actorRole"
               }
             }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "initializeShopperArgs",
+      "name": "orderClosed",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "name",
-            "type": "string"
-          },
-          {
-            "name": "image",
-            "type": "string"
+            "name": "order",
+            "type": "pubkey"
           },
           {
-            "name": "address",
-            "type": "string"
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "initializeStoreArgs",
+      "name": "orderCompleted",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "name",
-            "type": "string"
-          },
-          {
-            "name": "image",
-            "type": "string"
+            "name": "order",
+            "type": "pubkey"
           },
           {
-            "name": "about",
-            "type": "string"
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "item",
+      "name": "orderCreated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "store",
-            "docs": [
-              "PDA of store account"
-            ],
+            "name": "order",
             "type": "pubkey"
           },
-          {
-            "name": "price",
-            "docs": [
-              "Price in atomic units of mint with 6 decimals"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "inventoryCount",
-            "docs": [
-              "Remaining inventory count"
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "bump",
-            "docs": [
-              "Bump used for seed derivation"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "name",
-            "docs": [
-              "Display name"
-            ],
-            "type": "string"
-          },
-          {
-            "name": "image",
-            "docs": [
-              "Display image"
-            ],
-            "type": "string"
-          },
-          {
-            "name": "description",
-            "docs": [
-              "Item description"
-            ],
-            "type": "string"
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "itemListed",
+      "name": "orderExpired",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "item",
+            "name": "order",
             "type": "pubkey"
           },
           {
@@ -2561,110 +7388,114 @@ export type Splurge = {
       }
     },
     {
-      "name": "listItemArgs",
+      "name": "orderFeeIncreaseStaged",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "price",
-            "type": "u64"
-          },
-          {
-            "name": "inventoryCount",
-            "type": "u32"
-          },
-          {
-            "name": "name",
-            "type": "string"
+            "name": "orderFeeBps",
+            "type": "u16"
           },
           {
-            "name": "image",
-            "type": "string"
+            "name": "effectiveTimestamp",
+            "type": "i64"
           },
           {
-            "name": "description",
-            "type": "string"
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "order",
+      "name": "orderPartiallyFulfilled",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "shopper",
-            "docs": [
-              "PDA of shopper account"
-            ],
+            "name": "order",
             "type": "pubkey"
           },
           {
-            "name": "item",
-            "docs": [
-              "PDA of item account"
-            ],
-            "type": "pubkey"
+            "name": "shippedAmount",
+            "type": "u32"
           },
           {
-            "name": "timestamp",
-            "docs": [
-              "Unix time of order creation"
-            ],
-            "type": "i64"
+            "name": "refundedAmount",
+            "type": "u32"
           },
           {
-            "name": "status",
-            "docs": [
-              "Order status"
-            ],
+            "name": "refundedSubtotal",
+            "type": "u64"
+          },
+          {
+            "name": "refundedFee",
+            "type": "u64"
+          },
+          {
+            "name": "shippedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "role",
             "type": {
               "defined": {
-                "name": "orderStatus"
+                "name": "actorRole"
               }
             }
           },
           {
-            "name": "amount",
-            "docs": [
-              "Amount of item purchased"
-            ],
-            "type": "u32"
-          },
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "orderQuote",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
             "name": "paymentSubtotal",
             "docs": [
-              "Payment subtotal in atomic units of mint"
+              "Payment for the items in atomic units of the payment mint"
             ],
             "type": "u64"
           },
           {
             "name": "platformFee",
             "docs": [
-              "Platform fee in atomic units of mint"
+              "Platform fee in atomic units of the payment mint"
             ],
             "type": "u64"
           },
           {
-            "name": "paymentMint",
+            "name": "orderFeeBps",
             "docs": [
-              "Address of stablecoin mint used for payment"
+              "Fee rate applied to the store in basis points"
             ],
-            "type": "pubkey"
+            "type": "u16"
           },
           {
-            "name": "bump",
+            "name": "oraclePrice",
             "docs": [
-              "Bump used for seed derivation"
+              "Oracle price used for conversion, 0 for fixed-price items"
             ],
-            "type": "u8"
+            "type": "i64"
+          },
+          {
+            "name": "oracleExponent",
+            "docs": [
+              "Exponent of the oracle price, 0 for fixed-price items"
+            ],
+            "type": "i32"
           }
         ]
       }
     },
     {
-      "name": "orderCancelled",
+      "name": "orderShipped",
       "type": {
         "kind": "struct",
         "fields": [
@@ -2672,6 +7503,18 @@ export type Splurge = {
             "name": "order",
             "type": "pubkey"
           },
+          {
+            "name": "shippedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "role",
+            "type": {
+              "defined": {
+                "name": "actorRole"
+              }
+            }
+          },
           {
             "name": "timestamp",
             "type": "i64"
@@ -2680,12 +7523,42 @@ export type Splurge = {
       }
     },
     {
-      "name": "orderCompleted",
+      "name": "orderStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "pending"
+          },
+          {
+            "name": "shipping"
+          },
+          {
+            "name": "cancelled"
+          },
+          {
+            "name": "completed"
+          },
+          {
+            "name": "disputed"
+          },
+          {
+            "name": "resolved"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pausedOperationsUpdated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "order",
+            "name": "pausedOperations",
+            "type": "u8"
+          },
+          {
+            "name": "updatedBy",
             "type": "pubkey"
           },
           {
@@ -2696,53 +7569,119 @@ export type Splurge = {
       }
     },
     {
-      "name": "orderCreated",
+      "name": "payoutRecipient",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "order",
+            "name": "recipient",
+            "docs": [
+              "Wallet address paid through its associated token account"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
+            "name": "shareBps",
+            "docs": [
+              "Share of each payout in basis points"
+            ],
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "orderShipped",
+      "name": "pendingFeeTiers",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "order",
-            "type": "pubkey"
+            "name": "feeTiers",
+            "docs": [
+              "Fee tiers once effective, ascending by volume"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeTier"
+                }
+              }
+            }
           },
           {
-            "name": "timestamp",
+            "name": "effectiveTimestamp",
+            "docs": [
+              "Unix time the fee tiers take effect"
+            ],
             "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "orderStatus",
+      "name": "pendingMaxOrderFee",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "pending"
+            "name": "maxOrderFeeBps",
+            "docs": [
+              "Upper bound on any order fee in basis points once effective"
+            ],
+            "type": "u16"
           },
           {
-            "name": "shipping"
+            "name": "effectiveTimestamp",
+            "docs": [
+              "Unix time the maximum takes effect"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingOrderFee",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "orderFeeBps",
+            "docs": [
+              "Fee charged on each order in basis points once effective"
+            ],
+            "type": "u16"
           },
           {
-            "name": "cancelled"
+            "name": "effectiveTimestamp",
+            "docs": [
+              "Unix time the fee takes effect"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingStoreOrderFee",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "orderFeeBps",
+            "docs": [
+              "Order fee override in basis points once effective, `None` to clear the override"
+            ],
+            "type": {
+              "option": "u16"
+            }
           },
           {
-            "name": "completed"
+            "name": "effectiveTimestamp",
+            "docs": [
+              "Unix time the override takes effect"
+            ],
+            "type": "i64"
           }
         ]
       }
@@ -2841,16 +7780,92 @@ export type Splurge = {
             }
           },
           {
-            "name": "priceMessage",
-            "type": {
-              "defined": {
-                "name": "priceFeedMessage"
-              }
-            }
+            "name": "priceMessage",
+            "type": {
+              "defined": {
+                "name": "priceFeedMessage"
+              }
+            }
+          },
+          {
+            "name": "postedSlot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priceVerificationLevel",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "partial",
+            "fields": [
+              {
+                "name": "numSignatures",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "full"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pricingMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "oracle"
+          },
+          {
+            "name": "fixed",
+            "fields": [
+              {
+                "name": "priceMint",
+                "type": "pubkey"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "referralPaid",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "order",
+            "type": "pubkey"
+          },
+          {
+            "name": "referrer",
+            "type": "pubkey"
           },
           {
-            "name": "postedSlot",
+            "name": "amount",
             "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "resolveDisputeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "refundBps",
+            "type": "u16"
           }
         ]
       }
@@ -2898,6 +7913,30 @@ export type Splurge = {
         ]
       }
     },
+    {
+      "name": "rolesUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "operator",
+            "type": "pubkey"
+          },
+          {
+            "name": "treasurer",
+            "type": "pubkey"
+          },
+          {
+            "name": "pauser",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
     {
       "name": "shopper",
       "type": {
@@ -3000,6 +8039,64 @@ export type Splurge = {
               "Store description"
             ],
             "type": "string"
+          },
+          {
+            "name": "completionDelaySecs",
+            "docs": [
+              "Overrides the config escrow hold period for this store if set"
+            ],
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "orderFeeBps",
+            "docs": [
+              "Overrides the config order fee for this store if set"
+            ],
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "completedVolume",
+            "docs": [
+              "Lifetime completed order volume in atomic units with 6 decimals"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "isSuspended",
+            "docs": [
+              "Boolean indicating if the store is suspended by the admin"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "payoutRecipients",
+            "docs": [
+              "Recipients of completed order payouts by share, paid to the store if empty"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "payoutRecipient"
+                }
+              }
+            }
+          },
+          {
+            "name": "pendingOrderFee",
+            "docs": [
+              "Order fee override change that takes effect once its timelock has elapsed"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "pendingStoreOrderFee"
+                }
+              }
+            }
           }
         ]
       }
@@ -3024,6 +8121,60 @@ export type Splurge = {
         ]
       }
     },
+    {
+      "name": "storeOrderFeeIncreaseStaged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "store",
+            "type": "pubkey"
+          },
+          {
+            "name": "orderFeeBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "effectiveTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "treasuryWithdrawn",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "treasurer",
+            "type": "pubkey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "destination",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
     {
       "name": "updateConfigArgs",
       "type": {
@@ -3036,9 +8187,9 @@ export type Splurge = {
             }
           },
           {
-            "name": "isPaused",
+            "name": "pausedOperations",
             "type": {
-              "option": "bool"
+              "option": "u8"
             }
           },
           {
@@ -3047,6 +8198,12 @@ export type Splurge = {
               "option": "u16"
             }
           },
+          {
+            "name": "maxOrderFeeBps",
+            "type": {
+              "option": "u16"
+            }
+          },
           {
             "name": "acceptedMints",
             "type": {
@@ -3058,6 +8215,36 @@ export type Splurge = {
                 }
               }
             }
+          },
+          {
+            "name": "shopperCancelWindowSecs",
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "completionDelaySecs",
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "shippingDeadlineSecs",
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "feeTiers",
+            "type": {
+              "option": {
+                "vec": {
+                  "defined": {
+                    "name": "feeTier"
+                  }
+                }
+              }
+            }
           }
         ]
       }
@@ -3073,6 +8260,22 @@ export type Splurge = {
               "option": "u64"
             }
           },
+          {
+            "name": "pricingMode",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "pricingMode"
+                }
+              }
+            }
+          },
+          {
+            "name": "referralBps",
+            "type": {
+              "option": "u16"
+            }
+          },
           {
             "name": "inventoryCount",
             "type": {
@@ -3082,6 +8285,32 @@ export type Splurge = {
         ]
       }
     },
+    {
+      "name": "updateRolesArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "operator",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "treasurer",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "pauser",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "verificationLevel",
       "docs": [
@@ -3117,25 +8346,55 @@ export type Splurge = {
     }
   ],
   "constants": [
+    {
+      "name": "cartOrderSeed",
+      "type": "bytes",
+      "value": "[99, 97, 114, 116, 95, 111, 114, 100, 101, 114]"
+    },
     {
       "name": "configSeed",
       "type": "bytes",
       "value": "[99, 111, 110, 102, 105, 103]"
     },
+    {
+      "name": "defaultCompletionDelaySecs",
+      "type": "u32",
+      "value": "60"
+    },
+    {
+      "name": "defaultMaxOrderFeeBps",
+      "type": "u16",
+      "value": "1000"
+    },
     {
       "name": "itemSeed",
       "type": "bytes",
       "value": "[105, 116, 101, 109]"
     },
+    {
+      "name": "maxCartItems",
+      "type": "u8",
+      "value": "8"
+    },
+    {
+      "name": "maxCompletionDelaySecs",
+      "type": "u32",
+      "value": "2592000"
+    },
+    {
+      "name": "maxFeeTiers",
+      "type": "u8",
+      "value": "4"
+    },
     {
       "name": "maxItemNameLen",
       "type": "u8",
       "value": "32"
     },
     {
-      "name": "maxOracleStaleness",
+      "name": "maxPayoutRecipients",
       "type": "u8",
-      "value": "60"
+      "value": "4"
     },
     {
       "name": "maxShopperNameLen",
@@ -3147,16 +8406,66 @@ export type Splurge = {
       "type": "u8",
       "value": "64"
     },
+    {
+      "name": "minCompletionDelaySecs",
+      "type": "u32",
+      "value": "60"
+    },
+    {
+      "name": "orderFeeTimelockSecs",
+      "type": "u32",
+      "value": "604800"
+    },
     {
       "name": "orderSeed",
       "type": "bytes",
       "value": "[111, 114, 100, 101, 114]"
     },
+    {
+      "name": "pauseAll",
+      "type": "u8",
+      "value": "31"
+    },
+    {
+      "name": "pauseCompletion",
+      "type": "u8",
+      "value": "4"
+    },
+    {
+      "name": "pauseListing",
+      "type": "u8",
+      "value": "16"
+    },
+    {
+      "name": "pauseOrdering",
+      "type": "u8",
+      "value": "1"
+    },
+    {
+      "name": "pauseShipping",
+      "type": "u8",
+      "value": "2"
+    },
+    {
+      "name": "pauseWithdrawals",
+      "type": "u8",
+      "value": "8"
+    },
+    {
+      "name": "priceDecimals",
+      "type": "u8",
+      "value": "6"
+    },
     {
       "name": "reviewSeed",
       "type": "bytes",
       "value": "[114, 101, 118, 105, 101, 119]"
     },
+    {
+      "name": "reviewWindowSecs",
+      "type": "u32",
+      "value": "2592000"
+    },
     {
       "name": "shopperSeed",
       "type": "bytes",
@@ -3167,11 +8476,6 @@ export type Splurge = {
       "type": "bytes",
       "value": "[115, 116, 111, 114, 101]"
     },
-    {
-      "name": "taskTriggerDelay",
-      "type": "u16",
-      "value": "60"
-    },
     {
       "name": "treasurySeed",
       "type": "bytes",
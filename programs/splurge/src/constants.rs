@@ -13,6 +13,8 @@ pub const ITEM_SEED: &[u8] = b"item";
 #[constant]
 pub const ORDER_SEED: &[u8] = b"order";
 #[constant]
+pub const CART_ORDER_SEED: &[u8] = b"cart_order";
+#[constant]
 pub const REVIEW_SEED: &[u8] = b"review";
 #[constant]
 pub const MAX_SHOPPER_NAME_LEN: u8 = 64;
@@ -21,6 +23,34 @@ pub const MAX_STORE_NAME_LEN: u8 = 64;
 #[constant]
 pub const MAX_ITEM_NAME_LEN: u8 = 32;
 #[constant]
-pub const MAX_ORACLE_STALENESS: u8 = 60;
+pub const DEFAULT_COMPLETION_DELAY_SECS: u32 = 60;
+#[constant]
+pub const MIN_COMPLETION_DELAY_SECS: u32 = 60;
+#[constant]
+pub const MAX_COMPLETION_DELAY_SECS: u32 = 2_592_000;
+#[constant]
+pub const REVIEW_WINDOW_SECS: u32 = 2_592_000;
+#[constant]
+pub const PRICE_DECIMALS: u8 = 6;
+#[constant]
+pub const MAX_CART_ITEMS: u8 = 8;
+#[constant]
+pub const MAX_FEE_TIERS: u8 = 4;
+#[constant]
+pub const DEFAULT_MAX_ORDER_FEE_BPS: u16 = 1_000;
+#[constant]
+pub const ORDER_FEE_TIMELOCK_SECS: u32 = 604_800;
+#[constant]
+pub const MAX_PAYOUT_RECIPIENTS: u8 = 4;
+#[constant]
+pub const PAUSE_ORDERING: u8 = 1;
+#[constant]
+pub const PAUSE_SHIPPING: u8 = 2;
+#[constant]
+pub const PAUSE_COMPLETION: u8 = 4;
+#[constant]
+pub const PAUSE_WITHDRAWALS: u8 = 8;
+#[constant]
+pub const PAUSE_LISTING: u8 = 16;
 #[constant]
-pub const TASK_TRIGGER_DELAY: u16 = 60;
+pub const PAUSE_ALL: u8 = 31;
@@ -10,12 +10,42 @@ pub enum SplurgeError {
     EmptyAcceptedMints,
     #[msg("Signer not authorized as config admin")]
     UnauthorizedAdmin,
-    #[msg("Platform paused, no new orders can be created")]
-    PlatformPaused,
+    #[msg("No admin transfer is pending")]
+    NoPendingAdmin,
+    #[msg("Signer is not the pending admin")]
+    UnauthorizedPendingAdmin,
+    #[msg("Signer not authorized as config operator")]
+    UnauthorizedOperator,
+    #[msg("Signer not authorized as config treasurer")]
+    UnauthorizedTreasurer,
+    #[msg("Signer not authorized as config pauser")]
+    UnauthorizedPauser,
+    #[msg("Signer not authorized to ship order")]
+    UnauthorizedShipper,
+    #[msg("Ordering paused, no new orders can be created")]
+    OrderingPaused,
+    #[msg("Shipping paused, orders cannot be shipped")]
+    ShippingPaused,
+    #[msg("Completion paused, orders cannot be completed")]
+    CompletionPaused,
+    #[msg("Withdrawals paused, funds cannot be withdrawn")]
+    WithdrawalsPaused,
+    #[msg("Listing paused, items cannot be listed or updated")]
+    ListingPaused,
+    #[msg("Pause flags contain unknown operations")]
+    InvalidPauseFlags,
+    #[msg("Payment mint is paused")]
+    PaymentMintPaused,
+    #[msg("Store is suspended")]
+    StoreSuspended,
     #[msg("Payment mint is not accepted")]
     PaymentMintNotAccepted,
-    #[msg("Price update v2 does not match with any accepted mint")]
-    InvalidPriceUpdateV2,
+    #[msg("Price update feed ID does not match accepted mint")]
+    InvalidPriceFeedId,
+    #[msg("Price update verification level is insufficient")]
+    InsufficientVerificationLevel,
+    #[msg("Completion delay is out of bounds")]
+    InvalidCompletionDelay,
     #[msg("Shopper name is required")]
     ShopperNameRequired,
     #[msg("Shopper name exceeded maximum length")]
@@ -38,20 +68,100 @@ pub enum SplurgeError {
     OrderAlreadyFinalized,
     #[msg("Order status is not pending")]
     OrderNotPending,
+    #[msg("Shopper cancellation window has elapsed")]
+    CancellationWindowElapsed,
+    #[msg("Fulfilled amount must be above 0 and below order amount")]
+    InvalidFulfillmentAmount,
+    #[msg("Order shipping deadline has not elapsed")]
+    OrderNotExpired,
+    #[msg("Tuktuk accounts and task ID are required to queue order expiry")]
+    MissingExpiryTask,
+    #[msg("Order token account does not match order")]
+    InvalidOrderTokenAccount,
     #[msg("Order status is not shipping")]
     OrderNotBeingShipped,
+    #[msg("Order status is not disputed")]
+    OrderNotDisputed,
+    #[msg("Refund basis points cannot exceed 10000")]
+    InvalidRefundBps,
+    #[msg("Fee basis points exceed maximum order fee")]
+    InvalidFeeBps,
+    #[msg("Fee tiers must be ascending by volume and within the maximum count")]
+    InvalidFeeTiers,
+    #[msg("Payout shares must sum to 10000 across unique recipients within the maximum count")]
+    InvalidPayoutRecipients,
+    #[msg("Payout token accounts do not match store payout recipients")]
+    InvalidPayoutTokenAccounts,
+    #[msg("Referral basis points cannot exceed 10000")]
+    InvalidReferralBps,
+    #[msg("Shopper cannot refer their own order")]
+    SelfReferral,
+    #[msg("Referrer token account is required to complete a referred order")]
+    MissingReferrerTokenAccount,
+    #[msg("Referrer token account does not match order referrer")]
+    InvalidReferrerTokenAccount,
+    #[msg("Withdrawal amount must be above 0 and within balance")]
+    InvalidWithdrawalAmount,
     #[msg("Order already completed")]
     OrderAlreadyCompleted,
     #[msg("Order not completed")]
     OrderNotCompleted,
+    #[msg("Task does not match order completion task")]
+    InvalidOrderTask,
     #[msg("Order completion must be done through complete_order instruction")]
     InvalidOrderStatus,
     #[msg("Order payment mint does not match provided mint")]
     InvalidOrderPaymentMint,
+    #[msg("Cart must contain at least one item")]
+    EmptyCart,
+    #[msg("Cart exceeded maximum number of items")]
+    CartTooLarge,
+    #[msg("Number of cart accounts does not match number of amounts")]
+    CartAccountsMismatch,
+    #[msg("Cart contains duplicate items")]
+    DuplicateCartItem,
+    #[msg("Cart subtotal or fee does not match sum of line items")]
+    InvalidCartSubtotal,
+    #[msg("Store token account does not match line item store")]
+    InvalidStoreTokenAccount,
+    #[msg("Item does not match line item")]
+    InvalidCartItem,
+    #[msg("Store does not match line item store")]
+    InvalidCartStore,
+    #[msg("Order must be completed, cancelled or resolved to be closed")]
+    OrderNotFinalized,
+    #[msg("Order cannot be closed before its shipping deadline")]
+    ExpiryTaskPending,
+    #[msg("Order review window has not elapsed")]
+    ReviewWindowOpen,
+    #[msg("Order with a review cannot be closed")]
+    OrderHasReview,
     #[msg("Rating must be between 1 and 5")]
     InvalidRating,
     #[msg("Math operation overflow")]
     MathOverflow,
+    #[msg("Payment exceeds maximum payment")]
+    SlippageExceeded,
     #[msg("Oracle price must be above 0")]
     InvalidPrice,
+    #[msg("Oracle price is older than maximum staleness")]
+    StalePrice,
+    #[msg("Oracle confidence interval exceeds maximum ratio")]
+    PriceConfidenceTooWide,
+    #[msg("Oracle price is below minimum accepted price")]
+    PriceBelowMinimum,
+    #[msg("Oracle price is above maximum accepted price")]
+    PriceAboveMaximum,
+    #[msg("Accepted mint oracle config is invalid")]
+    InvalidOracleConfig,
+    #[msg("Price update is required for oracle-priced items")]
+    MissingPriceUpdate,
+    #[msg("Fixed-price item must be paid in its price mint")]
+    FixedPriceMintMismatch,
+    #[msg("Referrer does not match order referrer")]
+    InvalidReferrer,
+    #[msg("Price is required when changing pricing mode")]
+    PriceRequired,
+    #[msg("Referral fee exceeds payment subtotal")]
+    InvalidReferralFee,
 }
@@ -1,5 +1,12 @@
 use anchor_lang::prelude::*;
 
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq)]
+pub enum ActorRole {
+    Operator,
+    Shopper,
+    Store,
+}
+
 #[event]
 pub struct StoreInitialized {
     pub store: Pubkey,
@@ -20,6 +27,78 @@ pub struct ItemListed {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct AdminTransferStarted {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferAccepted {
+    pub previous_admin: Pubkey,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferCancelled {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RolesUpdated {
+    pub operator: Pubkey,
+    pub treasurer: Pubkey,
+    pub pauser: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PausedOperationsUpdated {
+    pub paused_operations: u8,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AcceptedMintPauseUpdated {
+    pub mint: Pubkey,
+    pub is_paused: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrderFeeIncreaseStaged {
+    pub order_fee_bps: u16,
+    pub effective_timestamp: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MaxOrderFeeIncreaseStaged {
+    pub max_order_fee_bps: u16,
+    pub effective_timestamp: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StoreOrderFeeIncreaseStaged {
+    pub store: Pubkey,
+    pub order_fee_bps: Option<u16>,
+    pub effective_timestamp: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeTiersIncreaseStaged {
+    pub effective_timestamp: i64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct OrderCreated {
     pub order: Pubkey,
@@ -29,12 +108,28 @@ pub struct OrderCreated {
 #[event]
 pub struct OrderShipped {
     pub order: Pubkey,
+    pub shipped_by: Pubkey,
+    pub role: ActorRole,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrderPartiallyFulfilled {
+    pub order: Pubkey,
+    pub shipped_amount: u32,
+    pub refunded_amount: u32,
+    pub refunded_subtotal: u64,
+    pub refunded_fee: u64,
+    pub shipped_by: Pubkey,
+    pub role: ActorRole,
     pub timestamp: i64,
 }
 
 #[event]
 pub struct OrderCancelled {
     pub order: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub role: ActorRole,
     pub timestamp: i64,
 }
 
@@ -43,3 +138,88 @@ pub struct OrderCompleted {
     pub order: Pubkey,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct OrderExpired {
+    pub order: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrderClosed {
+    pub order: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeOpened {
+    pub order: Pubkey,
+    pub shopper: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub order: Pubkey,
+    pub refund_bps: u16,
+    pub refund_amount: u64,
+    pub payout_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CartOrderCreated {
+    pub cart_order: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CartOrderShipped {
+    pub cart_order: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CartOrderCancelled {
+    pub cart_order: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CartOrderCompleted {
+    pub cart_order: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CartOrderClosed {
+    pub cart_order: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EarningsWithdrawn {
+    pub store: Pubkey,
+    pub authority: Pubkey,
+    pub payment_mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub treasurer: Pubkey,
+    pub payment_mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralPaid {
+    pub order: Pubkey,
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::CONFIG_SEED, error::SplurgeError, events::AdminTransferAccepted, state::Config,
+};
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub pending_admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.pending_admin.is_some() @ SplurgeError::NoPendingAdmin,
+        constraint = config.pending_admin == Some(pending_admin.key()) @ SplurgeError::UnauthorizedPendingAdmin,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl AcceptAdmin<'_> {
+    pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let AcceptAdmin {
+            pending_admin,
+            config,
+        } = ctx.accounts;
+
+        let previous_admin = config.admin;
+
+        config.admin = pending_admin.key();
+        config.pending_admin = None;
+
+        emit!(AdminTransferAccepted {
+            previous_admin,
+            admin: config.admin,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Config::invariant(&config)
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::CONFIG_SEED, error::SplurgeError, events::AdminTransferCancelled, state::Config,
+};
+
+#[derive(Accounts)]
+pub struct CancelAdminTransfer<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ SplurgeError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl CancelAdminTransfer<'_> {
+    pub fn handler(ctx: Context<CancelAdminTransfer>) -> Result<()> {
+        let CancelAdminTransfer { admin, config } = ctx.accounts;
+
+        let pending_admin = config
+            .pending_admin
+            .take()
+            .ok_or(SplurgeError::NoPendingAdmin)?;
+
+        emit!(AdminTransferCancelled {
+            admin: admin.key(),
+            pending_admin,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Config::invariant(&config)
+    }
+}
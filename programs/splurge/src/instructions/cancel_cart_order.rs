@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+
+use crate::{
+    cart_order_signer,
+    constants::{CART_ORDER_SEED, CONFIG_SEED, TREASURY_SEED},
+    error::SplurgeError,
+    events::CartOrderCancelled,
+    state::{CartOrder, Config, Item, OrderStatus, Shopper},
+    treasury_signer,
+};
+
+#[derive(Accounts)]
+pub struct CancelCartOrder<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = config.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+    #[account(mut)]
+    pub authority: SystemAccount<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = operator @ SplurgeError::UnauthorizedOperator,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        has_one = authority @ SplurgeError::InvalidShopperAuthority,
+    )]
+    pub shopper: Account<'info, Shopper>,
+    #[account(
+        mut,
+        seeds = [CART_ORDER_SEED, shopper.key().as_ref(), cart_order.timestamp.to_le_bytes().as_ref()],
+        bump = cart_order.bump,
+        constraint = cart_order.status == OrderStatus::Pending || cart_order.status == OrderStatus::Shipping @ SplurgeError::OrderAlreadyFinalized,
+        constraint = cart_order.payment_mint == payment_mint.key() @ SplurgeError::InvalidOrderPaymentMint,
+    )]
+    pub cart_order: Account<'info, CartOrder>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = cart_order,
+        associated_token::token_program = token_program,
+    )]
+    pub cart_order_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = operator,
+        associated_token::mint = payment_mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program,
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl CancelCartOrder<'_> {
+    /// Item accounts are passed as writable remaining accounts, one per line item in order
+    pub fn handler<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelCartOrder<'info>>,
+    ) -> Result<()> {
+        let CancelCartOrder {
+            authority,
+            authority_token_account,
+            cart_order,
+            cart_order_token_account,
+            config,
+            payment_mint,
+            shopper,
+            token_program,
+            treasury,
+            treasury_token_account,
+            ..
+        } = ctx.accounts;
+
+        require_eq!(
+            ctx.remaining_accounts.len(),
+            cart_order.line_items.len(),
+            SplurgeError::CartAccountsMismatch
+        );
+
+        for (line_item, item_info) in cart_order
+            .line_items
+            .iter()
+            .zip(ctx.remaining_accounts.iter())
+        {
+            require_keys_eq!(
+                item_info.key(),
+                line_item.item,
+                SplurgeError::InvalidCartItem
+            );
+            require!(item_info.is_writable, ErrorCode::ConstraintMut);
+
+            let mut item = Account::<Item>::try_from(item_info)?;
+
+            item.inventory_count = item
+                .inventory_count
+                .checked_add(line_item.amount)
+                .ok_or(SplurgeError::MathOverflow)?;
+
+            Item::invariant(&item)?;
+            item.exit(&crate::ID)?;
+        }
+
+        cart_order.status = OrderStatus::Cancelled;
+
+        let treasury_signer_seeds: &[&[u8]] = treasury_signer!(config.treasury_bump);
+        let shopper_key = shopper.key();
+        let timestamp_bytes = cart_order.timestamp.to_le_bytes();
+        let cart_order_signer_seeds: &[&[u8]] =
+            cart_order_signer!(shopper_key, timestamp_bytes, cart_order.bump);
+        let decimals = payment_mint.decimals;
+
+        transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                TransferChecked {
+                    authority: cart_order.to_account_info(),
+                    mint: payment_mint.to_account_info(),
+                    from: cart_order_token_account.to_account_info(),
+                    to: authority_token_account.to_account_info(),
+                },
+            )
+            .with_signer(&[cart_order_signer_seeds]),
+            cart_order_token_account.amount,
+            decimals,
+        )?;
+
+        close_account(
+            CpiContext::new(
+                token_program.to_account_info(),
+                CloseAccount {
+                    authority: cart_order.to_account_info(),
+                    account: cart_order_token_account.to_account_info(),
+                    destination: authority.to_account_info(),
+                },
+            )
+            .with_signer(&[cart_order_signer_seeds]),
+        )?;
+
+        transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                TransferChecked {
+                    authority: treasury.to_account_info(),
+                    mint: payment_mint.to_account_info(),
+                    from: treasury_token_account.to_account_info(),
+                    to: authority_token_account.to_account_info(),
+                },
+            )
+            .with_signer(&[treasury_signer_seeds]),
+            cart_order.platform_fee,
+            decimals,
+        )?;
+
+        emit!(CartOrderCancelled {
+            cart_order: cart_order.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -8,16 +8,16 @@ use anchor_spl::{
 use crate::{
     constants::{CONFIG_SEED, ORDER_SEED, TREASURY_SEED},
     error::SplurgeError,
-    events::OrderCancelled,
+    events::{ActorRole, OrderCancelled},
     order_signer,
-    state::{Config, Order, OrderStatus, Shopper},
+    state::{Config, Item, Order, OrderStatus, Shopper},
     treasury_signer,
 };
 
 #[derive(Accounts)]
 pub struct CancelOrder<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub operator: Signer<'info>,
     #[account(
         seeds = [TREASURY_SEED],
         bump = config.treasury_bump,
@@ -28,16 +28,18 @@ pub struct CancelOrder<'info> {
     #[account(
         seeds = [CONFIG_SEED],
         bump = config.bump,
-        has_one = admin @ SplurgeError::UnauthorizedAdmin,
+        has_one = operator @ SplurgeError::UnauthorizedOperator,
     )]
     pub config: Account<'info, Config>,
     #[account(
         has_one = authority @ SplurgeError::InvalidShopperAuthority,
     )]
     pub shopper: Account<'info, Shopper>,
+    #[account(mut)]
+    pub item: Account<'info, Item>,
     #[account(
         mut,
-        seeds = [ORDER_SEED, shopper.key().as_ref(), order.item.key().as_ref(), order.timestamp.to_le_bytes().as_ref()],
+        seeds = [ORDER_SEED, shopper.key().as_ref(), item.key().as_ref(), order.timestamp.to_le_bytes().as_ref()],
         bump = order.bump,
         constraint = order.status == OrderStatus::Pending || order.status == OrderStatus::Shipping @ SplurgeError::OrderAlreadyFinalized,
         constraint = order.payment_mint == payment_mint.key() @ SplurgeError::InvalidOrderPaymentMint,
@@ -63,7 +65,7 @@ pub struct CancelOrder<'info> {
     pub order_token_account: InterfaceAccount<'info, TokenAccount>,
     #[account(
         init_if_needed,
-        payer = admin,
+        payer = operator,
         associated_token::mint = payment_mint,
         associated_token::authority = authority,
         associated_token::token_program = token_program,
@@ -77,9 +79,11 @@ pub struct CancelOrder<'info> {
 impl CancelOrder<'_> {
     pub fn handler(ctx: Context<CancelOrder>) -> Result<()> {
         let CancelOrder {
+            operator,
             authority,
             authority_token_account,
             config,
+            item,
             order,
             order_token_account,
             payment_mint,
@@ -90,11 +94,18 @@ impl CancelOrder<'_> {
             ..
         } = ctx.accounts;
 
+        item.inventory_count = item
+            .inventory_count
+            .checked_add(order.amount - order.refunded_amount)
+            .ok_or(SplurgeError::MathOverflow)?;
+
         order.status = OrderStatus::Cancelled;
+        order.shipped_amount = 0;
+        order.refunded_amount = order.amount;
 
         let treasury_signer_seeds: &[&[u8]] = treasury_signer!(config.treasury_bump);
         let shopper_key = shopper.key();
-        let item_key = order.item.key();
+        let item_key = item.key();
         let timestamp_bytes = order.timestamp.to_le_bytes();
         let order_signer_seeds: &[&[u8]] =
             order_signer!(shopper_key, item_key, timestamp_bytes, order.bump);
@@ -144,9 +155,11 @@ impl CancelOrder<'_> {
 
         emit!(OrderCancelled {
             order: order.key(),
+            cancelled_by: operator.key(),
+            role: ActorRole::Operator,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
-        Ok(())
+        Item::invariant(&item)
     }
 }
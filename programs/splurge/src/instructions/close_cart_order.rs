@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CART_ORDER_SEED, SHOPPER_SEED},
+    events::CartOrderClosed,
+    state::{CartOrder, Shopper},
+};
+
+#[derive(Accounts)]
+pub struct CloseCartOrder<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [SHOPPER_SEED, authority.key().as_ref()],
+        bump = shopper.bump,
+    )]
+    pub shopper: Account<'info, Shopper>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [CART_ORDER_SEED, shopper.key().as_ref(), cart_order.timestamp.to_le_bytes().as_ref()],
+        bump = cart_order.bump,
+    )]
+    pub cart_order: Account<'info, CartOrder>,
+}
+
+impl CloseCartOrder<'_> {
+    pub fn handler(ctx: Context<CloseCartOrder>) -> Result<()> {
+        let CloseCartOrder { cart_order, .. } = ctx.accounts;
+
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        cart_order.validate_closable(timestamp)?;
+
+        emit!(CartOrderClosed {
+            cart_order: cart_order.key(),
+            timestamp,
+        });
+
+        Ok(())
+    }
+}
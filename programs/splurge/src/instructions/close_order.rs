@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ORDER_SEED, SHOPPER_SEED},
+    events::OrderClosed,
+    state::{Order, Shopper},
+};
+
+#[derive(Accounts)]
+pub struct CloseOrder<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [SHOPPER_SEED, authority.key().as_ref()],
+        bump = shopper.bump,
+    )]
+    pub shopper: Account<'info, Shopper>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [ORDER_SEED, shopper.key().as_ref(), order.item.key().as_ref(), order.timestamp.to_le_bytes().as_ref()],
+        bump = order.bump,
+    )]
+    pub order: Account<'info, Order>,
+}
+
+impl CloseOrder<'_> {
+    pub fn handler(ctx: Context<CloseOrder>) -> Result<()> {
+        let CloseOrder { order, .. } = ctx.accounts;
+
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        order.validate_closable(timestamp)?;
+
+        emit!(OrderClosed {
+            order: order.key(),
+            timestamp,
+        });
+
+        Ok(())
+    }
+}
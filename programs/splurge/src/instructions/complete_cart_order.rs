@@ -0,0 +1,259 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{get_associated_token_address_with_program_id, AssociatedToken},
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+
+use crate::{
+    cart_order_signer,
+    constants::{CART_ORDER_SEED, CONFIG_SEED, PAUSE_COMPLETION},
+    error::SplurgeError,
+    events::{CartOrderCompleted, ReferralPaid},
+    state::{CartOrder, Config, OrderStatus, Shopper, Store},
+    utils::{
+        create_token_account_idempotent, get_referral_payout, get_store_payouts,
+        init_payout_token_accounts, CreateTokenAccounts,
+    },
+};
+
+#[derive(Accounts)]
+pub struct CompleteCartOrder<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+    #[account(mut)]
+    pub authority: SystemAccount<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = operator @ SplurgeError::UnauthorizedOperator,
+        constraint = !config.is_paused(PAUSE_COMPLETION) @ SplurgeError::CompletionPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        has_one = authority @ SplurgeError::InvalidShopperAuthority,
+    )]
+    pub shopper: Account<'info, Shopper>,
+    #[account(
+        mut,
+        seeds = [CART_ORDER_SEED, shopper.key().as_ref(), cart_order.timestamp.to_le_bytes().as_ref()],
+        bump = cart_order.bump,
+        constraint = cart_order.status == OrderStatus::Shipping @ SplurgeError::OrderNotBeingShipped,
+        constraint = cart_order.payment_mint == payment_mint.key() @ SplurgeError::InvalidOrderPaymentMint,
+    )]
+    pub cart_order: Account<'info, CartOrder>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = cart_order,
+        associated_token::token_program = token_program,
+    )]
+    pub cart_order_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Wallet of the order referrer, authority of the referrer token account
+    #[account(
+        constraint = cart_order.referrer == Some(referrer.key()) @ SplurgeError::InvalidReferrer,
+    )]
+    pub referrer: Option<UncheckedAccount<'info>>,
+    #[account(
+        init_if_needed,
+        payer = operator,
+        associated_token::mint = payment_mint,
+        associated_token::authority = referrer,
+        associated_token::token_program = token_program,
+    )]
+    pub referrer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl CompleteCartOrder<'_> {
+    /// For each line item in order, the store and store token account are passed as writable
+    /// remaining accounts, followed by the line item's payout recipient wallet and writable token
+    /// account pairs. Missing token accounts are created, paid by the operator
+    pub fn handler<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CompleteCartOrder<'info>>,
+    ) -> Result<()> {
+        let CompleteCartOrder {
+            operator,
+            authority,
+            cart_order,
+            cart_order_token_account,
+            payment_mint,
+            referrer_token_account,
+            shopper,
+            system_program,
+            token_program,
+            associated_token_program,
+            ..
+        } = ctx.accounts;
+
+        require_eq!(
+            ctx.remaining_accounts.len(),
+            cart_order
+                .line_items
+                .iter()
+                .map(|line_item| 2 + line_item.payout_recipients.len() * 2)
+                .sum::<usize>(),
+            SplurgeError::CartAccountsMismatch
+        );
+
+        let shopper_key = shopper.key();
+        let order_timestamp = cart_order.timestamp.to_le_bytes();
+
+        let signer_seeds: &[&[u8]] =
+            cart_order_signer!(shopper_key, order_timestamp, cart_order.bump);
+
+        let referral_payout = get_referral_payout(
+            cart_order.get_referrer_token_account(&payment_mint.key(), &token_program.key()),
+            cart_order.get_referral_fee()?,
+            referrer_token_account.as_ref(),
+        )?;
+        let referral_fee = referral_payout
+            .as_ref()
+            .map_or(0, |(_, referral_fee)| *referral_fee);
+
+        if let Some((token_account, amount)) = referral_payout {
+            transfer_checked(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        authority: cart_order.to_account_info(),
+                        mint: payment_mint.to_account_info(),
+                        from: cart_order_token_account.to_account_info(),
+                        to: token_account,
+                    },
+                )
+                .with_signer(&[signer_seeds]),
+                amount,
+                payment_mint.decimals,
+            )?;
+        }
+
+        let create_token_accounts = CreateTokenAccounts {
+            payer: operator.to_account_info(),
+            payment_mint: payment_mint.to_account_info(),
+            system_program: system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+            associated_token_program: associated_token_program.to_account_info(),
+        };
+        let mut remaining_accounts = ctx.remaining_accounts;
+        // Tokens sent to the escrow beyond the order subtotal are swept to the last line item, so
+        // the escrow can always be closed
+        let excess = cart_order_token_account
+            .amount
+            .checked_sub(cart_order.payment_subtotal)
+            .ok_or(SplurgeError::MathOverflow)?;
+        let last = cart_order.line_items.len() - 1;
+
+        for (i, line_item) in cart_order.line_items.iter().enumerate() {
+            let (accounts, rest) =
+                remaining_accounts.split_at(2 + line_item.payout_recipients.len() * 2);
+            remaining_accounts = rest;
+
+            let [store_info, store_token_account, payout_accounts @ ..] = accounts else {
+                return err!(SplurgeError::CartAccountsMismatch);
+            };
+
+            require_keys_eq!(
+                store_info.key(),
+                line_item.store,
+                SplurgeError::InvalidCartStore
+            );
+            require!(store_info.is_writable, ErrorCode::ConstraintMut);
+            require_keys_eq!(
+                store_token_account.key(),
+                get_associated_token_address_with_program_id(
+                    &line_item.store,
+                    &payment_mint.key(),
+                    &token_program.key(),
+                ),
+                SplurgeError::InvalidStoreTokenAccount
+            );
+
+            let mut store = Account::<Store>::try_from(store_info)?;
+
+            create_token_account_idempotent(
+                &create_token_accounts,
+                store_token_account.clone(),
+                store_info.clone(),
+            )?;
+
+            let payout_token_accounts = init_payout_token_accounts(
+                &line_item.payout_recipients,
+                payout_accounts,
+                &create_token_accounts,
+            )?;
+            let payout_amount = line_item
+                .payment_subtotal
+                .checked_sub(line_item.referral_fee)
+                .and_then(|amount| amount.checked_add(if i == last { excess } else { 0 }))
+                .ok_or(SplurgeError::MathOverflow)?;
+            let store_payouts = get_store_payouts(
+                &line_item.payout_recipients,
+                payout_amount,
+                store_token_account.clone(),
+                payout_token_accounts,
+            )?;
+
+            for (token_account, amount) in store_payouts {
+                transfer_checked(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        TransferChecked {
+                            authority: cart_order.to_account_info(),
+                            mint: payment_mint.to_account_info(),
+                            from: cart_order_token_account.to_account_info(),
+                            to: token_account,
+                        },
+                    )
+                    .with_signer(&[signer_seeds]),
+                    amount,
+                    payment_mint.decimals,
+                )?;
+            }
+
+            store.record_completed_volume(line_item.payment_subtotal, payment_mint.decimals)?;
+
+            Store::invariant(&store)?;
+            store.exit(&crate::ID)?;
+        }
+
+        close_account(
+            CpiContext::new(
+                token_program.to_account_info(),
+                CloseAccount {
+                    authority: cart_order.to_account_info(),
+                    account: cart_order_token_account.to_account_info(),
+                    destination: authority.to_account_info(),
+                },
+            )
+            .with_signer(&[signer_seeds]),
+        )?;
+
+        cart_order.status = OrderStatus::Completed;
+        cart_order.finalized_at = Clock::get()?.unix_timestamp;
+
+        if let Some(referrer) = cart_order.referrer.filter(|_| referral_fee > 0) {
+            emit!(ReferralPaid {
+                order: cart_order.key(),
+                referrer,
+                amount: referral_fee,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
+
+        emit!(CartOrderCompleted {
+            cart_order: cart_order.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        CartOrder::invariant(&cart_order)
+    }
+}
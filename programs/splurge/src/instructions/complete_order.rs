@@ -1,30 +1,27 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{
-        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
-        TransferChecked,
-    },
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{
-    constants::{CONFIG_SEED, ORDER_SEED, STORE_SEED},
+    constants::{CONFIG_SEED, ORDER_SEED, PAUSE_COMPLETION, STORE_SEED},
     error::SplurgeError,
-    events::OrderCompleted,
-    order_signer,
     state::{Config, Item, Order, OrderStatus, Shopper, Store},
+    utils::{settle_order, SettleOrderAccounts},
 };
 
 #[derive(Accounts)]
 pub struct CompleteOrder<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub operator: Signer<'info>,
     #[account(mut)]
     pub authority: SystemAccount<'info>,
     #[account(
         seeds = [CONFIG_SEED],
         bump = config.bump,
-        has_one = admin @ SplurgeError::UnauthorizedAdmin,
+        has_one = operator @ SplurgeError::UnauthorizedOperator,
+        constraint = !config.is_paused(PAUSE_COMPLETION) @ SplurgeError::CompletionPaused,
     )]
     pub config: Account<'info, Config>,
     #[account(
@@ -32,6 +29,7 @@ pub struct CompleteOrder<'info> {
     )]
     pub shopper: Account<'info, Shopper>,
     #[account(
+        mut,
         seeds = [STORE_SEED, store.authority.key().as_ref()],
         bump = store.bump,
     )]
@@ -63,75 +61,68 @@ pub struct CompleteOrder<'info> {
     pub order_token_account: InterfaceAccount<'info, TokenAccount>,
     #[account(
         init_if_needed,
-        payer = admin,
+        payer = operator,
         associated_token::mint = payment_mint,
         associated_token::authority = store,
         associated_token::token_program = token_program,
     )]
     pub store_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Wallet of the order referrer, authority of the referrer token account
+    #[account(
+        constraint = order.referrer == Some(referrer.key()) @ SplurgeError::InvalidReferrer,
+    )]
+    pub referrer: Option<UncheckedAccount<'info>>,
+    #[account(
+        init_if_needed,
+        payer = operator,
+        associated_token::mint = payment_mint,
+        associated_token::authority = referrer,
+        associated_token::token_program = token_program,
+    )]
+    pub referrer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
 impl CompleteOrder<'_> {
-    pub fn handler(ctx: Context<CompleteOrder>) -> Result<()> {
+    /// Payout recipient wallets and writable token accounts are passed as remaining accounts in
+    /// pairs, in the same order as the order's payout recipients. Missing token accounts are
+    /// created, paid by the operator
+    pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CompleteOrder<'info>>) -> Result<()> {
         let CompleteOrder {
+            operator,
             authority,
-            config,
-            item,
             order,
             order_token_account,
             payment_mint,
-            shopper,
+            referrer_token_account,
+            store,
             store_token_account,
+            system_program,
             token_program,
+            associated_token_program,
             ..
         } = ctx.accounts;
 
-        config.validate_mint(payment_mint.key())?;
-
-        let shopper_key = shopper.key();
-        let item_key = item.key();
-        let order_timestamp = order.timestamp.to_le_bytes();
-
-        let signer_seeds: &[&[u8]] =
-            order_signer!(shopper_key, item_key, order_timestamp, order.bump);
-
-        transfer_checked(
-            CpiContext::new(
-                token_program.to_account_info(),
-                TransferChecked {
-                    authority: order.to_account_info(),
-                    mint: payment_mint.to_account_info(),
-                    from: order_token_account.to_account_info(),
-                    to: store_token_account.to_account_info(),
-                },
-            )
-            .with_signer(&[signer_seeds]),
-            order_token_account.amount,
-            payment_mint.decimals,
+        settle_order(
+            SettleOrderAccounts {
+                order,
+                store,
+                payment_mint,
+                order_token_account,
+                store_token_account,
+                referrer_token_account: referrer_token_account.as_ref(),
+                authority: authority.to_account_info(),
+                payer: operator.to_account_info(),
+                system_program: system_program.to_account_info(),
+                token_program,
+                associated_token_program: associated_token_program.to_account_info(),
+            },
+            ctx.remaining_accounts,
         )?;
 
-        close_account(
-            CpiContext::new(
-                token_program.to_account_info(),
-                CloseAccount {
-                    authority: order.to_account_info(),
-                    account: order_token_account.to_account_info(),
-                    destination: authority.to_account_info(),
-                },
-            )
-            .with_signer(&[signer_seeds]),
-        )?;
-
-        order.status = OrderStatus::Completed;
-
-        emit!(OrderCompleted {
-            order: order.key(),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-
+        Store::invariant(&store)?;
         Order::invariant(&order)
     }
 }
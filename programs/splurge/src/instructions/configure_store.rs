@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CONFIG_SEED, STORE_SEED},
+    error::SplurgeError,
+    events::StoreOrderFeeIncreaseStaged,
+    state::{Config, Store},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ConfigureStoreArgs {
+    pub completion_delay_secs: Option<u32>,
+    pub order_fee_bps: Option<u16>,
+    pub is_suspended: bool,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureStore<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ SplurgeError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [STORE_SEED, store.authority.key().as_ref()],
+        bump = store.bump,
+    )]
+    pub store: Account<'info, Store>,
+}
+
+impl ConfigureStore<'_> {
+    pub fn handler(ctx: Context<ConfigureStore>, args: ConfigureStoreArgs) -> Result<()> {
+        let ConfigureStoreArgs {
+            completion_delay_secs,
+            order_fee_bps,
+            is_suspended,
+        } = args;
+
+        let ConfigureStore { config, store, .. } = ctx.accounts;
+
+        let now = Clock::get()?.unix_timestamp;
+
+        if let Some(order_fee_bps) = order_fee_bps {
+            require_gte!(
+                config.get_max_order_fee_bps(now),
+                order_fee_bps,
+                SplurgeError::InvalidFeeBps
+            );
+        }
+
+        if let Some(pending_order_fee) = config.set_store_order_fee_bps(store, order_fee_bps, now) {
+            emit!(StoreOrderFeeIncreaseStaged {
+                store: store.key(),
+                order_fee_bps: pending_order_fee.order_fee_bps,
+                effective_timestamp: pending_order_fee.effective_timestamp,
+                timestamp: now,
+            });
+        }
+
+        store.completion_delay_secs = completion_delay_secs;
+        store.is_suspended = is_suspended;
+
+        Store::invariant(&store)
+    }
+}
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use tuktuk_program::tuktuk::{
+    cpi::{accounts::DequeueTaskV0, dequeue_task_v0},
+    program::Tuktuk,
+};
+
+use crate::{
+    config_signer,
+    constants::{CONFIG_SEED, ORDER_SEED, PAUSE_COMPLETION, SHOPPER_SEED, STORE_SEED},
+    error::SplurgeError,
+    state::{Config, Item, Order, OrderStatus, Shopper, Store},
+    utils::{settle_order, SettleOrderAccounts},
+};
+
+#[derive(Accounts)]
+pub struct ConfirmDelivery<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_COMPLETION) @ SplurgeError::CompletionPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [SHOPPER_SEED, authority.key().as_ref()],
+        bump = shopper.bump,
+    )]
+    pub shopper: Account<'info, Shopper>,
+    #[account(
+        mut,
+        seeds = [STORE_SEED, store.authority.key().as_ref()],
+        bump = store.bump,
+    )]
+    pub store: Account<'info, Store>,
+    #[account(
+        has_one = store,
+    )]
+    pub item: Account<'info, Item>,
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, shopper.key().as_ref(), item.key().as_ref(), order.timestamp.to_le_bytes().as_ref()],
+        bump = order.bump,
+        has_one = task @ SplurgeError::InvalidOrderTask,
+        constraint = order.status == OrderStatus::Shipping @ SplurgeError::OrderNotBeingShipped,
+        constraint = order.payment_mint == payment_mint.key() @ SplurgeError::InvalidOrderPaymentMint,
+    )]
+    pub order: Account<'info, Order>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = order,
+        associated_token::token_program = token_program,
+    )]
+    pub order_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = payment_mint,
+        associated_token::authority = store,
+        associated_token::token_program = token_program,
+    )]
+    pub store_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Wallet of the order referrer, authority of the referrer token account
+    #[account(
+        constraint = order.referrer == Some(referrer.key()) @ SplurgeError::InvalidReferrer,
+    )]
+    pub referrer: Option<UncheckedAccount<'info>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = payment_mint,
+        associated_token::authority = referrer,
+        associated_token::token_program = token_program,
+    )]
+    pub referrer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub tuktuk: Program<'info, Tuktuk>,
+    /// CHECK: Task queue, used in CPI
+    #[account(mut)]
+    pub task_queue: UncheckedAccount<'info>,
+    /// CHECK: Task, used in CPI
+    #[account(mut)]
+    pub task: UncheckedAccount<'info>,
+    /// CHECK: Task queue authority, used in CPI
+    pub task_queue_authority: UncheckedAccount<'info>,
+    /// CHECK: Payer of the task, refunded its rent in CPI
+    #[account(mut)]
+    pub rent_refund: UncheckedAccount<'info>,
+}
+
+impl ConfirmDelivery<'_> {
+    /// Payout recipient wallets and writable token accounts are passed as remaining accounts in
+    /// pairs, in the same order as the order's payout recipients. Missing token accounts are
+    /// created, paid by the shopper
+    pub fn handler<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ConfirmDelivery<'info>>,
+    ) -> Result<()> {
+        let ConfirmDelivery {
+            authority,
+            config,
+            order,
+            order_token_account,
+            payment_mint,
+            referrer_token_account,
+            store,
+            store_token_account,
+            system_program,
+            token_program,
+            associated_token_program,
+            tuktuk,
+            task_queue,
+            task,
+            task_queue_authority,
+            rent_refund,
+            ..
+        } = ctx.accounts;
+
+        settle_order(
+            SettleOrderAccounts {
+                order,
+                store,
+                payment_mint,
+                order_token_account,
+                store_token_account,
+                referrer_token_account: referrer_token_account.as_ref(),
+                authority: authority.to_account_info(),
+                payer: authority.to_account_info(),
+                system_program: system_program.to_account_info(),
+                token_program,
+                associated_token_program: associated_token_program.to_account_info(),
+            },
+            ctx.remaining_accounts,
+        )?;
+
+        let config_signer_seeds: &[&[u8]] = config_signer!(config.bump);
+
+        dequeue_task_v0(
+            CpiContext::new(
+                tuktuk.to_account_info(),
+                DequeueTaskV0 {
+                    queue_authority: config.to_account_info(),
+                    rent_refund: rent_refund.to_account_info(),
+                    task_queue_authority: task_queue_authority.to_account_info(),
+                    task_queue: task_queue.to_account_info(),
+                    task: task.to_account_info(),
+                },
+            )
+            .with_signer(&[config_signer_seeds]),
+        )?;
+
+        Store::invariant(&store)?;
+        Order::invariant(&order)
+    }
+}
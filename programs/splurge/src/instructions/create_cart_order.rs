@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::{
+    constants::{
+        CART_ORDER_SEED, CONFIG_SEED, MAX_CART_ITEMS, PAUSE_ORDERING, SHOPPER_SEED, TREASURY_SEED,
+    },
+    error::SplurgeError,
+    events::CartOrderCreated,
+    state::{CartOrder, Config, Item, LineItem, OrderStatus, Shopper, Store},
+    utils::{get_bps_share, get_item_payment_subtotal, get_oracle_price, get_platform_fee},
+};
+
+#[derive(Accounts)]
+#[instruction(amounts: Vec<u32>, timestamp: i64)]
+pub struct CreateCartOrder<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = config.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_ORDERING) @ SplurgeError::OrderingPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [SHOPPER_SEED, authority.key().as_ref()],
+        bump = shopper.bump,
+    )]
+    pub shopper: Account<'info, Shopper>,
+    #[account(
+        init,
+        payer = authority,
+        space = CartOrder::space(amounts.len()),
+        seeds = [CART_ORDER_SEED, shopper.key().as_ref(), timestamp.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub cart_order: Account<'info, CartOrder>,
+    pub price_update_v2: Option<Account<'info, PriceUpdateV2>>,
+    #[account(
+        constraint = referrer.key() != authority.key() @ SplurgeError::SelfReferral,
+    )]
+    pub referrer: Option<SystemAccount<'info>>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program,
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = payment_mint,
+        associated_token::authority = cart_order,
+        associated_token::token_program = token_program,
+    )]
+    pub cart_order_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl CreateCartOrder<'_> {
+    /// Item and store accounts are passed as remaining accounts in pairs, in the same order as
+    /// `amounts`, with the item accounts writable
+    pub fn handler<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateCartOrder<'info>>,
+        amounts: Vec<u32>,
+        timestamp: i64,
+        max_payment: u64,
+    ) -> Result<()> {
+        require!(!amounts.is_empty(), SplurgeError::EmptyCart);
+        require_gte!(
+            MAX_CART_ITEMS as usize,
+            amounts.len(),
+            SplurgeError::CartTooLarge
+        );
+        require_eq!(
+            ctx.remaining_accounts.len(),
+            amounts.len() * 2,
+            SplurgeError::CartAccountsMismatch
+        );
+
+        let CreateCartOrder {
+            authority,
+            authority_token_account,
+            cart_order,
+            cart_order_token_account,
+            config,
+            payment_mint,
+            price_update_v2,
+            referrer,
+            shopper,
+            token_program,
+            treasury_token_account,
+            ..
+        } = ctx.accounts;
+
+        let accepted_mint = config.get_active_accepted_mint(payment_mint.key())?;
+        let now = Clock::get()?.unix_timestamp;
+
+        let oracle_price = price_update_v2
+            .as_ref()
+            .map(|price_update_v2| get_oracle_price(price_update_v2, accepted_mint, now))
+            .transpose()?;
+        let mut line_items: Vec<LineItem> = Vec::with_capacity(amounts.len());
+
+        for (accounts, amount) in ctx.remaining_accounts.chunks(2).zip(amounts) {
+            let [item_info, store_info] = accounts else {
+                return err!(SplurgeError::CartAccountsMismatch);
+            };
+
+            require!(
+                line_items.iter().all(|l| l.item != item_info.key()),
+                SplurgeError::DuplicateCartItem
+            );
+            require!(item_info.is_writable, ErrorCode::ConstraintMut);
+
+            let mut item = Account::<Item>::try_from(item_info)?;
+            let store = Account::<Store>::try_from(store_info)?;
+
+            require_keys_eq!(store.key(), item.store, SplurgeError::InvalidCartStore);
+            require!(!store.is_suspended, SplurgeError::StoreSuspended);
+
+            let payment_subtotal =
+                get_item_payment_subtotal(amount, &item, payment_mint, oracle_price.as_ref())?;
+            let order_fee_bps = config.get_order_fee_bps(&store, now);
+            let platform_fee = get_platform_fee(payment_subtotal, order_fee_bps)?;
+            let referral_fee = if referrer.is_some() {
+                get_bps_share(payment_subtotal, item.referral_bps)?
+            } else {
+                0
+            };
+
+            item.inventory_count = item
+                .inventory_count
+                .checked_sub(amount)
+                .ok_or(SplurgeError::InsufficientInventory)?;
+
+            Item::invariant(&item)?;
+            item.exit(&crate::ID)?;
+
+            line_items.push(LineItem {
+                item: item.key(),
+                store: item.store,
+                amount,
+                payment_subtotal,
+                order_fee_bps,
+                platform_fee,
+                referral_fee,
+                payout_recipients: Vec::new(),
+            });
+        }
+
+        let (payment_subtotal, platform_fee) = line_items
+            .iter()
+            .try_fold((0_u64, 0_u64), |(subtotal, fee), line_item| {
+                Some((
+                    subtotal.checked_add(line_item.payment_subtotal)?,
+                    fee.checked_add(line_item.platform_fee)?,
+                ))
+            })
+            .ok_or(SplurgeError::MathOverflow)?;
+
+        require_gte!(
+            max_payment,
+            payment_subtotal
+                .checked_add(platform_fee)
+                .ok_or(SplurgeError::MathOverflow)?,
+            SplurgeError::SlippageExceeded
+        );
+
+        cart_order.set_inner(CartOrder {
+            bump: ctx.bumps.cart_order,
+            shopper: shopper.key(),
+            timestamp,
+            status: OrderStatus::default(),
+            payment_subtotal,
+            platform_fee,
+            payment_mint: payment_mint.key(),
+            task: Pubkey::default(),
+            referrer: referrer.as_ref().map(|referrer| referrer.key()),
+            finalized_at: 0,
+            line_items,
+        });
+
+        let decimals = payment_mint.decimals;
+
+        transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                TransferChecked {
+                    authority: authority.to_account_info(),
+                    mint: payment_mint.to_account_info(),
+                    from: authority_token_account.to_account_info(),
+                    to: cart_order_token_account.to_account_info(),
+                },
+            ),
+            payment_subtotal,
+            decimals,
+        )?;
+
+        transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                TransferChecked {
+                    authority: authority.to_account_info(),
+                    mint: payment_mint.to_account_info(),
+                    from: authority_token_account.to_account_info(),
+                    to: treasury_token_account.to_account_info(),
+                },
+            ),
+            platform_fee,
+            decimals,
+        )?;
+
+        emit!(CartOrderCreated {
+            cart_order: cart_order.key(),
+            timestamp,
+        });
+
+        CartOrder::invariant(&cart_order)
+    }
+}
@@ -1,22 +1,18 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_2022::spl_token_2022::extension::transfer_fee::MAX_FEE_BASIS_POINTS,
     token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
-#[cfg(not(feature = "no-staleness-check"))]
-use pyth_solana_receiver_sdk::error::GetPriceError;
-use pyth_solana_receiver_sdk::price_update::{PriceFeedMessage, PriceUpdateV2};
-use spl_math::precise_number::PreciseNumber;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use tuktuk_program::tuktuk::{cpi::accounts::QueueTaskV0, program::Tuktuk};
 
-#[cfg(not(feature = "no-staleness-check"))]
-use crate::constants::MAX_ORACLE_STALENESS;
 use crate::{
-    constants::{CONFIG_SEED, ORDER_SEED, SHOPPER_SEED, STORE_SEED, TREASURY_SEED},
+    constants::{CONFIG_SEED, ORDER_SEED, PAUSE_ORDERING, SHOPPER_SEED, STORE_SEED, TREASURY_SEED},
     error::SplurgeError,
     events::OrderCreated,
-    imprecise_number, precise_number,
+    instructions::OrderQuote,
     state::{Config, Item, Order, OrderStatus, Shopper, Store},
+    utils::{get_bps_share, queue_expire_order_task},
 };
 
 #[derive(Accounts)]
@@ -32,7 +28,7 @@ pub struct CreateOrder<'info> {
     #[account(
         seeds = [CONFIG_SEED],
         bump = config.bump,
-        constraint = !config.is_paused @ SplurgeError::PlatformPaused,
+        constraint = !config.is_paused(PAUSE_ORDERING) @ SplurgeError::OrderingPaused,
     )]
     pub config: Account<'info, Config>,
     #[account(
@@ -43,6 +39,7 @@ pub struct CreateOrder<'info> {
     #[account(
         seeds = [STORE_SEED, store.authority.key().as_ref()],
         bump = store.bump,
+        constraint = !store.is_suspended @ SplurgeError::StoreSuspended,
     )]
     pub store: Account<'info, Store>,
     #[account(
@@ -58,7 +55,11 @@ pub struct CreateOrder<'info> {
         bump,
     )]
     pub order: Account<'info, Order>,
-    pub price_update_v2: Account<'info, PriceUpdateV2>,
+    pub price_update_v2: Option<Account<'info, PriceUpdateV2>>,
+    #[account(
+        constraint = referrer.key() != authority.key() @ SplurgeError::SelfReferral,
+    )]
+    pub referrer: Option<SystemAccount<'info>>,
     #[account(
         mint::token_program = token_program,
     )]
@@ -88,10 +89,25 @@ pub struct CreateOrder<'info> {
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    pub tuktuk: Option<Program<'info, Tuktuk>>,
+    /// CHECK: Task queue, used in CPI if shipping deadline is set
+    #[account(mut)]
+    pub task_queue: Option<UncheckedAccount<'info>>,
+    /// CHECK: Task, used in CPI if shipping deadline is set
+    #[account(mut)]
+    pub task: Option<UncheckedAccount<'info>>,
+    /// CHECK: Task queue authority, used in CPI if shipping deadline is set
+    pub task_queue_authority: Option<UncheckedAccount<'info>>,
 }
 
 impl CreateOrder<'_> {
-    pub fn handler(ctx: Context<CreateOrder>, amount: u32, timestamp: i64) -> Result<()> {
+    pub fn handler(
+        ctx: Context<CreateOrder>,
+        amount: u32,
+        timestamp: i64,
+        max_payment: u64,
+        task_id: Option<u16>,
+    ) -> Result<()> {
         let CreateOrder {
             authority,
             authority_token_account,
@@ -101,58 +117,54 @@ impl CreateOrder<'_> {
             order_token_account,
             payment_mint,
             price_update_v2,
+            referrer,
             shopper,
+            store,
             token_program,
+            treasury,
             treasury_token_account,
+            system_program,
+            tuktuk,
+            task_queue,
+            task,
+            task_queue_authority,
             ..
         } = ctx.accounts;
 
-        config.validate_mint(payment_mint.key())?;
-        config.validate_price_update_v2(price_update_v2.key())?;
-
-        let PriceFeedMessage {
-            exponent,
-            price,
-            #[cfg(not(feature = "no-staleness-check"))]
-            publish_time,
+        let OrderQuote {
+            payment_subtotal,
+            platform_fee,
+            order_fee_bps,
             ..
-        } = price_update_v2.price_message;
+        } = OrderQuote::new(
+            config,
+            store,
+            item,
+            payment_mint,
+            price_update_v2.as_ref(),
+            amount,
+        )?;
 
-        #[cfg(not(feature = "no-staleness-check"))]
-        require!(
-            timestamp - publish_time <= MAX_ORACLE_STALENESS as i64,
-            GetPriceError::PriceTooOld
+        require_gte!(
+            max_payment,
+            payment_subtotal
+                .checked_add(platform_fee)
+                .ok_or(SplurgeError::MathOverflow)?,
+            SplurgeError::SlippageExceeded
         );
 
-        require!(price > 0, SplurgeError::InvalidPrice);
-
-        let ops = if exponent > 0 {
-            PreciseNumber::checked_mul
+        let referral_fee = if referrer.is_some() {
+            get_bps_share(payment_subtotal, item.referral_bps)?
         } else {
-            PreciseNumber::checked_div
+            0
         };
 
-        let oracle_price = ops(
-            &precise_number!(price as u128),
-            &precise_number!(10_u64.pow(exponent.abs() as u32) as u128),
-        )
-        .unwrap();
-
-        let payment_subtotal = imprecise_number!(precise_number!(amount.into())
-            .checked_mul(&precise_number!(item.price.into()))
-            .ok_or(SplurgeError::MathOverflow)?
-            .checked_mul(&oracle_price)
-            .ok_or(SplurgeError::MathOverflow)?
-            .ceiling()
-            .ok_or(SplurgeError::MathOverflow)?) as u64;
-
-        let platform_fee = imprecise_number!(precise_number!(payment_subtotal.into())
-            .checked_mul(&precise_number!(config.order_fee_bps.into()))
-            .ok_or(SplurgeError::MathOverflow)?
-            .checked_div(&precise_number!(MAX_FEE_BASIS_POINTS.into()))
-            .ok_or(SplurgeError::MathOverflow)?
-            .ceiling()
-            .ok_or(SplurgeError::MathOverflow)?) as u64;
+        // Measured from the cluster clock, as the order timestamp is supplied by the shopper
+        let shipping_deadline = if config.shipping_deadline_secs > 0 {
+            Clock::get()?.unix_timestamp + config.shipping_deadline_secs as i64
+        } else {
+            0
+        };
 
         order.set_inner(Order {
             bump: ctx.bumps.order,
@@ -161,9 +173,18 @@ impl CreateOrder<'_> {
             timestamp,
             status: OrderStatus::default(),
             amount,
+            shipped_amount: 0,
+            refunded_amount: 0,
             payment_subtotal,
             platform_fee,
+            order_fee_bps,
             payment_mint: payment_mint.key(),
+            task: Pubkey::default(),
+            shipping_deadline,
+            referrer: referrer.as_ref().map(|referrer| referrer.key()),
+            referral_fee,
+            finalized_at: 0,
+            payout_recipients: Vec::new(),
         });
 
         let decimals = payment_mint.decimals;
@@ -201,6 +222,47 @@ impl CreateOrder<'_> {
             .checked_sub(amount)
             .ok_or(SplurgeError::InsufficientInventory)?;
 
+        if shipping_deadline > 0 {
+            let (
+                Some(tuktuk),
+                Some(task_queue),
+                Some(task),
+                Some(task_queue_authority),
+                Some(task_id),
+            ) = (tuktuk, task_queue, task, task_queue_authority, task_id)
+            else {
+                return err!(SplurgeError::MissingExpiryTask);
+            };
+
+            queue_expire_order_task(
+                tuktuk.to_account_info(),
+                QueueTaskV0 {
+                    payer: authority.to_account_info(),
+                    queue_authority: config.to_account_info(),
+                    task_queue: task_queue.to_account_info(),
+                    task_queue_authority: task_queue_authority.to_account_info(),
+                    task: task.to_account_info(),
+                    system_program: system_program.to_account_info(),
+                },
+                config.bump,
+                crate::accounts::ExpireOrder {
+                    treasury: treasury.key(),
+                    authority: authority.key(),
+                    config: config.key(),
+                    shopper: shopper.key(),
+                    item: item.key(),
+                    order: order.key(),
+                    payment_mint: payment_mint.key(),
+                    treasury_token_account: treasury_token_account.key(),
+                    order_token_account: order_token_account.key(),
+                    authority_token_account: authority_token_account.key(),
+                    token_program: token_program.key(),
+                },
+                task_id,
+                shipping_deadline,
+            )?;
+        }
+
         emit!(OrderCreated {
             order: order.key(),
             timestamp,
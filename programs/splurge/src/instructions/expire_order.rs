@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+
+use crate::{
+    constants::{CONFIG_SEED, ORDER_SEED, TREASURY_SEED},
+    error::SplurgeError,
+    events::OrderExpired,
+    order_signer,
+    state::{Config, Item, Order, OrderStatus, Shopper},
+    treasury_signer,
+};
+
+#[derive(Accounts)]
+pub struct ExpireOrder<'info> {
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = config.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+    #[account(mut)]
+    pub authority: SystemAccount<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        has_one = authority @ SplurgeError::InvalidShopperAuthority,
+    )]
+    pub shopper: Account<'info, Shopper>,
+    #[account(mut)]
+    pub item: Account<'info, Item>,
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, shopper.key().as_ref(), item.key().as_ref(), order.timestamp.to_le_bytes().as_ref()],
+        bump = order.bump,
+        constraint = order.payment_mint == payment_mint.key() @ SplurgeError::InvalidOrderPaymentMint,
+    )]
+    pub order: Account<'info, Order>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Order token account, already closed if the order is no longer pending
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&order.key(), &payment_mint.key(), &token_program.key()) @ SplurgeError::InvalidOrderTokenAccount,
+    )]
+    pub order_token_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program,
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl ExpireOrder<'_> {
+    pub fn handler(ctx: Context<ExpireOrder>) -> Result<()> {
+        let ExpireOrder {
+            authority,
+            authority_token_account,
+            config,
+            item,
+            order,
+            order_token_account,
+            payment_mint,
+            shopper,
+            token_program,
+            treasury,
+            treasury_token_account,
+        } = ctx.accounts;
+
+        if order.status != OrderStatus::Pending {
+            return Ok(());
+        }
+
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        require!(
+            order.shipping_deadline > 0 && timestamp >= order.shipping_deadline,
+            SplurgeError::OrderNotExpired
+        );
+
+        item.inventory_count = item
+            .inventory_count
+            .checked_add(order.amount)
+            .ok_or(SplurgeError::MathOverflow)?;
+
+        order.status = OrderStatus::Cancelled;
+        order.refunded_amount = order.amount;
+
+        let treasury_signer_seeds: &[&[u8]] = treasury_signer!(config.treasury_bump);
+        let shopper_key = shopper.key();
+        let item_key = item.key();
+        let timestamp_bytes = order.timestamp.to_le_bytes();
+        let order_signer_seeds: &[&[u8]] =
+            order_signer!(shopper_key, item_key, timestamp_bytes, order.bump);
+        let decimals = payment_mint.decimals;
+
+        transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                TransferChecked {
+                    authority: order.to_account_info(),
+                    mint: payment_mint.to_account_info(),
+                    from: order_token_account.to_account_info(),
+                    to: authority_token_account.to_account_info(),
+                },
+            )
+            .with_signer(&[order_signer_seeds]),
+            order.payment_subtotal,
+            decimals,
+        )?;
+
+        close_account(
+            CpiContext::new(
+                token_program.to_account_info(),
+                CloseAccount {
+                    authority: order.to_account_info(),
+                    account: order_token_account.to_account_info(),
+                    destination: authority.to_account_info(),
+                },
+            )
+            .with_signer(&[order_signer_seeds]),
+        )?;
+
+        transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                TransferChecked {
+                    authority: treasury.to_account_info(),
+                    mint: payment_mint.to_account_info(),
+                    from: treasury_token_account.to_account_info(),
+                    to: authority_token_account.to_account_info(),
+                },
+            )
+            .with_signer(&[treasury_signer_seeds]),
+            order.platform_fee,
+            decimals,
+        )?;
+
+        emit!(OrderExpired {
+            order: order.key(),
+            timestamp,
+        });
+
+        Item::invariant(&item)?;
+        Order::invariant(&order)
+    }
+}
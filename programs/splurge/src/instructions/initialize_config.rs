@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{CONFIG_SEED, TREASURY_SEED},
+    constants::{
+        CONFIG_SEED, DEFAULT_COMPLETION_DELAY_SECS, DEFAULT_MAX_ORDER_FEE_BPS, TREASURY_SEED,
+    },
     state::{AcceptedMint, Config},
 };
 
@@ -25,7 +27,7 @@ pub struct InitializeConfig<'info> {
     #[account(
         init,
         payer = authority,
-        space = Config::space(args.accepted_mints.as_ref()),
+        space = Config::space(&args.accepted_mints),
         seeds = [CONFIG_SEED],
         bump,
     )]
@@ -49,10 +51,21 @@ impl InitializeConfig<'_> {
             bump: ctx.bumps.config,
             treasury_bump: ctx.bumps.treasury,
             admin,
-            is_paused: false,
+            paused_operations: 0,
             order_fee_bps,
             accepted_mints,
-            reserved: [0; 64],
+            shopper_cancel_window_secs: 0,
+            completion_delay_secs: DEFAULT_COMPLETION_DELAY_SECS,
+            shipping_deadline_secs: 0,
+            fee_tiers: Vec::new(),
+            max_order_fee_bps: DEFAULT_MAX_ORDER_FEE_BPS,
+            pending_order_fee: None,
+            pending_admin: None,
+            operator: admin,
+            treasurer: admin,
+            pauser: admin,
+            pending_fee_tiers: None,
+            pending_max_order_fee: None,
         });
 
         Config::invariant(&config)
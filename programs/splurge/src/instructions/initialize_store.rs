@@ -22,7 +22,7 @@ pub struct InitializeStore<'info> {
     #[account(
         init,
         payer = authority,
-        space = Store::space(&args.name, &args.image, &args.about),
+        space = Store::space(&args.name, &args.image, &args.about, &[]),
         seeds = [STORE_SEED, authority.key().as_ref()],
         bump,
     )]
@@ -50,6 +50,12 @@ impl InitializeStore<'_> {
             name,
             image,
             about,
+            completion_delay_secs: None,
+            order_fee_bps: None,
+            completed_volume: 0,
+            is_suspended: false,
+            payout_recipients: Vec::new(),
+            pending_order_fee: None,
         });
 
         emit!(StoreInitialized {
@@ -1,15 +1,17 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{ITEM_SEED, MAX_ITEM_NAME_LEN, STORE_SEED},
+    constants::{CONFIG_SEED, ITEM_SEED, MAX_ITEM_NAME_LEN, PAUSE_LISTING, STORE_SEED},
     error::SplurgeError,
     events::ItemListed,
-    state::{Item, Store},
+    state::{Config, Item, PricingMode, Store},
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct ListItemArgs {
     pub price: u64,
+    pub pricing_mode: PricingMode,
+    pub referral_bps: u16,
     pub inventory_count: u32,
     pub name: String,
     pub image: String,
@@ -29,10 +31,17 @@ pub struct ListItem<'info> {
         bump,
     )]
     pub item: Account<'info, Item>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_LISTING) @ SplurgeError::ListingPaused,
+    )]
+    pub config: Account<'info, Config>,
     #[account(
         seeds = [STORE_SEED, authority.key().as_ref()],
         bump = store.bump,
         has_one = authority,
+        constraint = !store.is_suspended @ SplurgeError::StoreSuspended,
     )]
     pub store: Account<'info, Store>,
     pub system_program: Program<'info, System>,
@@ -42,6 +51,8 @@ impl ListItem<'_> {
     pub fn handler(ctx: Context<ListItem>, args: ListItemArgs) -> Result<()> {
         let ListItemArgs {
             price,
+            pricing_mode,
+            referral_bps,
             inventory_count,
             name,
             image,
@@ -54,12 +65,23 @@ impl ListItem<'_> {
             SplurgeError::ItemNameTooLong
         );
 
-        let ListItem { item, store, .. } = ctx.accounts;
+        let ListItem {
+            config,
+            item,
+            store,
+            ..
+        } = ctx.accounts;
+
+        if let PricingMode::Fixed { price_mint } = pricing_mode {
+            config.get_accepted_mint(price_mint)?;
+        }
 
         item.set_inner(Item {
             bump: ctx.bumps.item,
             store: store.key(),
             price,
+            price_mint: pricing_mode.into(),
+            referral_bps,
             inventory_count,
             name,
             image,
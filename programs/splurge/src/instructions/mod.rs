@@ -1,29 +1,69 @@
+pub mod accept_admin;
+pub mod cancel_admin_transfer;
+pub mod cancel_cart_order;
 pub mod cancel_order;
+pub mod close_cart_order;
+pub mod close_order;
+pub mod complete_cart_order;
 pub mod complete_order;
+pub mod configure_store;
+pub mod confirm_delivery;
+pub mod create_cart_order;
 pub mod create_order;
 pub mod create_review;
+pub mod expire_order;
 pub mod initialize_config;
 pub mod initialize_shopper;
 pub mod initialize_store;
 pub mod list_item;
+pub mod open_dispute;
+pub mod partially_fulfill_order;
+pub mod quote_order;
+pub mod resolve_dispute;
+pub mod set_mint_paused;
+pub mod set_paused;
+pub mod set_payout_recipients;
+pub mod ship_cart_order;
 pub mod ship_order;
+pub mod shopper_cancel_order;
 pub mod unlist_item;
 pub mod update_config;
 pub mod update_item;
+pub mod update_roles;
 pub mod withdraw_earnings;
 pub mod withdraw_treasury;
 
+pub use accept_admin::*;
+pub use cancel_admin_transfer::*;
+pub use cancel_cart_order::*;
 pub use cancel_order::*;
+pub use close_cart_order::*;
+pub use close_order::*;
+pub use complete_cart_order::*;
 pub use complete_order::*;
+pub use configure_store::*;
+pub use confirm_delivery::*;
+pub use create_cart_order::*;
 pub use create_order::*;
 pub use create_review::*;
+pub use expire_order::*;
 pub use initialize_config::*;
 pub use initialize_shopper::*;
 pub use initialize_store::*;
 pub use list_item::*;
+pub use open_dispute::*;
+pub use partially_fulfill_order::*;
+pub use quote_order::*;
+pub use resolve_dispute::*;
+pub use set_mint_paused::*;
+pub use set_paused::*;
+pub use set_payout_recipients::*;
+pub use ship_cart_order::*;
 pub use ship_order::*;
+pub use shopper_cancel_order::*;
 pub use unlist_item::*;
 pub use update_config::*;
 pub use update_item::*;
+pub use update_roles::*;
 pub use withdraw_earnings::*;
 pub use withdraw_treasury::*;
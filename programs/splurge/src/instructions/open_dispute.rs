@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ORDER_SEED, SHOPPER_SEED},
+    error::SplurgeError,
+    events::DisputeOpened,
+    state::{Order, OrderStatus, Shopper},
+};
+
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [SHOPPER_SEED, authority.key().as_ref()],
+        bump = shopper.bump,
+    )]
+    pub shopper: Account<'info, Shopper>,
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, shopper.key().as_ref(), order.item.key().as_ref(), order.timestamp.to_le_bytes().as_ref()],
+        bump = order.bump,
+        constraint = order.status == OrderStatus::Shipping @ SplurgeError::OrderNotBeingShipped,
+    )]
+    pub order: Account<'info, Order>,
+}
+
+impl OpenDispute<'_> {
+    pub fn handler(ctx: Context<OpenDispute>) -> Result<()> {
+        let OpenDispute { order, shopper, .. } = ctx.accounts;
+
+        order.status = OrderStatus::Disputed;
+
+        emit!(DisputeOpened {
+            order: order.key(),
+            shopper: shopper.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Order::invariant(&order)
+    }
+}
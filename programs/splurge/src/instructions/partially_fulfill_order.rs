@@ -0,0 +1,266 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use tuktuk_program::tuktuk::{cpi::accounts::QueueTaskV0, program::Tuktuk};
+
+use crate::{
+    constants::{CONFIG_SEED, ORDER_SEED, PAUSE_SHIPPING, STORE_SEED, TREASURY_SEED},
+    error::SplurgeError,
+    events::{ActorRole, OrderPartiallyFulfilled},
+    order_signer,
+    state::{Config, Item, Order, OrderStatus, Shopper, Store},
+    treasury_signer,
+    utils::{get_payout_account_metas, get_pro_rata_share, queue_complete_order_task},
+};
+
+#[derive(Accounts)]
+pub struct PartiallyFulfillOrder<'info> {
+    #[account(
+        mut,
+        constraint = shipper.key() == config.operator || shipper.key() == store.authority @ SplurgeError::UnauthorizedShipper,
+    )]
+    pub shipper: Signer<'info>,
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = config.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_SHIPPING) @ SplurgeError::ShippingPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, shopper.key().as_ref(), item.key().as_ref(), order.timestamp.to_le_bytes().as_ref()],
+        bump = order.bump,
+        constraint = order.status == OrderStatus::Pending @ SplurgeError::OrderNotPending,
+        constraint = order.payment_mint == payment_mint.key() @ SplurgeError::InvalidOrderPaymentMint,
+    )]
+    pub order: Account<'info, Order>,
+    #[account(mut)]
+    pub authority: SystemAccount<'info>,
+    #[account(
+        mut,
+        has_one = store,
+    )]
+    pub item: Account<'info, Item>,
+    #[account(
+        has_one = authority @ SplurgeError::InvalidShopperAuthority,
+    )]
+    pub shopper: Account<'info, Shopper>,
+    #[account(
+        seeds = [STORE_SEED, store.authority.key().as_ref()],
+        bump = store.bump,
+    )]
+    pub store: Account<'info, Store>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = order,
+        associated_token::token_program = token_program,
+    )]
+    pub order_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = shipper,
+        associated_token::mint = payment_mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program,
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = shipper,
+        associated_token::mint = payment_mint,
+        associated_token::authority = store,
+        associated_token::token_program = token_program,
+    )]
+    pub store_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub tuktuk: Program<'info, Tuktuk>,
+    /// CHECK: Task queue, used in CPI
+    #[account(mut)]
+    pub task_queue: UncheckedAccount<'info>,
+    /// CHECK: Task, used in CPI
+    #[account(mut)]
+    pub task: UncheckedAccount<'info>,
+    /// CHECK: Task queue authority, used in CPI
+    pub task_queue_authority: UncheckedAccount<'info>,
+}
+
+impl PartiallyFulfillOrder<'_> {
+    pub fn handler(
+        ctx: Context<PartiallyFulfillOrder>,
+        ship_amount: u32,
+        task_id: u16,
+    ) -> Result<()> {
+        let PartiallyFulfillOrder {
+            shipper,
+            treasury,
+            config,
+            order,
+            authority,
+            item,
+            shopper,
+            store,
+            payment_mint,
+            treasury_token_account,
+            order_token_account,
+            authority_token_account,
+            store_token_account,
+            system_program,
+            token_program,
+            associated_token_program,
+            tuktuk,
+            task_queue,
+            task,
+            task_queue_authority,
+        } = ctx.accounts;
+
+        require!(
+            ship_amount > 0 && ship_amount < order.amount,
+            SplurgeError::InvalidFulfillmentAmount
+        );
+
+        let role = if shipper.key() == config.operator {
+            ActorRole::Operator
+        } else {
+            ActorRole::Store
+        };
+
+        let refund_amount = order.amount - ship_amount;
+        let refund_subtotal =
+            get_pro_rata_share(order.payment_subtotal, refund_amount, order.amount)?;
+        let refund_fee = get_pro_rata_share(order.platform_fee, refund_amount, order.amount)?;
+        let refund_referral_fee =
+            get_pro_rata_share(order.referral_fee, refund_amount, order.amount)?;
+
+        let treasury_signer_seeds: &[&[u8]] = treasury_signer!(config.treasury_bump);
+        let shopper_key = shopper.key();
+        let item_key = item.key();
+        let timestamp_bytes = order.timestamp.to_le_bytes();
+        let order_signer_seeds: &[&[u8]] =
+            order_signer!(shopper_key, item_key, timestamp_bytes, order.bump);
+        let decimals = payment_mint.decimals;
+
+        if refund_subtotal > 0 {
+            transfer_checked(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        authority: order.to_account_info(),
+                        mint: payment_mint.to_account_info(),
+                        from: order_token_account.to_account_info(),
+                        to: authority_token_account.to_account_info(),
+                    },
+                )
+                .with_signer(&[order_signer_seeds]),
+                refund_subtotal,
+                decimals,
+            )?;
+        }
+
+        if refund_fee > 0 {
+            transfer_checked(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        authority: treasury.to_account_info(),
+                        mint: payment_mint.to_account_info(),
+                        from: treasury_token_account.to_account_info(),
+                        to: authority_token_account.to_account_info(),
+                    },
+                )
+                .with_signer(&[treasury_signer_seeds]),
+                refund_fee,
+                decimals,
+            )?;
+        }
+
+        item.inventory_count = item
+            .inventory_count
+            .checked_add(refund_amount)
+            .ok_or(SplurgeError::MathOverflow)?;
+
+        order.status = OrderStatus::Shipping;
+        order.task = task.key();
+        order.payout_recipients = store.payout_recipients.clone();
+        order.shipped_amount = ship_amount;
+        order.refunded_amount = refund_amount;
+        order.payment_subtotal -= refund_subtotal;
+        order.platform_fee -= refund_fee;
+        order.referral_fee -= refund_referral_fee;
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        let trigger_timestamp = timestamp + config.get_completion_delay_secs(store) as i64;
+
+        queue_complete_order_task(
+            tuktuk.to_account_info(),
+            QueueTaskV0 {
+                payer: shipper.to_account_info(),
+                queue_authority: config.to_account_info(),
+                task_queue: task_queue.to_account_info(),
+                task_queue_authority: task_queue_authority.to_account_info(),
+                task: task.to_account_info(),
+                system_program: system_program.to_account_info(),
+            },
+            config.bump,
+            crate::accounts::CompleteOrder {
+                operator: config.operator,
+                associated_token_program: associated_token_program.key(),
+                authority: authority.key(),
+                config: config.key(),
+                item: item.key(),
+                order: order.key(),
+                order_token_account: order_token_account.key(),
+                payment_mint: payment_mint.key(),
+                referrer: order.get_owed_referrer(),
+                referrer_token_account: order
+                    .get_referrer_token_account(&payment_mint.key(), &token_program.key()),
+                shopper: shopper.key(),
+                store: store.key(),
+                store_token_account: store_token_account.key(),
+                system_program: system_program.key(),
+                token_program: token_program.key(),
+            },
+            get_payout_account_metas(
+                &order.payout_recipients,
+                &payment_mint.key(),
+                &token_program.key(),
+            ),
+            task_id,
+            trigger_timestamp,
+        )?;
+
+        emit!(OrderPartiallyFulfilled {
+            order: order.key(),
+            shipped_amount: ship_amount,
+            refunded_amount: refund_amount,
+            refunded_subtotal: refund_subtotal,
+            refunded_fee: refund_fee,
+            shipped_by: shipper.key(),
+            role,
+            timestamp,
+        });
+
+        Item::invariant(&item)?;
+        Order::invariant(&order)
+    }
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::{
+    constants::{CONFIG_SEED, STORE_SEED},
+    state::{Config, Item, Store},
+    utils::{get_item_payment_subtotal, get_oracle_price, get_platform_fee},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct OrderQuote {
+    /// Payment for the items in atomic units of the payment mint
+    pub payment_subtotal: u64,
+    /// Platform fee in atomic units of the payment mint
+    pub platform_fee: u64,
+    /// Fee rate applied to the store in basis points
+    pub order_fee_bps: u16,
+    /// Oracle price used for conversion, 0 for fixed-price items
+    pub oracle_price: i64,
+    /// Exponent of the oracle price, 0 for fixed-price items
+    pub oracle_exponent: i32,
+}
+
+impl OrderQuote {
+    pub fn new(
+        config: &Config,
+        store: &Store,
+        item: &Item,
+        payment_mint: &InterfaceAccount<Mint>,
+        price_update_v2: Option<&Account<PriceUpdateV2>>,
+        amount: u32,
+    ) -> Result<Self> {
+        let accepted_mint = config.get_active_accepted_mint(payment_mint.key())?;
+        let now = Clock::get()?.unix_timestamp;
+
+        let oracle_price = if item.price_mint.is_some() {
+            None
+        } else {
+            price_update_v2
+                .map(|price_update_v2| get_oracle_price(price_update_v2, accepted_mint, now))
+                .transpose()?
+        };
+        let payment_subtotal =
+            get_item_payment_subtotal(amount, item, payment_mint, oracle_price.as_ref())?;
+        let order_fee_bps = config.get_order_fee_bps(store, now);
+        let platform_fee = get_platform_fee(payment_subtotal, order_fee_bps)?;
+
+        let (oracle_price, oracle_exponent) = oracle_price.map_or((0, 0), |oracle_price| {
+            (oracle_price.price, oracle_price.exponent)
+        });
+
+        Ok(OrderQuote {
+            payment_subtotal,
+            platform_fee,
+            order_fee_bps,
+            oracle_price,
+            oracle_exponent,
+        })
+    }
+}
+
+#[derive(Accounts)]
+pub struct QuoteOrder<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [STORE_SEED, store.authority.key().as_ref()],
+        bump = store.bump,
+    )]
+    pub store: Account<'info, Store>,
+    #[account(
+        has_one = store,
+    )]
+    pub item: Account<'info, Item>,
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    pub price_update_v2: Option<Account<'info, PriceUpdateV2>>,
+}
+
+impl QuoteOrder<'_> {
+    pub fn handler(ctx: Context<QuoteOrder>, amount: u32) -> Result<OrderQuote> {
+        let QuoteOrder {
+            config,
+            store,
+            item,
+            payment_mint,
+            price_update_v2,
+        } = ctx.accounts;
+
+        OrderQuote::new(
+            config,
+            store,
+            item,
+            payment_mint,
+            price_update_v2.as_ref(),
+            amount,
+        )
+    }
+}
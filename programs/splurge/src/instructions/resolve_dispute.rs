@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::spl_token_2022::extension::transfer_fee::MAX_FEE_BASIS_POINTS,
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+
+use crate::{
+    constants::{CONFIG_SEED, ORDER_SEED, PAUSE_COMPLETION, STORE_SEED},
+    error::SplurgeError,
+    events::DisputeResolved,
+    order_signer,
+    state::{Config, Item, Order, OrderStatus, Shopper, Store},
+    utils::{get_bps_share, get_store_payouts, init_payout_token_accounts, CreateTokenAccounts},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ResolveDisputeArgs {
+    pub refund_bps: u16,
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+    #[account(mut)]
+    pub authority: SystemAccount<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = operator @ SplurgeError::UnauthorizedOperator,
+        constraint = !config.is_paused(PAUSE_COMPLETION) @ SplurgeError::CompletionPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        has_one = authority @ SplurgeError::InvalidShopperAuthority,
+    )]
+    pub shopper: Account<'info, Shopper>,
+    #[account(
+        seeds = [STORE_SEED, store.authority.key().as_ref()],
+        bump = store.bump,
+    )]
+    pub store: Account<'info, Store>,
+    #[account(
+        has_one = store,
+    )]
+    pub item: Account<'info, Item>,
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, shopper.key().as_ref(), item.key().as_ref(), order.timestamp.to_le_bytes().as_ref()],
+        bump = order.bump,
+        constraint = order.status == OrderStatus::Disputed @ SplurgeError::OrderNotDisputed,
+        constraint = order.payment_mint == payment_mint.key() @ SplurgeError::InvalidOrderPaymentMint,
+    )]
+    pub order: Account<'info, Order>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = order,
+        associated_token::token_program = token_program,
+    )]
+    pub order_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = operator,
+        associated_token::mint = payment_mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program,
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = operator,
+        associated_token::mint = payment_mint,
+        associated_token::authority = store,
+        associated_token::token_program = token_program,
+    )]
+    pub store_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl ResolveDispute<'_> {
+    /// Payout recipient wallets and writable token accounts are passed as remaining accounts in
+    /// pairs, in the same order as the order's payout recipients. Missing token accounts are
+    /// created, paid by the operator
+    pub fn handler<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveDispute<'info>>,
+        args: ResolveDisputeArgs,
+    ) -> Result<()> {
+        let ResolveDisputeArgs { refund_bps } = args;
+
+        require_gte!(
+            MAX_FEE_BASIS_POINTS,
+            refund_bps,
+            SplurgeError::InvalidRefundBps
+        );
+
+        let ResolveDispute {
+            operator,
+            authority,
+            authority_token_account,
+            item,
+            order,
+            order_token_account,
+            payment_mint,
+            shopper,
+            store_token_account,
+            system_program,
+            token_program,
+            associated_token_program,
+            ..
+        } = ctx.accounts;
+
+        let shopper_key = shopper.key();
+        let item_key = item.key();
+        let order_timestamp = order.timestamp.to_le_bytes();
+
+        let signer_seeds: &[&[u8]] =
+            order_signer!(shopper_key, item_key, order_timestamp, order.bump);
+        let decimals = payment_mint.decimals;
+
+        let refund_amount = get_bps_share(order_token_account.amount, refund_bps)?;
+        let payout_amount = order_token_account
+            .amount
+            .checked_sub(refund_amount)
+            .ok_or(SplurgeError::MathOverflow)?;
+
+        if refund_amount > 0 {
+            transfer_checked(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        authority: order.to_account_info(),
+                        mint: payment_mint.to_account_info(),
+                        from: order_token_account.to_account_info(),
+                        to: authority_token_account.to_account_info(),
+                    },
+                )
+                .with_signer(&[signer_seeds]),
+                refund_amount,
+                decimals,
+            )?;
+        }
+
+        let payout_token_accounts = init_payout_token_accounts(
+            &order.payout_recipients,
+            ctx.remaining_accounts,
+            &CreateTokenAccounts {
+                payer: operator.to_account_info(),
+                payment_mint: payment_mint.to_account_info(),
+                system_program: system_program.to_account_info(),
+                token_program: token_program.to_account_info(),
+                associated_token_program: associated_token_program.to_account_info(),
+            },
+        )?;
+        let store_payouts = get_store_payouts(
+            &order.payout_recipients,
+            payout_amount,
+            store_token_account.to_account_info(),
+            payout_token_accounts,
+        )?;
+
+        for (token_account, amount) in store_payouts {
+            if amount == 0 {
+                continue;
+            }
+
+            transfer_checked(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        authority: order.to_account_info(),
+                        mint: payment_mint.to_account_info(),
+                        from: order_token_account.to_account_info(),
+                        to: token_account,
+                    },
+                )
+                .with_signer(&[signer_seeds]),
+                amount,
+                decimals,
+            )?;
+        }
+
+        close_account(
+            CpiContext::new(
+                token_program.to_account_info(),
+                CloseAccount {
+                    authority: order.to_account_info(),
+                    account: order_token_account.to_account_info(),
+                    destination: authority.to_account_info(),
+                },
+            )
+            .with_signer(&[signer_seeds]),
+        )?;
+
+        order.status = OrderStatus::Resolved;
+        order.finalized_at = Clock::get()?.unix_timestamp;
+
+        emit!(DisputeResolved {
+            order: order.key(),
+            refund_bps,
+            refund_amount,
+            payout_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Order::invariant(&order)
+    }
+}
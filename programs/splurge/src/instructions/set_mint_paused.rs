@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::CONFIG_SEED, error::SplurgeError, events::AcceptedMintPauseUpdated, state::Config,
+};
+
+#[derive(Accounts)]
+pub struct SetMintPaused<'info> {
+    pub pauser: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = pauser @ SplurgeError::UnauthorizedPauser,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl SetMintPaused<'_> {
+    pub fn handler(ctx: Context<SetMintPaused>, mint: Pubkey, is_paused: bool) -> Result<()> {
+        let SetMintPaused { pauser, config } = ctx.accounts;
+
+        let accepted_mint = config
+            .accepted_mints
+            .iter_mut()
+            .find(|m| m.mint == mint)
+            .ok_or(SplurgeError::PaymentMintNotAccepted)?;
+
+        accepted_mint.is_paused = is_paused;
+
+        emit!(AcceptedMintPauseUpdated {
+            mint,
+            is_paused,
+            updated_by: pauser.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Config::invariant(&config)
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::CONFIG_SEED, error::SplurgeError, events::PausedOperationsUpdated, state::Config,
+};
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub pauser: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = pauser @ SplurgeError::UnauthorizedPauser,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl SetPaused<'_> {
+    pub fn handler(ctx: Context<SetPaused>, paused_operations: u8) -> Result<()> {
+        let SetPaused { pauser, config } = ctx.accounts;
+
+        config.paused_operations = paused_operations;
+
+        emit!(PausedOperationsUpdated {
+            paused_operations,
+            updated_by: pauser.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Config::invariant(&config)
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::STORE_SEED,
+    state::{PayoutRecipient, Store},
+};
+
+#[derive(Accounts)]
+#[instruction(payout_recipients: Vec<PayoutRecipient>)]
+pub struct SetPayoutRecipients<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        realloc = Store::space(&store.name, &store.image, &store.about, &payout_recipients),
+        realloc::payer = authority,
+        realloc::zero = false,
+        seeds = [STORE_SEED, authority.key().as_ref()],
+        bump = store.bump,
+        has_one = authority,
+    )]
+    pub store: Account<'info, Store>,
+    pub system_program: Program<'info, System>,
+}
+
+impl SetPayoutRecipients<'_> {
+    pub fn handler(
+        ctx: Context<SetPayoutRecipients>,
+        payout_recipients: Vec<PayoutRecipient>,
+    ) -> Result<()> {
+        let SetPayoutRecipients { store, .. } = ctx.accounts;
+
+        store.payout_recipients = payout_recipients;
+
+        Store::invariant(&store)
+    }
+}
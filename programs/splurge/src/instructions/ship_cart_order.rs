@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{get_associated_token_address_with_program_id, AssociatedToken},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use tuktuk_program::tuktuk::{cpi::accounts::QueueTaskV0, program::Tuktuk};
+
+use crate::{
+    constants::{CART_ORDER_SEED, CONFIG_SEED, PAUSE_SHIPPING},
+    error::SplurgeError,
+    events::CartOrderShipped,
+    state::{CartOrder, Config, OrderStatus, Shopper, Store},
+    utils::{get_payout_account_metas, queue_complete_cart_order_task},
+};
+
+#[derive(Accounts)]
+pub struct ShipCartOrder<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = operator @ SplurgeError::UnauthorizedOperator,
+        constraint = !config.is_paused(PAUSE_SHIPPING) @ SplurgeError::ShippingPaused,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub authority: SystemAccount<'info>,
+    #[account(
+        has_one = authority @ SplurgeError::InvalidShopperAuthority,
+    )]
+    pub shopper: Account<'info, Shopper>,
+    #[account(
+        mut,
+        seeds = [CART_ORDER_SEED, shopper.key().as_ref(), cart_order.timestamp.to_le_bytes().as_ref()],
+        bump = cart_order.bump,
+        constraint = cart_order.status == OrderStatus::Pending @ SplurgeError::OrderNotPending,
+        constraint = cart_order.payment_mint == payment_mint.key() @ SplurgeError::InvalidOrderPaymentMint,
+    )]
+    pub cart_order: Account<'info, CartOrder>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        associated_token::mint = payment_mint,
+        associated_token::authority = cart_order,
+        associated_token::token_program = token_program,
+    )]
+    pub cart_order_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub tuktuk: Program<'info, Tuktuk>,
+    /// CHECK: Task queue, used in CPI
+    #[account(mut)]
+    pub task_queue: UncheckedAccount<'info>,
+    /// CHECK: Task, used in CPI
+    #[account(mut)]
+    pub task: UncheckedAccount<'info>,
+    /// CHECK: Task queue authority, used in CPI
+    pub task_queue_authority: UncheckedAccount<'info>,
+}
+
+impl ShipCartOrder<'_> {
+    /// Store accounts are passed as remaining accounts, one per line item in order. The escrow is
+    /// held for the longest completion delay of the stores in the cart, and each store's payout
+    /// recipients are snapshotted on its line item
+    pub fn handler<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ShipCartOrder<'info>>,
+        task_id: u16,
+    ) -> Result<()> {
+        let ShipCartOrder {
+            operator,
+            config,
+            authority,
+            shopper,
+            cart_order,
+            payment_mint,
+            cart_order_token_account,
+            system_program,
+            token_program,
+            associated_token_program,
+            tuktuk,
+            task_queue,
+            task,
+            task_queue_authority,
+        } = ctx.accounts;
+
+        require_eq!(
+            ctx.remaining_accounts.len(),
+            cart_order.line_items.len(),
+            SplurgeError::CartAccountsMismatch
+        );
+
+        let mut completion_delay_secs = 0;
+        let mut line_item_accounts = Vec::with_capacity(cart_order.line_items.len() * 2);
+
+        for (line_item, store_info) in cart_order
+            .line_items
+            .iter_mut()
+            .zip(ctx.remaining_accounts.iter())
+        {
+            require_keys_eq!(
+                store_info.key(),
+                line_item.store,
+                SplurgeError::InvalidCartStore
+            );
+
+            let store = Account::<Store>::try_from(store_info)?;
+
+            completion_delay_secs =
+                completion_delay_secs.max(config.get_completion_delay_secs(&store));
+
+            line_item_accounts.push(AccountMeta::new(store.key(), false));
+            line_item_accounts.push(AccountMeta::new(
+                get_associated_token_address_with_program_id(
+                    &store.key(),
+                    &payment_mint.key(),
+                    &token_program.key(),
+                ),
+                false,
+            ));
+            line_item_accounts.extend(get_payout_account_metas(
+                &store.payout_recipients,
+                &payment_mint.key(),
+                &token_program.key(),
+            ));
+
+            line_item.payout_recipients = store.payout_recipients.clone();
+        }
+
+        cart_order.status = OrderStatus::Shipping;
+        cart_order.task = task.key();
+        let timestamp = Clock::get()?.unix_timestamp;
+        let trigger_timestamp = timestamp + completion_delay_secs as i64;
+
+        queue_complete_cart_order_task(
+            tuktuk.to_account_info(),
+            QueueTaskV0 {
+                payer: operator.to_account_info(),
+                queue_authority: config.to_account_info(),
+                task_queue: task_queue.to_account_info(),
+                task_queue_authority: task_queue_authority.to_account_info(),
+                task: task.to_account_info(),
+                system_program: system_program.to_account_info(),
+            },
+            config.bump,
+            crate::accounts::CompleteCartOrder {
+                operator: config.operator,
+                authority: authority.key(),
+                config: config.key(),
+                shopper: shopper.key(),
+                cart_order: cart_order.key(),
+                payment_mint: payment_mint.key(),
+                cart_order_token_account: cart_order_token_account.key(),
+                referrer: cart_order.get_owed_referrer(),
+                referrer_token_account: cart_order
+                    .get_referrer_token_account(&payment_mint.key(), &token_program.key()),
+                system_program: system_program.key(),
+                token_program: token_program.key(),
+                associated_token_program: associated_token_program.key(),
+            },
+            line_item_accounts,
+            task_id,
+            trigger_timestamp,
+        )?;
+
+        emit!(CartOrderShipped {
+            cart_order: cart_order.key(),
+            timestamp,
+        });
+
+        CartOrder::invariant(&cart_order)
+    }
+}
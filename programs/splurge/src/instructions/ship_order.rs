@@ -1,35 +1,29 @@
-use anchor_lang::{
-    prelude::*,
-    solana_program::{instruction::Instruction, pubkey::PUBKEY_BYTES},
-    InstructionData,
-};
+use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
-use tuktuk_program::{
-    compile_transaction,
-    tuktuk::cpi::{accounts::QueueTaskV0, queue_task_v0},
-    types::QueueTaskArgsV0,
-    TransactionSourceV0, TriggerV0,
-};
+use tuktuk_program::tuktuk::{cpi::accounts::QueueTaskV0, program::Tuktuk};
 
 use crate::{
-    constants::{CONFIG_SEED, ORDER_SEED, TASK_TRIGGER_DELAY},
+    constants::{CONFIG_SEED, ORDER_SEED, PAUSE_SHIPPING, STORE_SEED},
     error::SplurgeError,
-    events::OrderShipped,
-    instructions::CompleteOrder,
+    events::{ActorRole, OrderShipped},
     state::{Config, Item, Order, OrderStatus, Shopper, Store},
+    utils::{get_payout_account_metas, queue_complete_order_task},
 };
 
 #[derive(Accounts)]
 pub struct ShipOrder<'info> {
-    #[account(mut)]
-    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        constraint = shipper.key() == config.operator || shipper.key() == store.authority @ SplurgeError::UnauthorizedShipper,
+    )]
+    pub shipper: Signer<'info>,
     #[account(
         seeds = [CONFIG_SEED],
         bump = config.bump,
-        has_one = admin @ SplurgeError::UnauthorizedAdmin,
+        constraint = !config.is_paused(PAUSE_SHIPPING) @ SplurgeError::ShippingPaused,
     )]
     pub config: Account<'info, Config>,
     #[account(
@@ -43,15 +37,25 @@ pub struct ShipOrder<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
     #[account(mut)]
     pub authority: SystemAccount<'info>,
+    #[account(
+        has_one = store,
+    )]
     pub item: Account<'info, Item>,
     #[account(mut)]
     pub order_token_account: InterfaceAccount<'info, TokenAccount>,
     pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        has_one = authority @ SplurgeError::InvalidShopperAuthority,
+    )]
     pub shopper: Account<'info, Shopper>,
+    #[account(
+        seeds = [STORE_SEED, store.authority.key().as_ref()],
+        bump = store.bump,
+    )]
     pub store: Account<'info, Store>,
     #[account(
         init_if_needed,
-        payer = admin,
+        payer = shipper,
         associated_token::mint = payment_mint,
         associated_token::authority = store,
         associated_token::token_program = token_program,
@@ -59,8 +63,7 @@ pub struct ShipOrder<'info> {
     pub store_token_account: InterfaceAccount<'info, TokenAccount>,
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
-    /// CHECK: Tuktuk program, used in CPI
-    pub tuktuk: UncheckedAccount<'info>,
+    pub tuktuk: Program<'info, Tuktuk>,
     /// CHECK: Task queue, used in CPI
     #[account(mut)]
     pub task_queue: UncheckedAccount<'info>,
@@ -74,7 +77,7 @@ pub struct ShipOrder<'info> {
 impl ShipOrder<'_> {
     pub fn handler(ctx: Context<ShipOrder>, task_id: u16) -> Result<()> {
         let ShipOrder {
-            admin,
+            shipper,
             config,
             order,
             tuktuk,
@@ -93,68 +96,61 @@ impl ShipOrder<'_> {
             task_queue_authority,
         } = ctx.accounts;
 
+        let role = if shipper.key() == config.operator {
+            ActorRole::Operator
+        } else {
+            ActorRole::Store
+        };
+
         order.status = OrderStatus::Shipping;
+        order.task = task.key();
+        order.payout_recipients = store.payout_recipients.clone();
+        order.shipped_amount = order.amount;
         let timestamp = Clock::get()?.unix_timestamp;
+        let trigger_timestamp = timestamp + config.get_completion_delay_secs(store) as i64;
 
-        let (compiled_tx, _) = compile_transaction(
-            vec![Instruction {
-                program_id: crate::ID,
-                accounts: CompleteOrder {
-                    admin: admin.clone(),
-                    associated_token_program: associated_token_program.clone(),
-                    authority: authority.clone(),
-                    config: config.clone(),
-                    item: item.clone(),
-                    order: order.clone(),
-                    order_token_account: order_token_account.clone(),
-                    payment_mint: payment_mint.clone(),
-                    shopper: shopper.clone(),
-                    store: store.clone(),
-                    store_token_account: store_token_account.clone(),
-                    system_program: system_program.clone(),
-                    token_program: token_program.clone(),
-                }
-                .to_account_metas(None)
-                .to_vec(),
-                data: crate::instruction::CompleteOrder.data(),
-            }],
-            vec![],
-        )
-        .unwrap();
-
-        let trigger_timestamp = timestamp + TASK_TRIGGER_DELAY as i64;
-        let order_key_string = order.key().to_string();
-        let description = format!(
-            "Complete order {}...{} at {}",
-            &order_key_string[..4],
-            &order_key_string[PUBKEY_BYTES - 4..PUBKEY_BYTES],
-            trigger_timestamp
-        );
-
-        queue_task_v0(
-            CpiContext::new(
-                tuktuk.to_account_info(),
-                QueueTaskV0 {
-                    payer: admin.to_account_info(),
-                    queue_authority: admin.to_account_info(),
-                    task_queue: task_queue.to_account_info(),
-                    task_queue_authority: task_queue_authority.to_account_info(),
-                    task: task.to_account_info(),
-                    system_program: system_program.to_account_info(),
-                },
-            ),
-            QueueTaskArgsV0 {
-                trigger: TriggerV0::Timestamp(trigger_timestamp),
-                transaction: TransactionSourceV0::CompiledV0(compiled_tx),
-                crank_reward: None,
-                free_tasks: 1,
-                id: task_id,
-                description,
+        queue_complete_order_task(
+            tuktuk.to_account_info(),
+            QueueTaskV0 {
+                payer: shipper.to_account_info(),
+                queue_authority: config.to_account_info(),
+                task_queue: task_queue.to_account_info(),
+                task_queue_authority: task_queue_authority.to_account_info(),
+                task: task.to_account_info(),
+                system_program: system_program.to_account_info(),
             },
+            config.bump,
+            crate::accounts::CompleteOrder {
+                operator: config.operator,
+                associated_token_program: associated_token_program.key(),
+                authority: authority.key(),
+                config: config.key(),
+                item: item.key(),
+                order: order.key(),
+                order_token_account: order_token_account.key(),
+                payment_mint: payment_mint.key(),
+                referrer: order.get_owed_referrer(),
+                referrer_token_account: order
+                    .get_referrer_token_account(&payment_mint.key(), &token_program.key()),
+                shopper: shopper.key(),
+                store: store.key(),
+                store_token_account: store_token_account.key(),
+                system_program: system_program.key(),
+                token_program: token_program.key(),
+            },
+            get_payout_account_metas(
+                &order.payout_recipients,
+                &payment_mint.key(),
+                &token_program.key(),
+            ),
+            task_id,
+            trigger_timestamp,
         )?;
 
         emit!(OrderShipped {
             order: order.key(),
+            shipped_by: shipper.key(),
+            role,
             timestamp,
         });
 
@@ -3,15 +3,24 @@ use anchor_lang::prelude::*;
 use crate::{
     constants::CONFIG_SEED,
     error::SplurgeError,
-    state::{AcceptedMint, Config},
+    events::{
+        AdminTransferStarted, FeeTiersIncreaseStaged, MaxOrderFeeIncreaseStaged,
+        OrderFeeIncreaseStaged,
+    },
+    state::{AcceptedMint, Config, FeeTier},
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateConfigArgs {
     pub new_admin: Option<Pubkey>,
-    pub is_paused: Option<bool>,
+    pub paused_operations: Option<u8>,
     pub order_fee_bps: Option<u16>,
+    pub max_order_fee_bps: Option<u16>,
     pub accepted_mints: Option<Vec<AcceptedMint>>,
+    pub shopper_cancel_window_secs: Option<u32>,
+    pub completion_delay_secs: Option<u32>,
+    pub shipping_deadline_secs: Option<u32>,
+    pub fee_tiers: Option<Vec<FeeTier>>,
 }
 
 #[derive(Accounts)]
@@ -21,11 +30,7 @@ pub struct UpdateConfig<'info> {
     pub admin: Signer<'info>,
     #[account(
         mut,
-        realloc = if args.accepted_mints.is_some() {
-            Config::space(args.accepted_mints.as_ref().unwrap())
-        } else {
-            config.to_account_info().data_len()
-        },
+        realloc = Config::space(args.accepted_mints.as_ref().unwrap_or(&config.accepted_mints)),
         realloc::payer = admin,
         realloc::zero = false,
         seeds = [CONFIG_SEED],
@@ -40,23 +45,56 @@ impl UpdateConfig<'_> {
     pub fn handler(ctx: Context<UpdateConfig>, args: UpdateConfigArgs) -> Result<()> {
         let UpdateConfigArgs {
             new_admin,
-            is_paused,
+            paused_operations,
             order_fee_bps,
+            max_order_fee_bps,
             accepted_mints,
+            shopper_cancel_window_secs,
+            completion_delay_secs,
+            shipping_deadline_secs,
+            fee_tiers,
         } = args;
 
         let UpdateConfig { config, .. } = ctx.accounts;
 
         if let Some(new_admin) = new_admin {
-            config.admin = new_admin;
+            config.pending_admin = Some(new_admin);
+
+            emit!(AdminTransferStarted {
+                admin: config.admin,
+                pending_admin: new_admin,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
+
+        if let Some(paused_operations) = paused_operations {
+            config.paused_operations = paused_operations;
         }
 
-        if let Some(is_paused) = is_paused {
-            config.is_paused = is_paused;
+        if let Some(max_order_fee_bps) = max_order_fee_bps {
+            let now = Clock::get()?.unix_timestamp;
+
+            if let Some(pending_max_order_fee) =
+                config.set_max_order_fee_bps(max_order_fee_bps, now)
+            {
+                emit!(MaxOrderFeeIncreaseStaged {
+                    max_order_fee_bps: pending_max_order_fee.max_order_fee_bps,
+                    effective_timestamp: pending_max_order_fee.effective_timestamp,
+                    timestamp: now,
+                });
+            }
         }
 
         if let Some(order_fee_bps) = order_fee_bps {
-            config.order_fee_bps = order_fee_bps;
+            let now = Clock::get()?.unix_timestamp;
+
+            if let Some(pending_order_fee) = config.set_order_fee_bps(order_fee_bps, now) {
+                emit!(OrderFeeIncreaseStaged {
+                    order_fee_bps: pending_order_fee.order_fee_bps,
+                    effective_timestamp: pending_order_fee.effective_timestamp,
+                    timestamp: now,
+                });
+            }
         }
 
         if let Some(mut accepted_mints) = accepted_mints {
@@ -65,6 +103,29 @@ impl UpdateConfig<'_> {
             config.accepted_mints = accepted_mints;
         }
 
+        if let Some(shopper_cancel_window_secs) = shopper_cancel_window_secs {
+            config.shopper_cancel_window_secs = shopper_cancel_window_secs;
+        }
+
+        if let Some(completion_delay_secs) = completion_delay_secs {
+            config.completion_delay_secs = completion_delay_secs;
+        }
+
+        if let Some(shipping_deadline_secs) = shipping_deadline_secs {
+            config.shipping_deadline_secs = shipping_deadline_secs;
+        }
+
+        if let Some(fee_tiers) = fee_tiers {
+            let now = Clock::get()?.unix_timestamp;
+
+            if let Some(effective_timestamp) = config.set_fee_tiers(fee_tiers, now) {
+                emit!(FeeTiersIncreaseStaged {
+                    effective_timestamp,
+                    timestamp: now,
+                });
+            }
+        }
+
         Config::invariant(&config)
     }
 }
@@ -1,13 +1,16 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::ITEM_SEED,
-    state::{Item, Store},
+    constants::{CONFIG_SEED, ITEM_SEED, PAUSE_LISTING},
+    error::SplurgeError,
+    state::{Config, Item, PricingMode, Store},
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateItemArgs {
     pub price: Option<u64>,
+    pub pricing_mode: Option<PricingMode>,
+    pub referral_bps: Option<u16>,
     pub inventory_count: Option<u32>,
 }
 
@@ -20,8 +23,15 @@ pub struct UpdateItem<'info> {
         bump = item.bump,
     )]
     pub item: Account<'info, Item>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(PAUSE_LISTING) @ SplurgeError::ListingPaused,
+    )]
+    pub config: Account<'info, Config>,
     #[account(
         has_one = authority,
+        constraint = !store.is_suspended @ SplurgeError::StoreSuspended,
     )]
     pub store: Account<'info, Store>,
 }
@@ -30,15 +40,36 @@ impl UpdateItem<'_> {
     pub fn handler(ctx: Context<UpdateItem>, args: UpdateItemArgs) -> Result<()> {
         let UpdateItemArgs {
             price,
+            pricing_mode,
+            referral_bps,
             inventory_count,
         } = args;
 
-        let UpdateItem { item, .. } = ctx.accounts;
+        let UpdateItem { config, item, .. } = ctx.accounts;
+
+        if let Some(pricing_mode) = pricing_mode {
+            let price_mint: Option<Pubkey> = pricing_mode.into();
+
+            // A price in USD and a price in atomic units of a mint are not interchangeable
+            if price_mint != item.price_mint {
+                require!(price.is_some(), SplurgeError::PriceRequired);
+            }
+
+            if let Some(price_mint) = price_mint {
+                config.get_accepted_mint(price_mint)?;
+            }
+
+            item.price_mint = price_mint;
+        };
 
         if let Some(price) = price {
             item.price = price;
         };
 
+        if let Some(referral_bps) = referral_bps {
+            item.referral_bps = referral_bps;
+        };
+
         if let Some(inventory_count) = inventory_count {
             item.inventory_count = inventory_count;
         };
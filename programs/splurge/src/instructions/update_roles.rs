@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::CONFIG_SEED, error::SplurgeError, events::RolesUpdated, state::Config};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateRolesArgs {
+    pub operator: Option<Pubkey>,
+    pub treasurer: Option<Pubkey>,
+    pub pauser: Option<Pubkey>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRoles<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ SplurgeError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl UpdateRoles<'_> {
+    pub fn handler(ctx: Context<UpdateRoles>, args: UpdateRolesArgs) -> Result<()> {
+        let UpdateRoles { config, .. } = ctx.accounts;
+        let UpdateRolesArgs {
+            operator,
+            treasurer,
+            pauser,
+        } = args;
+
+        if let Some(operator) = operator {
+            config.operator = operator;
+        }
+
+        if let Some(treasurer) = treasurer {
+            config.treasurer = treasurer;
+        }
+
+        if let Some(pauser) = pauser {
+            config.pauser = pauser;
+        }
+
+        emit!(RolesUpdated {
+            operator: config.operator,
+            treasurer: config.treasurer,
+            pauser: config.pauser,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Config::invariant(&config)
+    }
+}
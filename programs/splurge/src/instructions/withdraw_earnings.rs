@@ -5,23 +5,26 @@ use anchor_spl::{
 };
 
 use crate::{
-    constants::{CONFIG_SEED, STORE_SEED},
+    constants::{CONFIG_SEED, PAUSE_WITHDRAWALS, STORE_SEED},
+    error::SplurgeError,
+    events::EarningsWithdrawn,
     state::{Config, Store},
     store_signer,
 };
 
 #[derive(Accounts)]
 pub struct WithdrawEarnings<'info> {
-    #[account(mut)]
     pub authority: Signer<'info>,
     #[account(
         seeds = [CONFIG_SEED],
         bump = config.bump,
+        constraint = !config.is_paused(PAUSE_WITHDRAWALS) @ SplurgeError::WithdrawalsPaused,
     )]
     pub config: Account<'info, Config>,
     #[account(
         seeds = [STORE_SEED, authority.key().as_ref()],
         bump = store.bump,
+        constraint = !store.is_suspended @ SplurgeError::StoreSuspended,
     )]
     pub store: Account<'info, Store>,
     #[account(
@@ -36,23 +39,22 @@ pub struct WithdrawEarnings<'info> {
     )]
     pub store_token_account: InterfaceAccount<'info, TokenAccount>,
     #[account(
-        init_if_needed,
-        payer = authority,
-        associated_token::mint = payment_mint,
-        associated_token::authority = authority,
-        associated_token::token_program = token_program,
+        mut,
+        token::mint = payment_mint,
+        token::token_program = token_program,
     )]
-    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
-    pub system_program: Program<'info, System>,
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
 impl WithdrawEarnings<'_> {
-    pub fn handler(ctx: Context<WithdrawEarnings>) -> Result<()> {
+    /// Withdraws the full balance if `amount` is not set. Mints removed from the accepted
+    /// mints can still be withdrawn
+    pub fn handler(ctx: Context<WithdrawEarnings>, amount: Option<u64>) -> Result<()> {
         let WithdrawEarnings {
-            authority_token_account,
-            config,
+            authority,
+            destination_token_account,
             payment_mint,
             store,
             store_token_account,
@@ -60,7 +62,12 @@ impl WithdrawEarnings<'_> {
             ..
         } = ctx.accounts;
 
-        config.validate_mint(payment_mint.key())?;
+        let amount = amount.unwrap_or(store_token_account.amount);
+
+        require!(
+            amount > 0 && amount <= store_token_account.amount,
+            SplurgeError::InvalidWithdrawalAmount
+        );
 
         let authority_key = store.authority.key();
         let signer_seeds: &[&[u8]] = store_signer!(authority_key, store.bump);
@@ -72,12 +79,23 @@ impl WithdrawEarnings<'_> {
                     from: store_token_account.to_account_info(),
                     authority: store.to_account_info(),
                     mint: payment_mint.to_account_info(),
-                    to: authority_token_account.to_account_info(),
+                    to: destination_token_account.to_account_info(),
                 },
             )
             .with_signer(&[signer_seeds]),
-            store_token_account.amount,
+            amount,
             payment_mint.decimals,
-        )
+        )?;
+
+        emit!(EarningsWithdrawn {
+            store: store.key(),
+            authority: authority.key(),
+            payment_mint: payment_mint.key(),
+            destination: destination_token_account.key(),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
     }
 }
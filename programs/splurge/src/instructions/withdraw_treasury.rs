@@ -6,16 +6,16 @@ use anchor_spl::{
 };
 
 use crate::{
-    constants::{CONFIG_SEED, TREASURY_SEED},
+    constants::{CONFIG_SEED, PAUSE_WITHDRAWALS, TREASURY_SEED},
     error::SplurgeError,
+    events::TreasuryWithdrawn,
     state::Config,
     treasury_signer,
 };
 
 #[derive(Accounts)]
 pub struct WithdrawTreasury<'info> {
-    #[account(mut)]
-    pub admin: Signer<'info>,
+    pub treasurer: Signer<'info>,
     #[account(
         seeds = [TREASURY_SEED],
         bump = config.treasury_bump,
@@ -24,7 +24,8 @@ pub struct WithdrawTreasury<'info> {
     #[account(
         seeds = [CONFIG_SEED],
         bump = config.bump,
-        has_one = admin @ SplurgeError::UnauthorizedAdmin,
+        has_one = treasurer @ SplurgeError::UnauthorizedTreasurer,
+        constraint = !config.is_paused(PAUSE_WITHDRAWALS) @ SplurgeError::WithdrawalsPaused,
     )]
     pub config: Account<'info, Config>,
     #[account(
@@ -39,30 +40,37 @@ pub struct WithdrawTreasury<'info> {
     )]
     pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
     #[account(
-        init_if_needed,
-        payer = admin,
-        associated_token::mint = payment_mint,
-        associated_token::authority = admin,
-        associated_token::token_program = token_program,
+        mut,
+        token::mint = payment_mint,
+        token::token_program = token_program,
     )]
-    pub admin_token_account: InterfaceAccount<'info, TokenAccount>,
-    pub system_program: Program<'info, System>,
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
 impl WithdrawTreasury<'_> {
-    pub fn handler(ctx: Context<WithdrawTreasury>) -> Result<()> {
+    /// Withdraws the full balance if `amount` is not set. Mints removed from the accepted
+    /// mints can still be withdrawn
+    pub fn handler(ctx: Context<WithdrawTreasury>, amount: Option<u64>) -> Result<()> {
         let WithdrawTreasury {
-            admin_token_account,
             config,
+            destination_token_account,
             payment_mint,
             token_program,
+            treasurer,
             treasury,
             treasury_token_account,
             ..
         } = ctx.accounts;
 
+        let amount = amount.unwrap_or(treasury_token_account.amount);
+
+        require!(
+            amount > 0 && amount <= treasury_token_account.amount,
+            SplurgeError::InvalidWithdrawalAmount
+        );
+
         let signer_seeds: &[&[u8]] = treasury_signer!(config.treasury_bump);
 
         transfer_checked(
@@ -72,12 +80,22 @@ impl WithdrawTreasury<'_> {
                     from: treasury_token_account.to_account_info(),
                     authority: treasury.to_account_info(),
                     mint: payment_mint.to_account_info(),
-                    to: admin_token_account.to_account_info(),
+                    to: destination_token_account.to_account_info(),
                 },
             )
             .with_signer(&[signer_seeds]),
-            treasury_token_account.amount,
+            amount,
             payment_mint.decimals,
-        )
+        )?;
+
+        emit!(TreasuryWithdrawn {
+            treasurer: treasurer.key(),
+            payment_mint: payment_mint.key(),
+            destination: destination_token_account.key(),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
     }
 }
@@ -5,6 +5,7 @@ pub mod instructions;
 #[doc(hidden)]
 pub mod macros;
 pub mod state;
+pub mod utils;
 
 use anchor_lang::prelude::*;
 
@@ -27,6 +28,30 @@ pub mod splurge {
         UpdateConfig::handler(ctx, args)
     }
 
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        AcceptAdmin::handler(ctx)
+    }
+
+    pub fn cancel_admin_transfer(ctx: Context<CancelAdminTransfer>) -> Result<()> {
+        CancelAdminTransfer::handler(ctx)
+    }
+
+    pub fn update_roles(ctx: Context<UpdateRoles>, args: UpdateRolesArgs) -> Result<()> {
+        UpdateRoles::handler(ctx, args)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused_operations: u8) -> Result<()> {
+        SetPaused::handler(ctx, paused_operations)
+    }
+
+    pub fn set_mint_paused(
+        ctx: Context<SetMintPaused>,
+        mint: Pubkey,
+        is_paused: bool,
+    ) -> Result<()> {
+        SetMintPaused::handler(ctx, mint, is_paused)
+    }
+
     pub fn initialize_shopper(
         ctx: Context<InitializeShopper>,
         args: InitializeShopperArgs,
@@ -41,6 +66,17 @@ pub mod splurge {
         InitializeStore::handler(ctx, args)
     }
 
+    pub fn configure_store(ctx: Context<ConfigureStore>, args: ConfigureStoreArgs) -> Result<()> {
+        ConfigureStore::handler(ctx, args)
+    }
+
+    pub fn set_payout_recipients(
+        ctx: Context<SetPayoutRecipients>,
+        payout_recipients: Vec<state::PayoutRecipient>,
+    ) -> Result<()> {
+        SetPayoutRecipients::handler(ctx, payout_recipients)
+    }
+
     pub fn list_item(ctx: Context<ListItem>, args: ListItemArgs) -> Result<()> {
         ListItem::handler(ctx, args)
     }
@@ -53,31 +89,112 @@ pub mod splurge {
         UnlistItem::handler(ctx)
     }
 
-    pub fn create_order(ctx: Context<CreateOrder>, amount: u32, timestamp: i64) -> Result<()> {
-        CreateOrder::handler(ctx, amount, timestamp)
+    pub fn create_order(
+        ctx: Context<CreateOrder>,
+        amount: u32,
+        timestamp: i64,
+        max_payment: u64,
+        task_id: Option<u16>,
+    ) -> Result<()> {
+        CreateOrder::handler(ctx, amount, timestamp, max_payment, task_id)
+    }
+
+    pub fn quote_order(ctx: Context<QuoteOrder>, amount: u32) -> Result<OrderQuote> {
+        QuoteOrder::handler(ctx, amount)
+    }
+
+    pub fn expire_order(ctx: Context<ExpireOrder>) -> Result<()> {
+        ExpireOrder::handler(ctx)
+    }
+
+    pub fn create_cart_order<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateCartOrder<'info>>,
+        amounts: Vec<u32>,
+        timestamp: i64,
+        max_payment: u64,
+    ) -> Result<()> {
+        CreateCartOrder::handler(ctx, amounts, timestamp, max_payment)
     }
 
     pub fn ship_order(ctx: Context<ShipOrder>, task_id: u16) -> Result<()> {
         ShipOrder::handler(ctx, task_id)
     }
 
+    pub fn partially_fulfill_order(
+        ctx: Context<PartiallyFulfillOrder>,
+        ship_amount: u32,
+        task_id: u16,
+    ) -> Result<()> {
+        PartiallyFulfillOrder::handler(ctx, ship_amount, task_id)
+    }
+
     pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
         CancelOrder::handler(ctx)
     }
 
-    pub fn complete_order(ctx: Context<CompleteOrder>) -> Result<()> {
+    pub fn shopper_cancel_order(ctx: Context<ShopperCancelOrder>) -> Result<()> {
+        ShopperCancelOrder::handler(ctx)
+    }
+
+    pub fn complete_order<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CompleteOrder<'info>>,
+    ) -> Result<()> {
         CompleteOrder::handler(ctx)
     }
 
+    pub fn confirm_delivery<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ConfirmDelivery<'info>>,
+    ) -> Result<()> {
+        ConfirmDelivery::handler(ctx)
+    }
+
+    pub fn ship_cart_order<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ShipCartOrder<'info>>,
+        task_id: u16,
+    ) -> Result<()> {
+        ShipCartOrder::handler(ctx, task_id)
+    }
+
+    pub fn cancel_cart_order<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelCartOrder<'info>>,
+    ) -> Result<()> {
+        CancelCartOrder::handler(ctx)
+    }
+
+    pub fn complete_cart_order<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CompleteCartOrder<'info>>,
+    ) -> Result<()> {
+        CompleteCartOrder::handler(ctx)
+    }
+
+    pub fn open_dispute(ctx: Context<OpenDispute>) -> Result<()> {
+        OpenDispute::handler(ctx)
+    }
+
+    pub fn resolve_dispute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveDispute<'info>>,
+        args: ResolveDisputeArgs,
+    ) -> Result<()> {
+        ResolveDispute::handler(ctx, args)
+    }
+
+    pub fn close_order(ctx: Context<CloseOrder>) -> Result<()> {
+        CloseOrder::handler(ctx)
+    }
+
+    pub fn close_cart_order(ctx: Context<CloseCartOrder>) -> Result<()> {
+        CloseCartOrder::handler(ctx)
+    }
+
     pub fn create_review(ctx: Context<CreateReview>, args: CreateReviewArgs) -> Result<()> {
         CreateReview::handler(ctx, args)
     }
 
-    pub fn withdraw_earnings(ctx: Context<WithdrawEarnings>) -> Result<()> {
-        WithdrawEarnings::handler(ctx)
+    pub fn withdraw_earnings(ctx: Context<WithdrawEarnings>, amount: Option<u64>) -> Result<()> {
+        WithdrawEarnings::handler(ctx, amount)
     }
 
-    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>) -> Result<()> {
-        WithdrawTreasury::handler(ctx)
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: Option<u64>) -> Result<()> {
+        WithdrawTreasury::handler(ctx, amount)
     }
 }
@@ -12,6 +12,13 @@ macro_rules! imprecise_number {
     };
 }
 
+#[macro_export]
+macro_rules! config_signer {
+    ($bump: expr) => {
+        &[CONFIG_SEED, &[$bump]]
+    };
+}
+
 #[macro_export]
 macro_rules! treasury_signer {
     ($bump: expr) => {
@@ -45,3 +52,15 @@ macro_rules! order_signer {
         ]
     };
 }
+
+#[macro_export]
+macro_rules! cart_order_signer {
+    ($shopper_key: expr, $order_timestamp: expr, $bump: expr) => {
+        &[
+            CART_ORDER_SEED,
+            $shopper_key.as_ref(),
+            $order_timestamp.as_ref(),
+            &[$bump],
+        ]
+    };
+}
@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+
+use crate::{
+    constants::{MAX_CART_ITEMS, MAX_PAYOUT_RECIPIENTS, REVIEW_WINDOW_SECS},
+    error::SplurgeError,
+    state::{OrderStatus, PayoutRecipient},
+};
+
+#[account]
+pub struct CartOrder {
+    /// PDA of shopper account
+    pub shopper: Pubkey, // 32
+    /// Unix time of order creation
+    pub timestamp: i64, // 8
+    /// Order status
+    pub status: OrderStatus, // 1
+    /// Payment subtotal of all line items in atomic units of mint
+    pub payment_subtotal: u64, // 8
+    /// Platform fee of all line items in atomic units of mint
+    pub platform_fee: u64, // 8
+    /// Address of stablecoin mint used for payment
+    pub payment_mint: Pubkey, // 32
+    /// Bump used for seed derivation
+    pub bump: u8, // 1
+    /// Tuktuk task queued to complete the order, default until shipped
+    pub task: Pubkey, // 32
+    /// Address credited with referring the order
+    pub referrer: Option<Pubkey>, // 1 + 32
+    /// Unix time the order reached a final status, zero until then
+    pub finalized_at: i64, // 8
+    /// Items purchased in this order
+    pub line_items: Vec<LineItem>, // 4
+}
+
+impl CartOrder {
+    pub fn space(line_items_len: usize) -> usize {
+        CartOrder::DISCRIMINATOR.len()
+            + 32
+            + 8
+            + 1
+            + 8
+            + 8
+            + 32
+            + 1
+            + 32
+            + 1
+            + 32
+            + 8
+            + 4
+            + (LineItem::INIT_SPACE * line_items_len)
+    }
+
+    /// Total referral commission of all line items in atomic units of mint
+    pub fn get_referral_fee(&self) -> Result<u64> {
+        Ok(self
+            .line_items
+            .iter()
+            .try_fold(0_u64, |acc, line_item| {
+                acc.checked_add(line_item.referral_fee)
+            })
+            .ok_or(SplurgeError::MathOverflow)?)
+    }
+
+    /// Referrer of the order, if it is owed a referral fee on completion
+    pub fn get_owed_referrer(&self) -> Option<Pubkey> {
+        self.referrer.filter(|_| {
+            self.line_items
+                .iter()
+                .any(|line_item| line_item.referral_fee > 0)
+        })
+    }
+
+    /// Associated token account of the referrer, if it is owed a referral fee on completion
+    pub fn get_referrer_token_account(
+        &self,
+        payment_mint: &Pubkey,
+        token_program: &Pubkey,
+    ) -> Option<Pubkey> {
+        self.get_owed_referrer().map(|referrer| {
+            get_associated_token_address_with_program_id(&referrer, payment_mint, token_program)
+        })
+    }
+
+    pub fn validate_closable(&self, now: i64) -> Result<()> {
+        match self.status {
+            OrderStatus::Cancelled => {}
+            OrderStatus::Completed => {
+                require_gt!(
+                    now,
+                    self.finalized_at + REVIEW_WINDOW_SECS as i64,
+                    SplurgeError::ReviewWindowOpen
+                );
+            }
+            _ => return err!(SplurgeError::OrderNotFinalized),
+        }
+
+        Ok(())
+    }
+
+    pub fn invariant(&self) -> Result<()> {
+        require_keys_neq!(
+            self.shopper,
+            Pubkey::default(),
+            SplurgeError::InvalidAddress
+        );
+        require_keys_neq!(
+            self.payment_mint,
+            Pubkey::default(),
+            SplurgeError::InvalidAddress
+        );
+        require_gte!(
+            Clock::get()?.unix_timestamp,
+            self.timestamp,
+            SplurgeError::InvalidTimestamp
+        );
+        require!(!self.line_items.is_empty(), SplurgeError::EmptyCart);
+        require_gte!(
+            MAX_CART_ITEMS as usize,
+            self.line_items.len(),
+            SplurgeError::CartTooLarge
+        );
+
+        let (line_items_subtotal, line_items_fee) = self
+            .line_items
+            .iter()
+            .try_fold((0_u64, 0_u64), |(subtotal, fee), line_item| {
+                Some((
+                    subtotal.checked_add(line_item.payment_subtotal)?,
+                    fee.checked_add(line_item.platform_fee)?,
+                ))
+            })
+            .ok_or(SplurgeError::MathOverflow)?;
+
+        require_eq!(
+            line_items_subtotal,
+            self.payment_subtotal,
+            SplurgeError::InvalidCartSubtotal
+        );
+        require_eq!(
+            line_items_fee,
+            self.platform_fee,
+            SplurgeError::InvalidCartSubtotal
+        );
+
+        for line_item in self.line_items.iter() {
+            require_gte!(
+                line_item.payment_subtotal,
+                line_item.referral_fee,
+                SplurgeError::InvalidReferralFee
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
+pub struct LineItem {
+    /// PDA of item account
+    pub item: Pubkey, // 32
+    /// PDA of store account the item belongs to
+    pub store: Pubkey, // 32
+    /// Amount of item purchased
+    pub amount: u32, // 4
+    /// Payment subtotal owed to the store in atomic units of mint
+    pub payment_subtotal: u64, // 8
+    /// Fee rate charged on the line item in basis points
+    pub order_fee_bps: u16, // 2
+    /// Platform fee in atomic units of mint
+    pub platform_fee: u64, // 8
+    /// Referral commission paid from the payment subtotal on completion
+    pub referral_fee: u64, // 8
+    /// Store payout recipients at the time the order was shipped
+    #[max_len(MAX_PAYOUT_RECIPIENTS)]
+    pub payout_recipients: Vec<PayoutRecipient>, // 4 + 34 * 4
+}
@@ -1,29 +1,60 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::MAX_FEE_BASIS_POINTS;
+use pyth_solana_receiver_sdk::price_update::VerificationLevel;
 
-use crate::error::SplurgeError;
+use super::{PendingStoreOrderFee, Store};
+use crate::{
+    constants::{
+        MAX_COMPLETION_DELAY_SECS, MAX_FEE_TIERS, MIN_COMPLETION_DELAY_SECS,
+        ORDER_FEE_TIMELOCK_SECS, PAUSE_ALL,
+    },
+    error::SplurgeError,
+};
 
 #[account]
 pub struct Config {
     /// Fee charged on each order in basis points
     pub order_fee_bps: u16, // 2
-    /// Address that has authority over the config
+    /// Address that has authority over the config and roles
     pub admin: Pubkey, // 32
-    /// Boolean indicating if new orders can be created
-    pub is_paused: bool, // 1
+    /// Bitmask of paused operations, see `PAUSE_*` constants
+    pub paused_operations: u8, // 1
     /// Bump used for seed derivation
     pub bump: u8, // 1
     /// Bump used for seed derivation
     pub treasury_bump: u8, // 1
     /// List of stablecoin mints accepted as payment
     pub accepted_mints: Vec<AcceptedMint>, // 4
-    /// Reserved for future upgrades
-    pub reserved: [u8; 64], // 64
+    /// Seconds after order creation a shopper can still cancel a pending order, 0 for no limit
+    pub shopper_cancel_window_secs: u32, // 4
+    /// Seconds after shipping an order is held in escrow before completion
+    pub completion_delay_secs: u32, // 4
+    /// Seconds after order creation a pending order is expired and refunded, 0 for no deadline
+    pub shipping_deadline_secs: u32, // 4
+    /// Reduced order fees by store completed volume, ascending by volume
+    pub fee_tiers: Vec<FeeTier>, // 4
+    /// Upper bound on any order fee in basis points
+    pub max_order_fee_bps: u16, // 2
+    /// Order fee increase that takes effect once its timelock has elapsed
+    pub pending_order_fee: Option<PendingOrderFee>, // 1 + 10
+    /// Address nominated to become admin once it accepts
+    pub pending_admin: Option<Pubkey>, // 1 + 32
+    /// Address allowed to ship, cancel, complete and resolve orders
+    pub operator: Pubkey, // 32
+    /// Address allowed to withdraw platform fees from the treasury
+    pub treasurer: Pubkey, // 32
+    /// Address allowed to pause and unpause the platform
+    pub pauser: Pubkey, // 32
+    /// Fee tier change that takes effect once its timelock has elapsed
+    pub pending_fee_tiers: Option<PendingFeeTiers>, // 1 + 4 + 8
+    /// Maximum order fee increase that takes effect once its timelock has elapsed
+    pub pending_max_order_fee: Option<PendingMaxOrderFee>, // 1 + 10
 }
 
 impl Config {
-    pub const MIN_SPACE: usize = Config::DISCRIMINATOR.len() + 32 + 2 + 32 + 1 + 1 + 4 + 64;
-
-    pub fn space(accepted_mints: &Vec<AcceptedMint>) -> usize {
+    /// Fee tiers and staged fee tiers are allocated at their maximum length, so a staged change
+    /// always fits
+    pub fn space(accepted_mints: &[AcceptedMint]) -> usize {
         Config::DISCRIMINATOR.len()
             + 2
             + 32
@@ -32,31 +63,249 @@ impl Config {
             + 1
             + 4
             + (AcceptedMint::INIT_SPACE * accepted_mints.len())
-            + 64
+            + 4
+            + 4
+            + 4
+            + 4
+            + (FeeTier::INIT_SPACE * MAX_FEE_TIERS as usize)
+            + 2
+            + 1
+            + PendingOrderFee::INIT_SPACE
+            + 1
+            + 32
+            + 32
+            + 32
+            + 32
+            + 1
+            + 4
+            + (FeeTier::INIT_SPACE * MAX_FEE_TIERS as usize)
+            + 8
+            + 1
+            + PendingMaxOrderFee::INIT_SPACE
     }
 
-    pub fn validate_mint(&self, mint: Pubkey) -> Result<()> {
-        require!(
-            self.accepted_mints.iter().any(|m| m.mint == mint),
-            SplurgeError::PaymentMintNotAccepted
-        );
+    pub fn is_paused(&self, operation: u8) -> bool {
+        self.paused_operations & operation != 0
+    }
 
-        Ok(())
+    pub fn get_accepted_mint(&self, mint: Pubkey) -> Result<&AcceptedMint> {
+        self.accepted_mints
+            .iter()
+            .find(|m| m.mint == mint)
+            .ok_or(SplurgeError::PaymentMintNotAccepted.into())
     }
 
-    pub fn validate_price_update_v2(&self, price_update_v2: Pubkey) -> Result<()> {
-        require!(
-            self.accepted_mints
-                .iter()
-                .any(|m| m.price_update_v2 == price_update_v2),
-            SplurgeError::InvalidPriceUpdateV2
-        );
+    pub fn get_active_accepted_mint(&self, mint: Pubkey) -> Result<&AcceptedMint> {
+        let accepted_mint = self.get_accepted_mint(mint)?;
+
+        require!(!accepted_mint.is_paused, SplurgeError::PaymentMintPaused);
+
+        Ok(accepted_mint)
+    }
+
+    pub fn validate_shopper_cancellation(&self, order_timestamp: i64, now: i64) -> Result<()> {
+        if self.shopper_cancel_window_secs > 0 {
+            require_gte!(
+                order_timestamp + self.shopper_cancel_window_secs as i64,
+                now,
+                SplurgeError::CancellationWindowElapsed
+            );
+        }
 
         Ok(())
     }
 
+    pub fn get_completion_delay_secs(&self, store: &Store) -> u32 {
+        store
+            .completion_delay_secs
+            .unwrap_or(self.completion_delay_secs)
+    }
+
+    pub fn get_base_order_fee_bps(&self, now: i64) -> u16 {
+        match self.pending_order_fee {
+            Some(pending_order_fee) if now >= pending_order_fee.effective_timestamp => {
+                pending_order_fee.order_fee_bps
+            }
+            _ => self.order_fee_bps,
+        }
+    }
+
+    pub fn get_max_order_fee_bps(&self, now: i64) -> u16 {
+        match self.pending_max_order_fee {
+            Some(pending_max_order_fee) if now >= pending_max_order_fee.effective_timestamp => {
+                pending_max_order_fee.max_order_fee_bps
+            }
+            _ => self.max_order_fee_bps,
+        }
+    }
+
+    pub fn get_fee_tiers(&self, now: i64) -> &[FeeTier] {
+        match &self.pending_fee_tiers {
+            Some(pending_fee_tiers) if now >= pending_fee_tiers.effective_timestamp => {
+                &pending_fee_tiers.fee_tiers
+            }
+            _ => &self.fee_tiers,
+        }
+    }
+
+    /// Fee charged on stores without an override, by completed volume
+    pub fn get_tier_order_fee_bps(&self, completed_volume: u64, now: i64) -> u16 {
+        get_tier_order_fee_bps(
+            self.get_fee_tiers(now),
+            completed_volume,
+            self.get_base_order_fee_bps(now),
+        )
+    }
+
+    pub fn get_order_fee_bps(&self, store: &Store, now: i64) -> u16 {
+        store
+            .get_order_fee_override(now)
+            .unwrap_or_else(|| self.get_tier_order_fee_bps(store.completed_volume, now))
+            .min(self.get_max_order_fee_bps(now))
+    }
+
+    /// Applies fee decreases immediately and stages increases behind the timelock
+    pub fn set_order_fee_bps(&mut self, order_fee_bps: u16, now: i64) -> Option<PendingOrderFee> {
+        self.order_fee_bps = self.get_base_order_fee_bps(now);
+        self.pending_order_fee = None;
+
+        if order_fee_bps <= self.order_fee_bps {
+            self.order_fee_bps = order_fee_bps;
+        } else {
+            self.pending_order_fee = Some(PendingOrderFee {
+                order_fee_bps,
+                effective_timestamp: now + ORDER_FEE_TIMELOCK_SECS as i64,
+            });
+        }
+
+        self.pending_order_fee
+    }
+
+    /// Applies maximum order fee decreases immediately and stages increases behind the timelock
+    pub fn set_max_order_fee_bps(
+        &mut self,
+        max_order_fee_bps: u16,
+        now: i64,
+    ) -> Option<PendingMaxOrderFee> {
+        self.max_order_fee_bps = self.get_max_order_fee_bps(now);
+        self.pending_max_order_fee = None;
+
+        if max_order_fee_bps <= self.max_order_fee_bps {
+            self.max_order_fee_bps = max_order_fee_bps;
+        } else {
+            self.pending_max_order_fee = Some(PendingMaxOrderFee {
+                max_order_fee_bps,
+                effective_timestamp: now + ORDER_FEE_TIMELOCK_SECS as i64,
+            });
+        }
+
+        self.pending_max_order_fee
+    }
+
+    /// Applies fee tiers that lower or keep the fee at every volume immediately and stages any
+    /// other change behind the timelock. Returns the effective timestamp if staged
+    pub fn set_fee_tiers(&mut self, fee_tiers: Vec<FeeTier>, now: i64) -> Option<i64> {
+        self.fee_tiers = self.get_fee_tiers(now).to_vec();
+        self.pending_fee_tiers = None;
+
+        let base_order_fee_bps = self.get_base_order_fee_bps(now);
+        let is_increase = self
+            .fee_tiers
+            .iter()
+            .chain(fee_tiers.iter())
+            .map(|fee_tier| fee_tier.min_volume)
+            .chain([0])
+            .any(|volume| {
+                get_tier_order_fee_bps(&fee_tiers, volume, base_order_fee_bps)
+                    > get_tier_order_fee_bps(&self.fee_tiers, volume, base_order_fee_bps)
+            });
+
+        if !is_increase {
+            self.fee_tiers = fee_tiers;
+
+            return None;
+        }
+
+        let effective_timestamp = now + ORDER_FEE_TIMELOCK_SECS as i64;
+
+        self.pending_fee_tiers = Some(PendingFeeTiers {
+            fee_tiers,
+            effective_timestamp,
+        });
+
+        Some(effective_timestamp)
+    }
+
+    /// Applies store fee override changes that lower or keep the store's fee immediately and
+    /// stages increases behind the timelock
+    pub fn set_store_order_fee_bps(
+        &self,
+        store: &mut Store,
+        order_fee_bps: Option<u16>,
+        now: i64,
+    ) -> Option<PendingStoreOrderFee> {
+        let current_order_fee_bps = self.get_order_fee_bps(store, now);
+
+        store.order_fee_bps = store.get_order_fee_override(now);
+
+        let pending_order_fee = store
+            .pending_order_fee
+            .take()
+            .filter(|pending_order_fee| now < pending_order_fee.effective_timestamp);
+
+        // Restating a staged override keeps its original timelock
+        if let Some(pending_order_fee) =
+            pending_order_fee.filter(|pending| pending.order_fee_bps == order_fee_bps)
+        {
+            store.pending_order_fee = Some(pending_order_fee);
+
+            return None;
+        }
+
+        let new_order_fee_bps = order_fee_bps
+            .unwrap_or_else(|| self.get_tier_order_fee_bps(store.completed_volume, now))
+            .min(self.get_max_order_fee_bps(now));
+
+        if new_order_fee_bps <= current_order_fee_bps {
+            store.order_fee_bps = order_fee_bps;
+        } else {
+            store.pending_order_fee = Some(PendingStoreOrderFee {
+                order_fee_bps,
+                effective_timestamp: now + ORDER_FEE_TIMELOCK_SECS as i64,
+            });
+        }
+
+        store.pending_order_fee
+    }
+
     pub fn invariant(&self) -> Result<()> {
         require_keys_neq!(self.admin, Pubkey::default(), SplurgeError::InvalidAddress);
+        require_keys_neq!(
+            self.operator,
+            Pubkey::default(),
+            SplurgeError::InvalidAddress
+        );
+        require_keys_neq!(
+            self.treasurer,
+            Pubkey::default(),
+            SplurgeError::InvalidAddress
+        );
+        require_keys_neq!(self.pauser, Pubkey::default(), SplurgeError::InvalidAddress);
+
+        if let Some(pending_admin) = self.pending_admin {
+            require_keys_neq!(
+                pending_admin,
+                Pubkey::default(),
+                SplurgeError::InvalidAddress
+            );
+        }
+
+        require!(
+            self.paused_operations & !PAUSE_ALL == 0,
+            SplurgeError::InvalidPauseFlags
+        );
+
+        validate_completion_delay_secs(self.completion_delay_secs)?;
 
         require!(
             !self.accepted_mints.is_empty(),
@@ -69,21 +318,141 @@ impl Config {
                 Pubkey::default(),
                 SplurgeError::InvalidAddress
             );
-            require_keys_neq!(
-                accepted_mint.price_update_v2,
-                Pubkey::default(),
-                SplurgeError::InvalidAddress
+            require!(
+                accepted_mint.feed_id != [0; 32]
+                    && accepted_mint.max_staleness_secs > 0
+                    && accepted_mint.max_conf_bps <= MAX_FEE_BASIS_POINTS
+                    && accepted_mint.min_price <= accepted_mint.max_price,
+                SplurgeError::InvalidOracleConfig
             );
         }
 
+        // Fees are checked against the staged maximum, as they are clamped to the maximum in
+        // effect when charged
+        let max_order_fee_bps = self.get_staged_max_order_fee_bps();
+
+        require!(
+            max_order_fee_bps <= MAX_FEE_BASIS_POINTS
+                && self.order_fee_bps <= max_order_fee_bps
+                && self
+                    .pending_order_fee
+                    .iter()
+                    .all(|pending| pending.order_fee_bps <= max_order_fee_bps),
+            SplurgeError::InvalidFeeBps
+        );
+
+        self.validate_fee_tiers(&self.fee_tiers)?;
+
+        if let Some(pending_fee_tiers) = &self.pending_fee_tiers {
+            self.validate_fee_tiers(&pending_fee_tiers.fee_tiers)?;
+        }
+
         Ok(())
     }
+
+    fn get_staged_max_order_fee_bps(&self) -> u16 {
+        self.pending_max_order_fee
+            .map_or(self.max_order_fee_bps, |pending| pending.max_order_fee_bps)
+    }
+
+    fn validate_fee_tiers(&self, fee_tiers: &[FeeTier]) -> Result<()> {
+        require!(
+            fee_tiers.len() <= MAX_FEE_TIERS as usize
+                && fee_tiers
+                    .windows(2)
+                    .all(|fee_tiers| fee_tiers[0].min_volume < fee_tiers[1].min_volume)
+                && fee_tiers
+                    .iter()
+                    .all(|fee_tier| fee_tier.order_fee_bps <= self.get_staged_max_order_fee_bps()),
+            SplurgeError::InvalidFeeTiers
+        );
+
+        Ok(())
+    }
+}
+
+fn get_tier_order_fee_bps(fee_tiers: &[FeeTier], completed_volume: u64, base: u16) -> u16 {
+    fee_tiers
+        .iter()
+        .rev()
+        .find(|fee_tier| completed_volume >= fee_tier.min_volume)
+        .map_or(base, |fee_tier| fee_tier.order_fee_bps)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy)]
+pub struct PendingOrderFee {
+    /// Fee charged on each order in basis points once effective
+    pub order_fee_bps: u16,
+    /// Unix time the fee takes effect
+    pub effective_timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy)]
+pub struct PendingMaxOrderFee {
+    /// Upper bound on any order fee in basis points once effective
+    pub max_order_fee_bps: u16,
+    /// Unix time the maximum takes effect
+    pub effective_timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PendingFeeTiers {
+    /// Fee tiers once effective, ascending by volume
+    pub fee_tiers: Vec<FeeTier>,
+    /// Unix time the fee tiers take effect
+    pub effective_timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
+pub struct FeeTier {
+    /// Minimum store completed volume in atomic units with 6 decimals
+    pub min_volume: u64,
+    /// Fee charged on each order in basis points
+    pub order_fee_bps: u16,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
 pub struct AcceptedMint {
     /// Mint address of the stablecoin
     pub mint: Pubkey,
-    /// Pyth price feed account address of the stablecoin
-    pub price_update_v2: Pubkey,
+    /// Pyth price feed ID of the stablecoin
+    pub feed_id: [u8; 32],
+    /// Minimum verification level of accepted price updates
+    pub verification_level: PriceVerificationLevel,
+    /// Maximum age of the oracle price in seconds
+    pub max_staleness_secs: u32,
+    /// Maximum oracle confidence interval in basis points of price
+    pub max_conf_bps: u16,
+    /// Minimum accepted oracle price in atomic units with 6 decimals
+    pub min_price: u64,
+    /// Maximum accepted oracle price in atomic units with 6 decimals
+    pub max_price: u64,
+    /// Boolean indicating if the mint is temporarily not accepted for new orders
+    pub is_paused: bool,
+}
+
+pub fn validate_completion_delay_secs(completion_delay_secs: u32) -> Result<()> {
+    require!(
+        (MIN_COMPLETION_DELAY_SECS..=MAX_COMPLETION_DELAY_SECS).contains(&completion_delay_secs),
+        SplurgeError::InvalidCompletionDelay
+    );
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq)]
+pub enum PriceVerificationLevel {
+    Partial { num_signatures: u8 },
+    Full,
+}
+
+impl From<PriceVerificationLevel> for VerificationLevel {
+    fn from(level: PriceVerificationLevel) -> Self {
+        match level {
+            PriceVerificationLevel::Partial { num_signatures } => {
+                VerificationLevel::Partial { num_signatures }
+            }
+            PriceVerificationLevel::Full => VerificationLevel::Full,
+        }
+    }
 }
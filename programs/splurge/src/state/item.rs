@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::MAX_FEE_BASIS_POINTS;
 
 use crate::error::SplurgeError;
 
@@ -6,8 +7,12 @@ use crate::error::SplurgeError;
 pub struct Item {
     /// PDA of store account
     pub store: Pubkey, // 32
-    /// Price in atomic units of mint with 6 decimals
+    /// Price in USD with 6 decimals, or in atomic units of the price mint if set
     pub price: u64, // 8
+    /// Mint the price is denominated in, charged exactly without oracle conversion
+    pub price_mint: Option<Pubkey>, // 1 + 32
+    /// Share of the payment subtotal paid to the order referrer in basis points
+    pub referral_bps: u16, // 2
     /// Remaining inventory count
     pub inventory_count: u32, // 4
     /// Bump used for seed derivation
@@ -25,6 +30,9 @@ impl Item {
         Item::DISCRIMINATOR.len()
             + 32
             + 8
+            + 1
+            + 32
+            + 2
             + 4
             + 1
             + 4
@@ -38,6 +46,33 @@ impl Item {
     pub fn invariant(&self) -> Result<()> {
         require_keys_neq!(self.store, Pubkey::default(), SplurgeError::InvalidAddress);
 
+        if let Some(price_mint) = self.price_mint {
+            require_keys_neq!(price_mint, Pubkey::default(), SplurgeError::InvalidAddress);
+        }
+
+        require_gte!(
+            MAX_FEE_BASIS_POINTS,
+            self.referral_bps,
+            SplurgeError::InvalidReferralBps
+        );
+
         Ok(())
     }
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub enum PricingMode {
+    /// Price is in USD and converted with the payment mint's oracle
+    Oracle,
+    /// Price is in atomic units of `price_mint`, charged exactly and only payable in that mint
+    Fixed { price_mint: Pubkey },
+}
+
+impl From<PricingMode> for Option<Pubkey> {
+    fn from(pricing_mode: PricingMode) -> Self {
+        match pricing_mode {
+            PricingMode::Oracle => None,
+            PricingMode::Fixed { price_mint } => Some(price_mint),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod cart_order;
 pub mod config;
 pub mod item;
 pub mod order;
@@ -5,6 +6,7 @@ pub mod review;
 pub mod shopper;
 pub mod store;
 
+pub use cart_order::*;
 pub use config::*;
 pub use item::*;
 pub use order::*;
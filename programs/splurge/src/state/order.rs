@@ -1,7 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
 use num_derive::*;
 
-use crate::error::SplurgeError;
+use super::PayoutRecipient;
+use crate::{
+    constants::{MAX_PAYOUT_RECIPIENTS, REVIEW_WINDOW_SECS},
+    error::SplurgeError,
+};
 
 #[account]
 #[derive(InitSpace)]
@@ -16,14 +21,33 @@ pub struct Order {
     pub status: OrderStatus, // 1
     /// Amount of item purchased
     pub amount: u32, // 4
+    /// Amount of item shipped to the shopper
+    pub shipped_amount: u32, // 4
+    /// Amount of item refunded to the shopper
+    pub refunded_amount: u32, // 4
     /// Payment subtotal in atomic units of mint
     pub payment_subtotal: u64, // 8
     /// Platform fee in atomic units of mint
     pub platform_fee: u64, // 8
+    /// Fee rate charged on the order in basis points
+    pub order_fee_bps: u16, // 2
     /// Address of stablecoin mint used for payment
     pub payment_mint: Pubkey, // 32
     /// Bump used for seed derivation
     pub bump: u8, // 1
+    /// Tuktuk task queued to complete the order, default until shipped
+    pub task: Pubkey, // 32
+    /// Unix time after which a pending order can be expired, 0 for no deadline
+    pub shipping_deadline: i64, // 8
+    /// Address credited with referring the order
+    pub referrer: Option<Pubkey>, // 1 + 32
+    /// Referral commission paid from the payment subtotal on completion
+    pub referral_fee: u64, // 8
+    /// Unix time the order was completed or resolved, 0 until then
+    pub finalized_at: i64, // 8
+    /// Store payout recipients at the time the order was shipped
+    #[max_len(MAX_PAYOUT_RECIPIENTS)]
+    pub payout_recipients: Vec<PayoutRecipient>, // 4 + 34 * 4
 }
 
 #[derive(
@@ -44,9 +68,49 @@ pub enum OrderStatus {
     Shipping,
     Cancelled,
     Completed,
+    Disputed,
+    Resolved,
 }
 
 impl Order {
+    /// Referrer of the order, if it is owed a referral fee on completion
+    pub fn get_owed_referrer(&self) -> Option<Pubkey> {
+        self.referrer.filter(|_| self.referral_fee > 0)
+    }
+
+    /// Associated token account of the referrer, if it is owed a referral fee on completion
+    pub fn get_referrer_token_account(
+        &self,
+        payment_mint: &Pubkey,
+        token_program: &Pubkey,
+    ) -> Option<Pubkey> {
+        self.get_owed_referrer().map(|referrer| {
+            get_associated_token_address_with_program_id(&referrer, payment_mint, token_program)
+        })
+    }
+
+    pub fn validate_closable(&self, now: i64) -> Result<()> {
+        // The queued expiry task runs against the order at the shipping deadline
+        require!(
+            self.shipping_deadline == 0 || now > self.shipping_deadline,
+            SplurgeError::ExpiryTaskPending
+        );
+
+        match self.status {
+            OrderStatus::Cancelled => {}
+            OrderStatus::Completed | OrderStatus::Resolved => {
+                require_gt!(
+                    now,
+                    self.finalized_at + REVIEW_WINDOW_SECS as i64,
+                    SplurgeError::ReviewWindowOpen
+                );
+            }
+            _ => return err!(SplurgeError::OrderNotFinalized),
+        }
+
+        Ok(())
+    }
+
     pub fn invariant(&self) -> Result<()> {
         require_keys_neq!(
             self.shopper,
@@ -64,6 +128,18 @@ impl Order {
             self.timestamp,
             SplurgeError::InvalidTimestamp
         );
+        require_gte!(
+            self.amount,
+            self.shipped_amount
+                .checked_add(self.refunded_amount)
+                .ok_or(SplurgeError::MathOverflow)?,
+            SplurgeError::InvalidFulfillmentAmount
+        );
+        require_gte!(
+            self.payment_subtotal,
+            self.referral_fee,
+            SplurgeError::InvalidReferralFee
+        );
 
         Ok(())
     }
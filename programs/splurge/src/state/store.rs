@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::MAX_FEE_BASIS_POINTS;
 
-use crate::error::SplurgeError;
+use super::validate_completion_delay_secs;
+use crate::{
+    constants::{MAX_PAYOUT_RECIPIENTS, PRICE_DECIMALS},
+    error::SplurgeError,
+};
 
 #[account]
 pub struct Store {
@@ -14,11 +19,67 @@ pub struct Store {
     pub image: String, // 4
     /// Store description
     pub about: String, // 4
+    /// Overrides the config escrow hold period for this store if set
+    pub completion_delay_secs: Option<u32>, // 1 + 4
+    /// Overrides the config order fee for this store if set
+    pub order_fee_bps: Option<u16>, // 1 + 2
+    /// Lifetime completed order volume in atomic units with 6 decimals
+    pub completed_volume: u64, // 8
+    /// Boolean indicating if the store is suspended by the admin
+    pub is_suspended: bool, // 1
+    /// Recipients of completed order payouts by share, paid to the store if empty
+    pub payout_recipients: Vec<PayoutRecipient>, // 4
+    /// Order fee override change that takes effect once its timelock has elapsed
+    pub pending_order_fee: Option<PendingStoreOrderFee>, // 1 + 11
 }
 
 impl Store {
-    pub fn space(name: &str, image: &str, about: &str) -> usize {
-        Store::DISCRIMINATOR.len() + 32 + 1 + 4 + name.len() + 4 + image.len() + 4 + about.len()
+    pub fn space(
+        name: &str,
+        image: &str,
+        about: &str,
+        payout_recipients: &[PayoutRecipient],
+    ) -> usize {
+        Store::DISCRIMINATOR.len()
+            + 32
+            + 1
+            + 4
+            + name.len()
+            + 4
+            + image.len()
+            + 4
+            + about.len()
+            + 1
+            + 4
+            + 1
+            + 2
+            + 8
+            + 1
+            + 4
+            + (PayoutRecipient::INIT_SPACE * payout_recipients.len())
+            + 1
+            + PendingStoreOrderFee::INIT_SPACE
+    }
+
+    pub fn get_order_fee_override(&self, now: i64) -> Option<u16> {
+        match self.pending_order_fee {
+            Some(pending_order_fee) if now >= pending_order_fee.effective_timestamp => {
+                pending_order_fee.order_fee_bps
+            }
+            _ => self.order_fee_bps,
+        }
+    }
+
+    pub fn record_completed_volume(&mut self, payment_subtotal: u64, decimals: u8) -> Result<()> {
+        let volume = (payment_subtotal as u128)
+            .checked_mul(10_u128.pow(PRICE_DECIMALS.into()))
+            .and_then(|volume| volume.checked_div(10_u128.checked_pow(decimals.into())?))
+            .and_then(|volume| u64::try_from(volume).ok())
+            .ok_or(SplurgeError::MathOverflow)?;
+
+        self.completed_volume = self.completed_volume.saturating_add(volume);
+
+        Ok(())
     }
 
     pub fn invariant(&self) -> Result<()> {
@@ -28,6 +89,65 @@ impl Store {
             SplurgeError::InvalidAddress
         );
 
+        if let Some(completion_delay_secs) = self.completion_delay_secs {
+            validate_completion_delay_secs(completion_delay_secs)?;
+        }
+
+        if let Some(order_fee_bps) = self.order_fee_bps {
+            require_gte!(
+                MAX_FEE_BASIS_POINTS,
+                order_fee_bps,
+                SplurgeError::InvalidFeeBps
+            );
+        }
+
+        if let Some(order_fee_bps) = self
+            .pending_order_fee
+            .and_then(|pending_order_fee| pending_order_fee.order_fee_bps)
+        {
+            require_gte!(
+                MAX_FEE_BASIS_POINTS,
+                order_fee_bps,
+                SplurgeError::InvalidFeeBps
+            );
+        }
+
+        if !self.payout_recipients.is_empty() {
+            require!(
+                self.payout_recipients.len() <= MAX_PAYOUT_RECIPIENTS as usize
+                    && self.payout_recipients.iter().enumerate().all(|(i, r)| {
+                        r.recipient != Pubkey::default()
+                            && r.share_bps > 0
+                            && self.payout_recipients[..i]
+                                .iter()
+                                .all(|other| other.recipient != r.recipient)
+                    })
+                    && self
+                        .payout_recipients
+                        .iter()
+                        .map(|r| r.share_bps as u32)
+                        .sum::<u32>()
+                        == MAX_FEE_BASIS_POINTS as u32,
+                SplurgeError::InvalidPayoutRecipients
+            );
+        }
+
         Ok(())
     }
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
+pub struct PayoutRecipient {
+    /// Wallet address paid through its associated token account
+    pub recipient: Pubkey,
+    /// Share of each payout in basis points
+    pub share_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy)]
+pub struct PendingStoreOrderFee {
+    /// Order fee override in basis points once effective, `None` to clear the override
+    pub order_fee_bps: Option<u16>,
+    /// Unix time the override takes effect
+    pub effective_timestamp: i64,
+}
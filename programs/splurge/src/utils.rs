@@ -0,0 +1,805 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, pubkey::PUBKEY_BYTES},
+    InstructionData,
+};
+use anchor_spl::{
+    associated_token::{create_idempotent, get_associated_token_address_with_program_id, Create},
+    token_2022::spl_token_2022::extension::transfer_fee::MAX_FEE_BASIS_POINTS,
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+use pyth_solana_receiver_sdk::price_update::{Price, PriceUpdateV2};
+use spl_math::precise_number::PreciseNumber;
+use tuktuk_program::{
+    compile_transaction,
+    tuktuk::cpi::{accounts::QueueTaskV0, queue_task_v0},
+    types::QueueTaskArgsV0,
+    TransactionSourceV0, TriggerV0,
+};
+
+use crate::{
+    config_signer,
+    constants::{CONFIG_SEED, ORDER_SEED, PRICE_DECIMALS},
+    error::SplurgeError,
+    events::{OrderCompleted, ReferralPaid},
+    imprecise_number, order_signer, precise_number,
+    state::{AcceptedMint, Item, Order, OrderStatus, PayoutRecipient, Store},
+};
+
+/// Reads the oracle price from a price update, rejecting prices outside the accepted mint's bounds.
+/// Staleness is measured against `now`, the cluster clock time
+pub fn get_oracle_price(
+    price_update_v2: &PriceUpdateV2,
+    accepted_mint: &AcceptedMint,
+    #[cfg_attr(feature = "no-staleness-check", allow(unused_variables))] now: i64,
+) -> Result<Price> {
+    require!(
+        price_update_v2
+            .verification_level
+            .gte(accepted_mint.verification_level.into()),
+        SplurgeError::InsufficientVerificationLevel
+    );
+
+    let oracle_price = price_update_v2
+        .get_price_unchecked(&accepted_mint.feed_id)
+        .map_err(|_| SplurgeError::InvalidPriceFeedId)?;
+    let Price {
+        exponent,
+        price,
+        conf,
+        #[cfg(not(feature = "no-staleness-check"))]
+        publish_time,
+        ..
+    } = oracle_price;
+
+    #[cfg(not(feature = "no-staleness-check"))]
+    require!(
+        now - publish_time <= accepted_mint.max_staleness_secs as i64,
+        SplurgeError::StalePrice
+    );
+
+    require!(price > 0, SplurgeError::InvalidPrice);
+
+    require!(
+        (conf as u128) * (MAX_FEE_BASIS_POINTS as u128)
+            <= (price as u128) * (accepted_mint.max_conf_bps as u128),
+        SplurgeError::PriceConfidenceTooWide
+    );
+
+    let ops = if exponent > 0 {
+        PreciseNumber::checked_mul
+    } else {
+        PreciseNumber::checked_div
+    };
+
+    let scaled_price = ops(
+        &precise_number!(price as u128),
+        &precise_number!(10_u64.pow(exponent.abs() as u32) as u128),
+    )
+    .ok_or(SplurgeError::MathOverflow)?
+    .checked_mul(&precise_number!(10_u128.pow(PRICE_DECIMALS as u32)))
+    .ok_or(SplurgeError::MathOverflow)?;
+
+    require!(
+        !scaled_price.less_than(&precise_number!(accepted_mint.min_price.into())),
+        SplurgeError::PriceBelowMinimum
+    );
+    require!(
+        !scaled_price.greater_than(&precise_number!(accepted_mint.max_price.into())),
+        SplurgeError::PriceAboveMaximum
+    );
+
+    Ok(oracle_price)
+}
+
+/// Converts a USD value with 6 decimals into atomic units of a mint worth `price * 10^exponent`
+/// USD per whole token, rounded up. Returns `None` on a zero price or overflow.
+pub fn convert_usd_to_mint_amount(
+    usd_value: u64,
+    price: u64,
+    exponent: i32,
+    mint_decimals: u8,
+) -> Option<u64> {
+    if price == 0 {
+        return None;
+    }
+
+    let mut numerator =
+        (usd_value as u128).checked_mul(10_u128.checked_pow(mint_decimals.into())?)?;
+    let mut denominator =
+        (price as u128).checked_mul(10_u128.checked_pow(PRICE_DECIMALS.into())?)?;
+
+    let exponent_scale = 10_u128.checked_pow(exponent.unsigned_abs())?;
+    if exponent < 0 {
+        numerator = numerator.checked_mul(exponent_scale)?;
+    } else {
+        denominator = denominator.checked_mul(exponent_scale)?;
+    }
+
+    numerator.div_ceil(denominator).try_into().ok()
+}
+
+/// Converts the price of `amount` items into atomic units of the payment mint, rounded up
+pub fn get_payment_subtotal(
+    amount: u32,
+    item_price: u64,
+    oracle_price: &Price,
+    mint_decimals: u8,
+) -> Result<u64> {
+    let usd_value = (amount as u64)
+        .checked_mul(item_price)
+        .ok_or(SplurgeError::MathOverflow)?;
+    let price = oracle_price
+        .price
+        .try_into()
+        .map_err(|_| SplurgeError::InvalidPrice)?;
+
+    let payment_subtotal =
+        convert_usd_to_mint_amount(usd_value, price, oracle_price.exponent, mint_decimals)
+            .ok_or(SplurgeError::MathOverflow)?;
+
+    Ok(payment_subtotal)
+}
+
+/// Prices `amount` items in the payment mint, charging fixed-price items without oracle conversion.
+/// Fixed-price items can only be paid in their price mint
+pub fn get_item_payment_subtotal(
+    amount: u32,
+    item: &Item,
+    payment_mint: &InterfaceAccount<Mint>,
+    oracle_price: Option<&Price>,
+) -> Result<u64> {
+    if let Some(price_mint) = item.price_mint {
+        require_keys_eq!(
+            price_mint,
+            payment_mint.key(),
+            SplurgeError::FixedPriceMintMismatch
+        );
+
+        return Ok((amount as u64)
+            .checked_mul(item.price)
+            .ok_or(SplurgeError::MathOverflow)?);
+    }
+
+    get_payment_subtotal(
+        amount,
+        item.price,
+        oracle_price.ok_or(SplurgeError::MissingPriceUpdate)?,
+        payment_mint.decimals,
+    )
+}
+
+/// Calculates the platform fee on a payment subtotal, rounded up
+pub fn get_platform_fee(payment_subtotal: u64, order_fee_bps: u16) -> Result<u64> {
+    Ok(imprecise_number!(precise_number!(payment_subtotal.into())
+        .checked_mul(&precise_number!(order_fee_bps.into()))
+        .ok_or(SplurgeError::MathOverflow)?
+        .checked_div(&precise_number!(MAX_FEE_BASIS_POINTS.into()))
+        .ok_or(SplurgeError::MathOverflow)?
+        .ceiling()
+        .ok_or(SplurgeError::MathOverflow)?) as u64)
+}
+
+/// Calculates a basis point share of an amount, rounded down
+pub fn get_bps_share(amount: u64, bps: u16) -> Result<u64> {
+    Ok(imprecise_number!(precise_number!(amount.into())
+        .checked_mul(&precise_number!(bps.into()))
+        .ok_or(SplurgeError::MathOverflow)?
+        .checked_div(&precise_number!(MAX_FEE_BASIS_POINTS.into()))
+        .ok_or(SplurgeError::MathOverflow)?
+        .floor()
+        .ok_or(SplurgeError::MathOverflow)?) as u64)
+}
+
+/// Calculates the share of `total` owed for `part` out of `whole` units, rounded down
+pub fn get_pro_rata_share(total: u64, part: u32, whole: u32) -> Result<u64> {
+    Ok(imprecise_number!(precise_number!(total.into())
+        .checked_mul(&precise_number!(part.into()))
+        .ok_or(SplurgeError::MathOverflow)?
+        .checked_div(&precise_number!(whole.into()))
+        .ok_or(SplurgeError::MathOverflow)?
+        .floor()
+        .ok_or(SplurgeError::MathOverflow)?) as u64)
+}
+
+/// Accounts used to create missing associated token accounts
+pub struct CreateTokenAccounts<'info> {
+    pub payer: AccountInfo<'info>,
+    pub payment_mint: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+}
+
+/// Creates the associated token account of `authority` if it does not exist
+pub fn create_token_account_idempotent<'info>(
+    accounts: &CreateTokenAccounts<'info>,
+    associated_token: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+) -> Result<()> {
+    create_idempotent(CpiContext::new(
+        accounts.associated_token_program.clone(),
+        Create {
+            payer: accounts.payer.clone(),
+            associated_token,
+            authority,
+            mint: accounts.payment_mint.clone(),
+            system_program: accounts.system_program.clone(),
+            token_program: accounts.token_program.clone(),
+        },
+    ))
+}
+
+/// Payout recipient wallets and their associated token accounts in pairs, in the same order as
+/// `payout_recipients`
+pub fn get_payout_account_metas(
+    payout_recipients: &[PayoutRecipient],
+    payment_mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Vec<AccountMeta> {
+    payout_recipients
+        .iter()
+        .flat_map(|payout_recipient| {
+            [
+                AccountMeta::new_readonly(payout_recipient.recipient, false),
+                AccountMeta::new(
+                    get_associated_token_address_with_program_id(
+                        &payout_recipient.recipient,
+                        payment_mint,
+                        token_program,
+                    ),
+                    false,
+                ),
+            ]
+        })
+        .collect()
+}
+
+/// Validates payout recipient wallet and token account pairs against `payout_recipients`, creating
+/// missing token accounts, and returns the token accounts
+pub fn init_payout_token_accounts<'info>(
+    payout_recipients: &[PayoutRecipient],
+    payout_accounts: &[AccountInfo<'info>],
+    accounts: &CreateTokenAccounts<'info>,
+) -> Result<Vec<AccountInfo<'info>>> {
+    require_eq!(
+        payout_accounts.len(),
+        payout_recipients.len() * 2,
+        SplurgeError::InvalidPayoutTokenAccounts
+    );
+
+    let mut payout_token_accounts = Vec::with_capacity(payout_recipients.len());
+
+    for (payout_recipient, pair) in payout_recipients.iter().zip(payout_accounts.chunks(2)) {
+        let [recipient, token_account] = pair else {
+            return err!(SplurgeError::InvalidPayoutTokenAccounts);
+        };
+
+        require!(
+            recipient.key() == payout_recipient.recipient
+                && token_account.key()
+                    == get_associated_token_address_with_program_id(
+                        &payout_recipient.recipient,
+                        accounts.payment_mint.key,
+                        accounts.token_program.key,
+                    )
+                && token_account.is_writable,
+            SplurgeError::InvalidPayoutTokenAccounts
+        );
+
+        create_token_account_idempotent(accounts, token_account.clone(), recipient.clone())?;
+
+        payout_token_accounts.push(token_account.clone());
+    }
+
+    Ok(payout_token_accounts)
+}
+
+/// Splits a store payout between the payout recipients snapshotted on the order, or pays the
+/// store token account if there are none. The last recipient receives any rounding remainder
+pub fn get_store_payouts<'info>(
+    payout_recipients: &[PayoutRecipient],
+    amount: u64,
+    store_token_account: AccountInfo<'info>,
+    payout_token_accounts: Vec<AccountInfo<'info>>,
+) -> Result<Vec<(AccountInfo<'info>, u64)>> {
+    if payout_recipients.is_empty() {
+        return Ok(vec![(store_token_account, amount)]);
+    }
+
+    let mut remaining = amount;
+    let mut payouts = Vec::with_capacity(payout_token_accounts.len());
+    let last = payout_token_accounts.len() - 1;
+
+    for (i, (token_account, payout_recipient)) in payout_token_accounts
+        .into_iter()
+        .zip(payout_recipients.iter())
+        .enumerate()
+    {
+        let share = if i == last {
+            remaining
+        } else {
+            get_bps_share(amount, payout_recipient.share_bps)?
+        };
+
+        remaining = remaining
+            .checked_sub(share)
+            .ok_or(SplurgeError::MathOverflow)?;
+        payouts.push((token_account, share));
+    }
+
+    Ok(payouts)
+}
+
+/// Returns the referrer token account and referral fee owed on completion, if a referral fee is due
+pub fn get_referral_payout<'info>(
+    expected_token_account: Option<Pubkey>,
+    referral_fee: u64,
+    referrer_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+) -> Result<Option<(AccountInfo<'info>, u64)>> {
+    let Some(expected_token_account) = expected_token_account.filter(|_| referral_fee > 0) else {
+        return Ok(None);
+    };
+
+    let referrer_token_account =
+        referrer_token_account.ok_or(SplurgeError::MissingReferrerTokenAccount)?;
+
+    require_keys_eq!(
+        referrer_token_account.key(),
+        expected_token_account,
+        SplurgeError::InvalidReferrerTokenAccount
+    );
+
+    Ok(Some((
+        referrer_token_account.to_account_info(),
+        referral_fee,
+    )))
+}
+
+/// Accounts used to release a shipped order's escrow on completion
+pub struct SettleOrderAccounts<'a, 'info> {
+    pub order: &'a mut Account<'info, Order>,
+    pub store: &'a mut Account<'info, Store>,
+    pub payment_mint: &'a InterfaceAccount<'info, Mint>,
+    pub order_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub store_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub referrer_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    /// Shopper authority, refunded the rent of the order token account
+    pub authority: AccountInfo<'info>,
+    /// Pays for payout recipient token accounts that do not exist yet
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub associated_token_program: AccountInfo<'info>,
+}
+
+/// Pays the referral fee and store payouts from the order escrow, closes the order token account
+/// and marks the order completed
+pub fn settle_order<'info>(
+    accounts: SettleOrderAccounts<'_, 'info>,
+    payout_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let SettleOrderAccounts {
+        order,
+        store,
+        payment_mint,
+        order_token_account,
+        store_token_account,
+        referrer_token_account,
+        authority,
+        payer,
+        system_program,
+        token_program,
+        associated_token_program,
+    } = accounts;
+
+    let order_timestamp = order.timestamp.to_le_bytes();
+
+    let signer_seeds: &[&[u8]] =
+        order_signer!(order.shopper, order.item, order_timestamp, order.bump);
+
+    let referral_payout = get_referral_payout(
+        order.get_referrer_token_account(&payment_mint.key(), &token_program.key()),
+        order.referral_fee,
+        referrer_token_account,
+    )?;
+    let referral_fee = referral_payout
+        .as_ref()
+        .map_or(0, |(_, referral_fee)| *referral_fee);
+    let payout_token_accounts = init_payout_token_accounts(
+        &order.payout_recipients,
+        payout_accounts,
+        &CreateTokenAccounts {
+            payer,
+            payment_mint: payment_mint.to_account_info(),
+            system_program,
+            token_program: token_program.to_account_info(),
+            associated_token_program,
+        },
+    )?;
+    let store_payouts = get_store_payouts(
+        &order.payout_recipients,
+        order_token_account
+            .amount
+            .checked_sub(referral_fee)
+            .ok_or(SplurgeError::MathOverflow)?,
+        store_token_account.to_account_info(),
+        payout_token_accounts,
+    )?;
+
+    for (token_account, amount) in referral_payout.into_iter().chain(store_payouts) {
+        transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                TransferChecked {
+                    authority: order.to_account_info(),
+                    mint: payment_mint.to_account_info(),
+                    from: order_token_account.to_account_info(),
+                    to: token_account,
+                },
+            )
+            .with_signer(&[signer_seeds]),
+            amount,
+            payment_mint.decimals,
+        )?;
+    }
+
+    close_account(
+        CpiContext::new(
+            token_program.to_account_info(),
+            CloseAccount {
+                authority: order.to_account_info(),
+                account: order_token_account.to_account_info(),
+                destination: authority,
+            },
+        )
+        .with_signer(&[signer_seeds]),
+    )?;
+
+    order.status = OrderStatus::Completed;
+    order.finalized_at = Clock::get()?.unix_timestamp;
+
+    if let Some(referrer) = order.referrer.filter(|_| referral_fee > 0) {
+        emit!(ReferralPaid {
+            order: order.key(),
+            referrer,
+            amount: referral_fee,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
+    store.record_completed_volume(order.payment_subtotal, payment_mint.decimals)?;
+
+    emit!(OrderCompleted {
+        order: order.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Queues a tuktuk task that runs `complete_order` at the trigger timestamp, passing the order's
+/// payout recipient wallets and token accounts as remaining accounts
+pub fn queue_complete_order_task<'info>(
+    tuktuk: AccountInfo<'info>,
+    queue_task_accounts: QueueTaskV0<'info>,
+    config_bump: u8,
+    complete_order_accounts: crate::accounts::CompleteOrder,
+    payout_accounts: Vec<AccountMeta>,
+    task_id: u16,
+    trigger_timestamp: i64,
+) -> Result<()> {
+    let description = get_task_description(
+        "Complete",
+        &complete_order_accounts.order,
+        trigger_timestamp,
+    );
+
+    queue_order_task(
+        tuktuk,
+        queue_task_accounts,
+        config_bump,
+        Instruction {
+            program_id: crate::ID,
+            accounts: complete_order_accounts
+                .to_account_metas(None)
+                .into_iter()
+                .chain(payout_accounts)
+                .collect(),
+            data: crate::instruction::CompleteOrder.data(),
+        },
+        task_id,
+        trigger_timestamp,
+        description,
+    )
+}
+
+/// Queues a tuktuk task that runs `complete_cart_order` at the trigger timestamp, passing each line
+/// item's store, store token account and payout recipient accounts as remaining accounts
+pub fn queue_complete_cart_order_task<'info>(
+    tuktuk: AccountInfo<'info>,
+    queue_task_accounts: QueueTaskV0<'info>,
+    config_bump: u8,
+    complete_cart_order_accounts: crate::accounts::CompleteCartOrder,
+    line_item_accounts: Vec<AccountMeta>,
+    task_id: u16,
+    trigger_timestamp: i64,
+) -> Result<()> {
+    let description = get_task_description(
+        "Complete",
+        &complete_cart_order_accounts.cart_order,
+        trigger_timestamp,
+    );
+
+    queue_order_task(
+        tuktuk,
+        queue_task_accounts,
+        config_bump,
+        Instruction {
+            program_id: crate::ID,
+            accounts: complete_cart_order_accounts
+                .to_account_metas(None)
+                .into_iter()
+                .chain(line_item_accounts)
+                .collect(),
+            data: crate::instruction::CompleteCartOrder.data(),
+        },
+        task_id,
+        trigger_timestamp,
+        description,
+    )
+}
+
+/// Queues a tuktuk task that runs `expire_order` at the trigger timestamp
+pub fn queue_expire_order_task<'info>(
+    tuktuk: AccountInfo<'info>,
+    queue_task_accounts: QueueTaskV0<'info>,
+    config_bump: u8,
+    expire_order_accounts: crate::accounts::ExpireOrder,
+    task_id: u16,
+    trigger_timestamp: i64,
+) -> Result<()> {
+    let description =
+        get_task_description("Expire", &expire_order_accounts.order, trigger_timestamp);
+
+    queue_order_task(
+        tuktuk,
+        queue_task_accounts,
+        config_bump,
+        Instruction {
+            program_id: crate::ID,
+            accounts: expire_order_accounts.to_account_metas(None),
+            data: crate::instruction::ExpireOrder.data(),
+        },
+        task_id,
+        trigger_timestamp,
+        description,
+    )
+}
+
+fn get_task_description(action: &str, order: &Pubkey, trigger_timestamp: i64) -> String {
+    let order_key_string = order.to_string();
+
+    format!(
+        "{} order {}...{} at {}",
+        action,
+        &order_key_string[..4],
+        &order_key_string[PUBKEY_BYTES - 4..PUBKEY_BYTES],
+        trigger_timestamp
+    )
+}
+
+fn queue_order_task<'info>(
+    tuktuk: AccountInfo<'info>,
+    queue_task_accounts: QueueTaskV0<'info>,
+    config_bump: u8,
+    instruction: Instruction,
+    task_id: u16,
+    trigger_timestamp: i64,
+    description: String,
+) -> Result<()> {
+    let (compiled_tx, _) = compile_transaction(vec![instruction], vec![]).unwrap();
+
+    let signer_seeds: &[&[u8]] = config_signer!(config_bump);
+
+    queue_task_v0(
+        CpiContext::new(tuktuk, queue_task_accounts).with_signer(&[signer_seeds]),
+        QueueTaskArgsV0 {
+            trigger: TriggerV0::Timestamp(trigger_timestamp),
+            transaction: TransactionSourceV0::CompiledV0(compiled_tx),
+            crank_reward: None,
+            free_tasks: 1,
+            id: task_id,
+            description,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Reference conversion in whole units, used to check exactness of rounding
+    fn exact_parts(usd_value: u64, price: u64, exponent: i32, mint_decimals: u8) -> (u128, u128) {
+        let mut numerator = usd_value as u128 * 10_u128.pow(mint_decimals.into());
+        let mut denominator = price as u128 * 10_u128.pow(PRICE_DECIMALS.into());
+        if exponent < 0 {
+            numerator *= 10_u128.pow(exponent.unsigned_abs());
+        } else {
+            denominator *= 10_u128.pow(exponent.unsigned_abs());
+        }
+        (numerator, denominator)
+    }
+
+    #[cfg(not(feature = "no-staleness-check"))]
+    fn price_update_v2(publish_time: i64) -> PriceUpdateV2 {
+        use pyth_solana_receiver_sdk::price_update::{PriceFeedMessage, VerificationLevel};
+
+        PriceUpdateV2 {
+            write_authority: Pubkey::default(),
+            verification_level: VerificationLevel::Full,
+            price_message: PriceFeedMessage {
+                feed_id: [1; 32],
+                price: 100_000_000,
+                conf: 10_000,
+                exponent: -8,
+                publish_time,
+                prev_publish_time: publish_time,
+                ema_price: 100_000_000,
+                ema_conf: 10_000,
+            },
+            posted_slot: 0,
+        }
+    }
+
+    #[cfg(not(feature = "no-staleness-check"))]
+    fn accepted_mint() -> AcceptedMint {
+        AcceptedMint {
+            mint: Pubkey::new_unique(),
+            feed_id: [1; 32],
+            verification_level: crate::state::PriceVerificationLevel::Full,
+            max_staleness_secs: 60,
+            max_conf_bps: 100,
+            min_price: 900_000,
+            max_price: 1_100_000,
+            is_paused: false,
+        }
+    }
+
+    #[cfg(not(feature = "no-staleness-check"))]
+    #[test]
+    fn measures_staleness_from_clock() {
+        let publish_time = 1_700_000_000;
+        let price_update_v2 = price_update_v2(publish_time);
+        let accepted_mint = accepted_mint();
+
+        assert!(get_oracle_price(&price_update_v2, &accepted_mint, publish_time + 60).is_ok());
+        assert_eq!(
+            get_oracle_price(&price_update_v2, &accepted_mint, publish_time + 61).unwrap_err(),
+            SplurgeError::StalePrice.into()
+        );
+    }
+
+    #[test]
+    fn converts_pegged_stablecoin_across_decimals() {
+        // $1.00 at exactly $1 per token (price 1e8, exponent -8)
+        assert_eq!(
+            convert_usd_to_mint_amount(1_000_000, 100_000_000, -8, 6),
+            Some(1_000_000)
+        );
+        assert_eq!(
+            convert_usd_to_mint_amount(1_000_000, 100_000_000, -8, 8),
+            Some(100_000_000)
+        );
+        assert_eq!(
+            convert_usd_to_mint_amount(1_000_000, 100_000_000, -8, 9),
+            Some(1_000_000_000)
+        );
+        assert_eq!(
+            convert_usd_to_mint_amount(1_000_000, 100_000_000, -8, 2),
+            Some(100)
+        );
+    }
+
+    #[test]
+    fn divides_by_oracle_price() {
+        // $1.00 at $0.99985 per token costs more than one token
+        assert_eq!(
+            convert_usd_to_mint_amount(1_000_000, 99_985_001, -8, 6),
+            Some(1_000_151)
+        );
+        // $100.00 at $2 per token (positive exponent) costs 50 tokens
+        assert_eq!(
+            convert_usd_to_mint_amount(100_000_000, 2, 0, 9),
+            Some(50_000_000_000)
+        );
+    }
+
+    #[test]
+    fn rejects_zero_price_and_overflow() {
+        assert_eq!(convert_usd_to_mint_amount(1_000_000, 0, -8, 6), None);
+        assert_eq!(convert_usd_to_mint_amount(u64::MAX, 1, -8, 6), None);
+        assert_eq!(convert_usd_to_mint_amount(1, 1, -8, u8::MAX), None);
+        assert_eq!(convert_usd_to_mint_amount(1, 1, i32::MIN, 6), None);
+        assert_eq!(convert_usd_to_mint_amount(1, 1, i32::MAX, 6), None);
+    }
+
+    proptest! {
+        #[test]
+        fn rounds_up_to_smallest_sufficient_amount(
+            usd_value in any::<u64>(),
+            price in 1..=u64::MAX,
+            exponent in -10_i32..=4,
+            mint_decimals in 0_u8..=9,
+        ) {
+            let (numerator, denominator) = exact_parts(usd_value, price, exponent, mint_decimals);
+
+            match convert_usd_to_mint_amount(usd_value, price, exponent, mint_decimals) {
+                Some(amount) => {
+                    let amount = amount as u128;
+                    prop_assert!(amount
+                        .checked_mul(denominator)
+                        .iter()
+                        .all(|value| *value >= numerator));
+                    prop_assert!(amount == 0 || (amount - 1) * denominator < numerator);
+                }
+                None => prop_assert!(numerator.div_ceil(denominator) > u64::MAX as u128),
+            }
+        }
+
+        #[test]
+        fn is_monotonic_in_usd_value(
+            a in any::<u64>(),
+            b in any::<u64>(),
+            price in 1..=u64::MAX,
+            exponent in -10_i32..=4,
+            mint_decimals in 0_u8..=9,
+        ) {
+            let (low, high) = (a.min(b), a.max(b));
+
+            let high_amount = convert_usd_to_mint_amount(high, price, exponent, mint_decimals);
+            if let Some(high_amount) = high_amount {
+                let low_amount = convert_usd_to_mint_amount(low, price, exponent, mint_decimals);
+                prop_assert!(low_amount.is_some_and(|low_amount| low_amount <= high_amount));
+            }
+        }
+
+        #[test]
+        fn is_antitonic_in_price(
+            usd_value in any::<u64>(),
+            a in 1..=u64::MAX,
+            b in 1..=u64::MAX,
+            exponent in -10_i32..=4,
+            mint_decimals in 0_u8..=9,
+        ) {
+            let (low, high) = (a.min(b), a.max(b));
+
+            let low_price_amount =
+                convert_usd_to_mint_amount(usd_value, low, exponent, mint_decimals);
+            if let Some(low_price_amount) = low_price_amount {
+                let high_price_amount =
+                    convert_usd_to_mint_amount(usd_value, high, exponent, mint_decimals);
+                prop_assert!(high_price_amount.is_some_and(|amount| amount <= low_price_amount));
+            }
+        }
+
+        #[test]
+        fn scales_exactly_with_mint_decimals_at_peg(
+            usd_value in 0..=u64::MAX / 1_000_000,
+            mint_decimals in 6_u8..=9,
+        ) {
+            let scale = 10_u64.pow((mint_decimals - PRICE_DECIMALS).into());
+            let expected = (usd_value as u128 * scale as u128).try_into().ok();
+
+            prop_assert_eq!(
+                convert_usd_to_mint_amount(usd_value, 100_000_000, -8, mint_decimals),
+                expected
+            );
+        }
+    }
+}
@@ -1,10 +1,21 @@
 import { PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
 
 export const USDC_MINT = new PublicKey(process.env.USDC_MINT);
 export const USDC_PRICE_UPDATE_V2 = new PublicKey('Dpw1EAVrSB1ibxiDQyTAW6Zip3J4Btk2x4SgApQCeFbX');
+export const USDC_FEED_ID = Array.from(Buffer.from('eaa020c61cc479712813461ce153894a96a6c00b21ed0cfc2798d1f9a9e9c94a', 'hex'));
 
 export const PYUSD_MINT = new PublicKey(process.env.PYUSD_MINT);
 export const PYUSD_PRICE_UPDATE_V2 = new PublicKey('9zXQxpYH3kYhtoybmZfUNNCRVuud7fY9jswTg1hLyT8k');
+export const PYUSD_FEED_ID = Array.from(Buffer.from(process.env.PYUSD_FEED_ID, 'hex'));
 
 export const TUKTUK_PROGRAM_ID = new PublicKey('tuktukUrfhXT6ZT77QTU8RQtvgL967uRuVagWF57zVA');
-export const TASK_QUEUE = new PublicKey('GHEmMQ7Av2aG93C2SHqNH7heEKd9Hu7p119xZvDSxHTn');
\ No newline at end of file
+export const TASK_QUEUE = new PublicKey('GHEmMQ7Av2aG93C2SHqNH7heEKd9Hu7p119xZvDSxHTn');
+
+export const ORACLE_CONFIG = {
+  verificationLevel: { full: {} },
+  maxStalenessSecs: 60,
+  maxConfBps: 100,
+  minPrice: new BN(980_000),
+  maxPrice: new BN(1_020_000),
+};
@@ -17,6 +17,7 @@ const signature = await splurgeProgram.methods
   .accountsPartial({
     shopper: shopperPda,
     order: orderPda,
+    item: orderAcc.item,
     paymentMint,
     tokenProgram,
   })
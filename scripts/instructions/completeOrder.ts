@@ -16,7 +16,7 @@ const { owner: tokenProgram } = await connection.getAccountInfo(orderAcc.payment
 const signature = await splurgeProgram.methods
   .completeOrder()
   .accountsPartial({
-    admin: admin.publicKey,
+    operator: admin.publicKey,
     shopper: shopperPda,
     store: storePda,
     item: itemPda,
@@ -1,5 +1,5 @@
 import { getAssociatedTokenAddressSync, getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
-import { PYUSD_MINT, PYUSD_PRICE_UPDATE_V2, USDC_MINT, USDC_PRICE_UPDATE_V2 } from "../constants";
+import { ORACLE_CONFIG, PYUSD_MINT, PYUSD_FEED_ID, USDC_MINT, USDC_FEED_ID } from "../constants";
 import { admin, connection, splurgeProgram, treasury } from "../setup";
 import { PublicKey } from "@solana/web3.js";
 
@@ -9,11 +9,15 @@ console.log("Initializing config...")
 const acceptedMints = [
   {
     mint: USDC_MINT,
-    priceUpdateV2: USDC_PRICE_UPDATE_V2,
+    feedId: USDC_FEED_ID,
+    ...ORACLE_CONFIG,
+    isPaused: false,
   },
   {
     mint: PYUSD_MINT,
-    priceUpdateV2: PYUSD_PRICE_UPDATE_V2,
+    feedId: PYUSD_FEED_ID,
+    ...ORACLE_CONFIG,
+    isPaused: false,
   }
 ];
 const orderFeeBps = 250;
@@ -1,5 +1,5 @@
 import { PublicKey } from "@solana/web3.js";
-import { admin, connection, splurgeProgram, tuktukProgram } from "../setup";
+import { admin, config, connection, splurgeProgram, tuktukProgram } from "../setup";
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { TASK_QUEUE, TUKTUK_PROGRAM_ID } from "../constants";
 import { nextAvailableTaskIds, taskKey, taskQueueAuthorityKey } from "@helium/tuktuk-sdk";
@@ -22,11 +22,12 @@ const taskQueueAcc = await tuktukProgram.account.taskQueueV0.fetchNullable(TASK_
 if (!taskQueueAcc) throw new Error("Task queue not found");
 const taskId = nextAvailableTaskIds(taskQueueAcc.taskBitmap, 1, false)[0];
 const [taskPda] = taskKey(TASK_QUEUE, taskId, TUKTUK_PROGRAM_ID);
-const [taskQueueAuthorityPda] = taskQueueAuthorityKey(TASK_QUEUE, admin.publicKey);
+const [taskQueueAuthorityPda] = taskQueueAuthorityKey(TASK_QUEUE, config);
 
 const signature = await splurgeProgram.methods
   .shipOrder(taskId)
   .accountsPartial({
+    shipper: admin.publicKey,
     order: orderPda,
     authority: authorityPubkey,
     item: itemPda,
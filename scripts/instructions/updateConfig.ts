@@ -1,4 +1,4 @@
-import { PYUSD_MINT, PYUSD_PRICE_UPDATE_V2, USDC_MINT, USDC_PRICE_UPDATE_V2 } from "../constants";
+import { ORACLE_CONFIG, PYUSD_MINT, PYUSD_FEED_ID, USDC_MINT, USDC_FEED_ID } from "../constants";
 import { admin, splurgeProgram } from "../setup";
 
 console.log("Updating config...")
@@ -7,23 +7,37 @@ console.log("Updating config...")
 const acceptedMints = [
   {
     mint: USDC_MINT,
-    priceUpdateV2: USDC_PRICE_UPDATE_V2,
+    feedId: USDC_FEED_ID,
+    ...ORACLE_CONFIG,
+    isPaused: false,
   },
   {
     mint: PYUSD_MINT,
-    priceUpdateV2: PYUSD_PRICE_UPDATE_V2,
+    feedId: PYUSD_FEED_ID,
+    ...ORACLE_CONFIG,
+    isPaused: false,
   }
 ];
-const isPaused = null;
+const pausedOperations = null;
 const newAdmin = null;
 const orderFeeBps = null;
+const maxOrderFeeBps = null;
+const shopperCancelWindowSecs = null;
+const completionDelaySecs = null;
+const shippingDeadlineSecs = null;
+const feeTiers = null;
 
 const signature = await splurgeProgram.methods
   .updateConfig({
     acceptedMints,
-    isPaused,
+    pausedOperations,
     newAdmin,
     orderFeeBps,
+    maxOrderFeeBps,
+    shopperCancelWindowSecs,
+    completionDelaySecs,
+    shippingDeadlineSecs,
+    feeTiers,
   })
   .accounts({
     authority: admin.publicKey,
@@ -18,4 +18,11 @@ export const [treasury] = PublicKey.findProgramAddressSync(
     Buffer.from("treasury"),
   ],
   splurgeProgram.programId
-)
\ No newline at end of file
+)
+
+export const [config] = PublicKey.findProgramAddressSync(
+  [
+    Buffer.from("config"),
+  ],
+  splurgeProgram.programId
+)
@@ -38,6 +38,13 @@ export async function fetchOrderAcc(
   return await program.account.order.fetchNullable(orderPda);
 }
 
+export async function fetchCartOrderAcc(
+  program: Program<Splurge>,
+  cartOrderPda: PublicKey
+) {
+  return await program.account.cartOrder.fetchNullable(cartOrderPda);
+}
+
 export async function fetchReviewAcc(
   program: Program<Splurge>,
   reviewPda: PublicKey
@@ -1,7 +1,9 @@
 import { PublicKey } from '@solana/web3.js';
+import { BN } from '@coral-xyz/anchor';
 import idl from '../target/idl/splurge.json';
 import usdcPriceUpdateV2AccInfo from './fixtures/usdc_price_update_v2.json';
 import usdtPriceUpdateV2AccInfo from './fixtures/usdt_price_update_v2.json';
+import usdcPartialPriceUpdateV2AccInfo from './fixtures/usdc_partial_price_update_v2.json';
 import tuktukIdl from './fixtures/tuktuk.json';
 import tuktukConfigV0 from './fixtures/tuktuk_config_v0.json';
 
@@ -10,6 +12,14 @@ export const TUKTUK_PROGRAM_ID = new PublicKey(tuktukIdl.address);
 export const MAX_SHOPPER_NAME_LEN = 64;
 export const MAX_STORE_NAME_LEN = 64;
 export const MAX_STORE_ITEM_NAME_LEN = 32;
+export const ORDER_FEE_TIMELOCK_SECS = 604_800;
+export const DEFAULT_MAX_ORDER_FEE_BPS = 1_000;
+export const REVIEW_WINDOW_SECS = 2_592_000;
+export const PAUSE_ORDERING = 1;
+export const PAUSE_SHIPPING = 2;
+export const PAUSE_COMPLETION = 4;
+export const PAUSE_WITHDRAWALS = 8;
+export const PAUSE_LISTING = 16;
 export const USDC_MINT = new PublicKey(
   'EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v'
 );
@@ -23,4 +33,26 @@ export const USDC_PRICE_UPDATE_V2 = new PublicKey(
 export const USDT_PRICE_UPDATE_V2 = new PublicKey(
   usdtPriceUpdateV2AccInfo.pubkey
 );
+export const USDC_PARTIAL_PRICE_UPDATE_V2 = new PublicKey(
+  usdcPartialPriceUpdateV2AccInfo.pubkey
+);
+export const USDC_FEED_ID = Array.from(
+  Buffer.from(
+    'eaa020c61cc479712813461ce153894a96a6c00b21ed0cfc2798d1f9a9e9c94a',
+    'hex'
+  )
+);
+export const USDT_FEED_ID = Array.from(
+  Buffer.from(
+    '2b89b9dc8fdf9f34709a5b106b472f0f39bb6ca9ce04b0fd7f2e971688e2e53b',
+    'hex'
+  )
+);
 export const CONFIG_V0 = new PublicKey(tuktukConfigV0.pubkey);
+export const ORACLE_CONFIG = {
+  verificationLevel: { full: {} },
+  maxStalenessSecs: 60,
+  maxConfBps: 100,
+  minPrice: new BN(980_000), // $0.98
+  maxPrice: new BN(1_020_000), // $1.02
+};
@@ -0,0 +1,120 @@
+import { beforeEach, describe, expect, test } from 'bun:test';
+import { Keypair } from '@solana/web3.js';
+import { Splurge } from '../../target/types/splurge';
+import { Program } from '@coral-xyz/anchor';
+import { fetchConfigAcc } from '../accounts';
+import { LiteSVM } from 'litesvm';
+import { LiteSVMProvider } from 'anchor-litesvm';
+import { expectAnchorError, fundedSystemAccountInfo, getSetup } from '../setup';
+import { ORACLE_CONFIG, USDC_FEED_ID, USDC_MINT } from '../constants';
+import { getConfigPda } from '../pda';
+
+describe('acceptAdmin', () => {
+  let { litesvm, provider, program } = {} as {
+    litesvm: LiteSVM;
+    provider: LiteSVMProvider;
+    program: Program<Splurge>;
+  };
+
+  const [admin, newAdmin] = Array.from({ length: 2 }, Keypair.generate);
+
+  beforeEach(async () => {
+    ({ litesvm, provider, program } = await getSetup([
+      ...[admin, newAdmin].map((kp) => {
+        return {
+          pubkey: kp.publicKey,
+          account: fundedSystemAccountInfo(),
+        };
+      }),
+    ]));
+
+    await program.methods
+      .initializeConfig({
+        acceptedMints: [
+          {
+            mint: USDC_MINT,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+          },
+        ],
+        admin: admin.publicKey,
+        orderFeeBps: 250,
+      })
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .updateConfig({
+        acceptedMints: null,
+        pausedOperations: null,
+        newAdmin: newAdmin.publicKey,
+        orderFeeBps: null,
+        maxOrderFeeBps: null,
+        shopperCancelWindowSecs: null,
+        completionDelaySecs: null,
+        shippingDeadlineSecs: null,
+        feeTiers: null,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+  });
+
+  test('accepts a pending admin transfer', async () => {
+    await program.methods
+      .acceptAdmin()
+      .accounts({
+        pendingAdmin: newAdmin.publicKey,
+      })
+      .signers([newAdmin])
+      .rpc();
+
+    const configPda = getConfigPda();
+    const configAcc = await fetchConfigAcc(program, configPda);
+
+    expect(configAcc.admin).toStrictEqual(newAdmin.publicKey);
+    expect(configAcc.pendingAdmin).toBeNull();
+  });
+
+  test('throws if signer is not the pending admin', async () => {
+    try {
+      await program.methods
+        .acceptAdmin()
+        .accounts({
+          pendingAdmin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'UnauthorizedPendingAdmin');
+    }
+  });
+
+  test('throws if no admin transfer is pending', async () => {
+    await program.methods
+      .acceptAdmin()
+      .accounts({
+        pendingAdmin: newAdmin.publicKey,
+      })
+      .signers([newAdmin])
+      .rpc();
+
+    try {
+      await program.methods
+        .acceptAdmin()
+        .accounts({
+          pendingAdmin: newAdmin.publicKey,
+        })
+        .signers([newAdmin])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'NoPendingAdmin');
+    }
+  });
+});
@@ -0,0 +1,334 @@
+import { beforeEach, describe, expect, test } from 'bun:test';
+import { Keypair, PublicKey, Transaction } from '@solana/web3.js';
+import {
+  createTransferCheckedInstruction,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
+import { Splurge } from '../../target/types/splurge';
+import { BN, Program } from '@coral-xyz/anchor';
+import {
+  getCartOrderPda,
+  getItemPda,
+  getShopperPda,
+  getStorePda,
+  getTreasuryPda,
+} from '../pda';
+import { fetchCartOrderAcc, fetchItemAcc } from '../accounts';
+import { LiteSVM } from 'litesvm';
+import { LiteSVMProvider } from 'anchor-litesvm';
+import {
+  MINT_DECIMALS,
+  ORACLE_CONFIG,
+  USDC_FEED_ID,
+  USDC_MINT,
+  USDC_PRICE_UPDATE_V2,
+} from '../constants';
+import {
+  expectAnchorError,
+  fundedSystemAccountInfo,
+  getSetup,
+  initAta,
+} from '../setup';
+
+describe('cancelCartOrder', () => {
+  let { litesvm, provider, program } = {} as {
+    litesvm: LiteSVM;
+    provider: LiteSVMProvider;
+    program: Program<Splurge>;
+  };
+
+  const [admin, shopperAuthority, storeAuthorityA, storeAuthorityB] =
+    Array.from({ length: 4 }, Keypair.generate);
+  const treasury = getTreasuryPda();
+
+  const itemName = 'Item A';
+  const itemPrice = 1e6; // $1
+  const initInventoryCount = 10;
+  const initShopperAtaBal = 1e8; // $100
+  const paymentMint = USDC_MINT;
+  const tokenProgram = TOKEN_PROGRAM_ID;
+  const amounts = [2, 3];
+  let cartOrderPda: PublicKey;
+
+  const shopperPda = getShopperPda(shopperAuthority.publicKey);
+  const storePdaA = getStorePda(storeAuthorityA.publicKey);
+  const storePdaB = getStorePda(storeAuthorityB.publicKey);
+  const itemPdaA = getItemPda(storePdaA, itemName);
+  const itemPdaB = getItemPda(storePdaB, itemName);
+
+  beforeEach(async () => {
+    ({ litesvm, provider, program } = await getSetup([
+      ...[admin, shopperAuthority, storeAuthorityA, storeAuthorityB].map(
+        (kp) => {
+          return {
+            pubkey: kp.publicKey,
+            account: fundedSystemAccountInfo(),
+          };
+        }
+      ),
+    ]));
+
+    initAta(litesvm, USDC_MINT, treasury);
+    initAta(litesvm, USDC_MINT, shopperAuthority.publicKey, initShopperAtaBal);
+
+    await program.methods
+      .initializeConfig({
+        acceptedMints: [
+          {
+            mint: USDC_MINT,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+          },
+        ],
+        admin: admin.publicKey,
+        orderFeeBps: 250,
+      })
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .initializeShopper({
+        name: 'Shopper A',
+        image: 'https://example.com/image.png',
+        address: 'address',
+      })
+      .accounts({
+        authority: shopperAuthority.publicKey,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    for (const storeAuthority of [storeAuthorityA, storeAuthorityB]) {
+      await program.methods
+        .initializeStore({
+          name: 'Store',
+          image: 'https://example.com/image.png',
+          about: 'about',
+        })
+        .accounts({
+          authority: storeAuthority.publicKey,
+        })
+        .signers([storeAuthority])
+        .rpc();
+
+      await program.methods
+        .listItem({
+          price: new BN(itemPrice),
+          pricingMode: { oracle: {} },
+          referralBps: 0,
+          inventoryCount: initInventoryCount,
+          name: itemName,
+          image: 'https://example.com/item.png',
+          description: 'description',
+        })
+        .accounts({
+          authority: storeAuthority.publicKey,
+        })
+        .signers([storeAuthority])
+        .rpc();
+    }
+
+    const { unixTimestamp } = litesvm.getClock();
+    cartOrderPda = getCartOrderPda(shopperPda, new BN(unixTimestamp));
+
+    await program.methods
+      .createCartOrder(
+        amounts,
+        new BN(unixTimestamp),
+        new BN(initShopperAtaBal)
+      )
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        cartOrder: cartOrderPda,
+        priceUpdateV2: USDC_PRICE_UPDATE_V2,
+        paymentMint,
+        tokenProgram,
+      })
+      .remainingAccounts(
+        [
+          [itemPdaA, storePdaA],
+          [itemPdaB, storePdaB],
+        ].flatMap(([itemPda, storePda]) => [
+          { pubkey: itemPda, isSigner: false, isWritable: true },
+          { pubkey: storePda, isSigner: false, isWritable: false },
+        ])
+      )
+      .signers([shopperAuthority])
+      .rpc();
+  });
+
+  test('cancels a cart order', async () => {
+    const shopperAuthorityAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      shopperAuthority.publicKey,
+      false,
+      tokenProgram
+    );
+    const preShopperAuthorityAtaBal = (
+      await getAccount(provider.connection, shopperAuthorityAta)
+    ).amount;
+    const cartOrderAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      cartOrderPda,
+      true,
+      tokenProgram
+    );
+
+    await program.methods
+      .cancelCartOrder()
+      .accountsPartial({
+        operator: admin.publicKey,
+        authority: shopperAuthority.publicKey,
+        shopper: shopperPda,
+        cartOrder: cartOrderPda,
+        paymentMint,
+        tokenProgram,
+      })
+      .remainingAccounts(
+        [itemPdaA, itemPdaB].map((pubkey) => {
+          return { pubkey, isSigner: false, isWritable: true };
+        })
+      )
+      .signers([admin])
+      .rpc();
+
+    const cartOrderAcc = await fetchCartOrderAcc(program, cartOrderPda);
+
+    expect(cartOrderAcc.status).toStrictEqual({ cancelled: {} });
+
+    const postShopperAuthorityAtaBal = (
+      await getAccount(provider.connection, shopperAuthorityAta)
+    ).amount;
+
+    expect(Number(postShopperAuthorityAtaBal)).toBe(
+      Number(preShopperAuthorityAtaBal) +
+        cartOrderAcc.paymentSubtotal.toNumber() +
+        cartOrderAcc.platformFee.toNumber()
+    );
+    expect(litesvm.getBalance(cartOrderAta)).toBe(null);
+
+    const itemAccA = await fetchItemAcc(program, itemPdaA);
+    const itemAccB = await fetchItemAcc(program, itemPdaB);
+
+    expect(itemAccA.inventoryCount).toBe(initInventoryCount);
+    expect(itemAccB.inventoryCount).toBe(initInventoryCount);
+  });
+
+  test('refunds dust sent to the escrow', async () => {
+    const shopperAuthorityAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      shopperAuthority.publicKey,
+      false,
+      tokenProgram
+    );
+    const preShopperAuthorityAtaBal = (
+      await getAccount(provider.connection, shopperAuthorityAta)
+    ).amount;
+    const cartOrderAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      cartOrderPda,
+      true,
+      tokenProgram
+    );
+
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        createTransferCheckedInstruction(
+          shopperAuthorityAta,
+          paymentMint,
+          cartOrderAta,
+          shopperAuthority.publicKey,
+          1n,
+          MINT_DECIMALS,
+          [],
+          tokenProgram
+        )
+      ),
+      [shopperAuthority]
+    );
+
+    await program.methods
+      .cancelCartOrder()
+      .accountsPartial({
+        operator: admin.publicKey,
+        authority: shopperAuthority.publicKey,
+        shopper: shopperPda,
+        cartOrder: cartOrderPda,
+        paymentMint,
+        tokenProgram,
+      })
+      .remainingAccounts(
+        [itemPdaA, itemPdaB].map((pubkey) => {
+          return { pubkey, isSigner: false, isWritable: true };
+        })
+      )
+      .signers([admin])
+      .rpc();
+
+    const cartOrderAcc = await fetchCartOrderAcc(program, cartOrderPda);
+    const postShopperAuthorityAtaBal = (
+      await getAccount(provider.connection, shopperAuthorityAta)
+    ).amount;
+
+    expect(Number(postShopperAuthorityAtaBal)).toBe(
+      Number(preShopperAuthorityAtaBal) +
+        cartOrderAcc.paymentSubtotal.toNumber() +
+        cartOrderAcc.platformFee.toNumber()
+    );
+    expect(litesvm.getBalance(cartOrderAta)).toBe(null);
+  });
+
+  test('throws if items do not match line items', async () => {
+    try {
+      await program.methods
+        .cancelCartOrder()
+        .accountsPartial({
+          operator: admin.publicKey,
+          authority: shopperAuthority.publicKey,
+          shopper: shopperPda,
+          cartOrder: cartOrderPda,
+          paymentMint,
+          tokenProgram,
+        })
+        .remainingAccounts(
+          [itemPdaB, itemPdaA].map((pubkey) => {
+            return { pubkey, isSigner: false, isWritable: true };
+          })
+        )
+        .signers([admin])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidCartItem');
+    }
+  });
+
+  test('throws if cancelling as unauthorized operator', async () => {
+    try {
+      await program.methods
+        .cancelCartOrder()
+        .accountsPartial({
+          operator: storeAuthorityA.publicKey,
+          authority: shopperAuthority.publicKey,
+          shopper: shopperPda,
+          cartOrder: cartOrderPda,
+          paymentMint,
+          tokenProgram,
+        })
+        .remainingAccounts(
+          [itemPdaA, itemPdaB].map((pubkey) => {
+            return { pubkey, isSigner: false, isWritable: true };
+          })
+        )
+        .signers([storeAuthorityA])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'UnauthorizedOperator');
+    }
+  });
+});
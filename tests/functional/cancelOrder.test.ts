@@ -8,17 +8,20 @@ import {
 import { Splurge } from '../../target/types/splurge';
 import { BN, Program } from '@coral-xyz/anchor';
 import {
+  getConfigPda,
   getItemPda,
   getOrderPda,
   getShopperPda,
   getStorePda,
   getTreasuryPda,
 } from '../pda';
-import { fetchOrderAcc, fetchTaskQueueAcc } from '../accounts';
+import { fetchItemAcc, fetchOrderAcc, fetchTaskQueueAcc } from '../accounts';
 import { LiteSVM } from 'litesvm';
 import { LiteSVMProvider } from 'anchor-litesvm';
 import {
+  ORACLE_CONFIG,
   TUKTUK_PROGRAM_ID,
+  USDC_FEED_ID,
   USDC_MINT,
   USDC_PRICE_UPDATE_V2,
   USDT_MINT,
@@ -92,7 +95,9 @@ describe('cancelOrder', () => {
         acceptedMints: [
           {
             mint: USDC_MINT,
-            priceUpdateV2: USDC_PRICE_UPDATE_V2,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
           },
         ],
         admin: admin.publicKey,
@@ -131,6 +136,8 @@ describe('cancelOrder', () => {
     await program.methods
       .listItem({
         price: new BN(itemPrice),
+        pricingMode: { oracle: {} },
+        referralBps: 0,
         inventoryCount: initInventoryCount,
         name: itemName,
         image: 'https://example.com/item.png',
@@ -146,7 +153,7 @@ describe('cancelOrder', () => {
     orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
 
     await program.methods
-      .createOrder(1, new BN(unixTimestamp))
+      .createOrder(1, new BN(unixTimestamp), new BN(initShopperAtaBal), null)
       .accountsPartial({
         authority: shopperAuthority.publicKey,
         store: storePda,
@@ -167,13 +174,13 @@ describe('cancelOrder', () => {
     const [taskPda] = taskKey(taskQueuePda, taskId);
     const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
       taskQueuePda,
-      admin.publicKey
+      getConfigPda()
     );
 
     await program.methods
       .shipOrder(taskId)
       .accountsPartial({
-        admin: admin.publicKey,
+        shipper: admin.publicKey,
         order: orderPda,
         authority: shopperAuthority.publicKey,
         item: itemPda,
@@ -223,8 +230,9 @@ describe('cancelOrder', () => {
     await program.methods
       .cancelOrder()
       .accountsPartial({
-        admin: admin.publicKey,
+        operator: admin.publicKey,
         order: orderPda,
+        item: itemPda,
         paymentMint,
         shopper: shopperPda,
         tokenProgram,
@@ -259,6 +267,10 @@ describe('cancelOrder', () => {
 
     expect(postOrderAtaRent).toBe(null);
 
+    const itemAcc = await fetchItemAcc(program, itemPda);
+
+    expect(itemAcc.inventoryCount).toBe(initInventoryCount);
+
     const postTreasuryAta = await getAccount(provider.connection, treasuryAta);
 
     expect(Number(preTreasuryAta.amount)).toBe(
@@ -266,13 +278,14 @@ describe('cancelOrder', () => {
     );
   });
 
-  test('throws if cancelling as unauthorized admin', async () => {
+  test('throws if cancelling as unauthorized operator', async () => {
     try {
       await program.methods
         .cancelOrder()
         .accountsPartial({
-          admin: storeAuthority.publicKey,
+          operator: storeAuthority.publicKey,
           order: orderPda,
+          item: itemPda,
           paymentMint,
           shopper: shopperPda,
           tokenProgram,
@@ -280,7 +293,7 @@ describe('cancelOrder', () => {
         .signers([storeAuthority])
         .rpc();
     } catch (err) {
-      expectAnchorError(err, 'UnauthorizedAdmin');
+      expectAnchorError(err, 'UnauthorizedOperator');
     }
   });
 });
@@ -0,0 +1,498 @@
+import { beforeEach, describe, expect, test } from 'bun:test';
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import {
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
+import { Splurge } from '../../target/types/splurge';
+import { BN, Program } from '@coral-xyz/anchor';
+import {
+  getConfigPda,
+  getItemPda,
+  getOrderPda,
+  getReviewPda,
+  getShopperPda,
+  getStorePda,
+  getTreasuryPda,
+} from '../pda';
+import {
+  fetchOrderAcc,
+  fetchReviewAcc,
+  fetchTaskQueueAcc,
+} from '../accounts';
+import { LiteSVM } from 'litesvm';
+import { LiteSVMProvider } from 'anchor-litesvm';
+import {
+  ORACLE_CONFIG,
+  REVIEW_WINDOW_SECS,
+  TUKTUK_PROGRAM_ID,
+  USDC_FEED_ID,
+  USDC_MINT,
+  USDC_PRICE_UPDATE_V2,
+  USDT_MINT,
+} from '../constants';
+import {
+  expectAnchorError,
+  fundedSystemAccountInfo,
+  getSetup,
+  initAta,
+  initTaskQueue,
+} from '../setup';
+import {
+  nextAvailableTaskIds,
+  taskKey,
+  taskQueueAuthorityKey,
+} from '@helium/tuktuk-sdk';
+import { Tuktuk } from '@helium/tuktuk-idls/lib/types/tuktuk.js';
+
+describe('closeOrder', () => {
+  let { litesvm, provider, program, tuktukProgram, taskQueuePda } = {} as {
+    litesvm: LiteSVM;
+    provider: LiteSVMProvider;
+    program: Program<Splurge>;
+    tuktukProgram: Program<Tuktuk>;
+    taskQueuePda: PublicKey;
+  };
+
+  const [admin, shopperAuthority, storeAuthority] = Array.from(
+    { length: 3 },
+    Keypair.generate
+  );
+  const treasury = getTreasuryPda();
+
+  const itemName = 'Item A';
+  const itemPrice = 1e6; // $1
+  const initInventoryCount = 10;
+  const initShopperAtaBal = 1e8; // $100
+  const paymentMint = USDC_MINT;
+  const tokenProgram = TOKEN_PROGRAM_ID;
+  let orderPda: PublicKey;
+  let taskId: number;
+
+  const shopperPda = getShopperPda(shopperAuthority.publicKey);
+  const storePda = getStorePda(storeAuthority.publicKey);
+  const itemPda = getItemPda(storePda, itemName);
+
+  beforeEach(async () => {
+    ({ litesvm, provider, program, tuktukProgram, taskQueuePda } =
+      await getSetup([
+        ...[admin, shopperAuthority, storeAuthority].map((kp) => {
+          return {
+            pubkey: kp.publicKey,
+            account: fundedSystemAccountInfo(LAMPORTS_PER_SOL * 5),
+          };
+        }),
+      ]));
+
+    await initTaskQueue(tuktukProgram, admin, taskQueuePda);
+    const taskQueueAcc = await fetchTaskQueueAcc(tuktukProgram, taskQueuePda);
+    taskId = nextAvailableTaskIds(taskQueueAcc.taskBitmap, 1, false)[0];
+
+    initAta(litesvm, USDC_MINT, treasury);
+    initAta(litesvm, USDC_MINT, shopperAuthority.publicKey, initShopperAtaBal);
+    initAta(litesvm, USDT_MINT, treasury);
+    initAta(litesvm, USDT_MINT, shopperAuthority.publicKey, initShopperAtaBal);
+
+    await program.methods
+      .initializeConfig({
+        acceptedMints: [
+          {
+            mint: USDC_MINT,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+          },
+        ],
+        admin: admin.publicKey,
+        orderFeeBps: 250,
+      })
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .initializeShopper({
+        name: 'Shopper A',
+        image: 'https://example.com/image.png',
+        address: 'address',
+      })
+      .accounts({
+        authority: shopperAuthority.publicKey,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    await program.methods
+      .initializeStore({
+        name: 'Store A',
+        image: 'https://example.com/image.png',
+        about: 'about',
+      })
+      .accounts({
+        authority: storeAuthority.publicKey,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    await program.methods
+      .listItem({
+        price: new BN(itemPrice),
+        pricingMode: { oracle: {} },
+        referralBps: 0,
+        inventoryCount: initInventoryCount,
+        name: itemName,
+        image: 'https://example.com/item.png',
+        description: 'description',
+      })
+      .accounts({
+        authority: storeAuthority.publicKey,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    const { unixTimestamp } = litesvm.getClock();
+    orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+    await program.methods
+      .createOrder(1, new BN(unixTimestamp), new BN(initShopperAtaBal), null)
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+        order: orderPda,
+        priceUpdateV2: USDC_PRICE_UPDATE_V2,
+        paymentMint,
+        tokenProgram,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+  });
+
+  test('closes a cancelled order', async () => {
+    await program.methods
+      .shopperCancelOrder()
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        order: orderPda,
+        item: itemPda,
+        paymentMint,
+        tokenProgram,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    const preShopperAuthorityBal = litesvm.getBalance(
+      shopperAuthority.publicKey
+    );
+    const orderRent = litesvm.getBalance(orderPda);
+
+    await program.methods
+      .closeOrder()
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        order: orderPda,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    expect(await fetchOrderAcc(program, orderPda)).toBe(null);
+
+    const postShopperAuthorityBal = litesvm.getBalance(
+      shopperAuthority.publicKey
+    );
+
+    expect(postShopperAuthorityBal).toBe(preShopperAuthorityBal + orderRent);
+  });
+
+  test('throws if order is not finalized', async () => {
+    try {
+      await program.methods
+        .closeOrder()
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          order: orderPda,
+        })
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'OrderNotFinalized');
+    }
+  });
+
+  test('closes a completed order after the review window', async () => {
+    const orderAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      orderPda,
+      !PublicKey.isOnCurve(orderPda)
+    );
+
+    await program.methods
+      .shipOrder(taskId)
+      .accountsPartial({
+        shipper: admin.publicKey,
+        order: orderPda,
+        authority: shopperAuthority.publicKey,
+        item: itemPda,
+        orderTokenAccount: orderAta,
+        paymentMint,
+        shopper: shopperPda,
+        store: storePda,
+        task: taskKey(taskQueuePda, taskId)[0],
+        taskQueue: taskQueuePda,
+        taskQueueAuthority: taskQueueAuthorityKey(
+          taskQueuePda,
+          getConfigPda()
+        )[0],
+        tokenProgram,
+        tuktuk: TUKTUK_PROGRAM_ID,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .completeOrder()
+      .accountsPartial({
+        operator: admin.publicKey,
+        shopper: shopperPda,
+        store: storePda,
+        item: itemPda,
+        order: orderPda,
+        tokenProgram,
+      })
+      .signers([admin])
+      .rpc();
+
+    const { finalizedAt } = await fetchOrderAcc(program, orderPda);
+    const clock = litesvm.getClock();
+    clock.unixTimestamp =
+      BigInt(finalizedAt.toNumber()) + BigInt(REVIEW_WINDOW_SECS) + 1n;
+    litesvm.setClock(clock);
+
+    await program.methods
+      .closeOrder()
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        order: orderPda,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    expect(await fetchOrderAcc(program, orderPda)).toBe(null);
+  });
+
+  test('throws if review window is open', async () => {
+    const { timestamp } = await fetchOrderAcc(program, orderPda);
+    const clock = litesvm.getClock();
+    clock.unixTimestamp += 86_400n;
+    litesvm.setClock(clock);
+
+    const orderAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      orderPda,
+      !PublicKey.isOnCurve(orderPda)
+    );
+
+    await program.methods
+      .shipOrder(taskId)
+      .accountsPartial({
+        shipper: admin.publicKey,
+        order: orderPda,
+        authority: shopperAuthority.publicKey,
+        item: itemPda,
+        orderTokenAccount: orderAta,
+        paymentMint,
+        shopper: shopperPda,
+        store: storePda,
+        task: taskKey(taskQueuePda, taskId)[0],
+        taskQueue: taskQueuePda,
+        taskQueueAuthority: taskQueueAuthorityKey(
+          taskQueuePda,
+          getConfigPda()
+        )[0],
+        tokenProgram,
+        tuktuk: TUKTUK_PROGRAM_ID,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .completeOrder()
+      .accountsPartial({
+        operator: admin.publicKey,
+        shopper: shopperPda,
+        store: storePda,
+        item: itemPda,
+        order: orderPda,
+        tokenProgram,
+      })
+      .signers([admin])
+      .rpc();
+
+    // The review window runs from completion, not order creation
+    clock.unixTimestamp =
+      BigInt(timestamp.toNumber()) + BigInt(REVIEW_WINDOW_SECS) + 1n;
+    litesvm.setClock(clock);
+
+    try {
+      await program.methods
+        .closeOrder()
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          order: orderPda,
+        })
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'ReviewWindowOpen');
+    }
+  });
+
+  test('closes a reviewed order after the review window', async () => {
+    const orderAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      orderPda,
+      !PublicKey.isOnCurve(orderPda)
+    );
+
+    await program.methods
+      .shipOrder(taskId)
+      .accountsPartial({
+        shipper: admin.publicKey,
+        order: orderPda,
+        authority: shopperAuthority.publicKey,
+        item: itemPda,
+        orderTokenAccount: orderAta,
+        paymentMint,
+        shopper: shopperPda,
+        store: storePda,
+        task: taskKey(taskQueuePda, taskId)[0],
+        taskQueue: taskQueuePda,
+        taskQueueAuthority: taskQueueAuthorityKey(
+          taskQueuePda,
+          getConfigPda()
+        )[0],
+        tokenProgram,
+        tuktuk: TUKTUK_PROGRAM_ID,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .completeOrder()
+      .accountsPartial({
+        operator: admin.publicKey,
+        shopper: shopperPda,
+        store: storePda,
+        item: itemPda,
+        order: orderPda,
+        tokenProgram,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .createReview({
+        text: 'review',
+        rating: 3,
+      })
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        order: orderPda,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    const clock = litesvm.getClock();
+    clock.unixTimestamp += BigInt(REVIEW_WINDOW_SECS) + 1n;
+    litesvm.setClock(clock);
+
+    await program.methods
+      .closeOrder()
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        order: orderPda,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    expect(await fetchOrderAcc(program, orderPda)).toBe(null);
+    expect(
+      await fetchReviewAcc(program, getReviewPda(orderPda))
+    ).not.toBe(null);
+  });
+
+  test('throws if expiry task is pending', async () => {
+    await program.methods
+      .updateConfig({
+        acceptedMints: null,
+        pausedOperations: null,
+        newAdmin: null,
+        orderFeeBps: null,
+        maxOrderFeeBps: null,
+        shopperCancelWindowSecs: null,
+        completionDelaySecs: null,
+        shippingDeadlineSecs: 86_400,
+        feeTiers: null,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const clock = litesvm.getClock();
+    clock.unixTimestamp += 60n;
+    litesvm.setClock(clock);
+
+    const timestamp = new BN(clock.unixTimestamp.toString());
+    const expiringOrderPda = getOrderPda(shopperPda, itemPda, timestamp);
+
+    await program.methods
+      .createOrder(1, timestamp, new BN(initShopperAtaBal), taskId)
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+        order: expiringOrderPda,
+        priceUpdateV2: USDC_PRICE_UPDATE_V2,
+        paymentMint,
+        tokenProgram,
+        tuktuk: TUKTUK_PROGRAM_ID,
+        taskQueue: taskQueuePda,
+        task: taskKey(taskQueuePda, taskId)[0],
+        taskQueueAuthority: taskQueueAuthorityKey(
+          taskQueuePda,
+          getConfigPda()
+        )[0],
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    await program.methods
+      .shopperCancelOrder()
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        order: expiringOrderPda,
+        item: itemPda,
+        paymentMint,
+        tokenProgram,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    try {
+      await program.methods
+        .closeOrder()
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          order: expiringOrderPda,
+        })
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'ExpiryTaskPending');
+    }
+  });
+});
@@ -8,19 +8,28 @@ import {
 import { Splurge } from '../../target/types/splurge';
 import { BN, Program } from '@coral-xyz/anchor';
 import {
+  getConfigPda,
   getItemPda,
   getOrderPda,
   getShopperPda,
   getStorePda,
   getTreasuryPda,
 } from '../pda';
-import { fetchOrderAcc, fetchTaskQueueAcc } from '../accounts';
+import {
+  fetchOrderAcc,
+  fetchStoreAcc,
+  fetchTaskQueueAcc,
+} from '../accounts';
 import { LiteSVM } from 'litesvm';
 import { LiteSVMProvider } from 'anchor-litesvm';
 import {
+  ORACLE_CONFIG,
   TUKTUK_PROGRAM_ID,
+  USDC_FEED_ID,
   USDC_MINT,
   USDC_PRICE_UPDATE_V2,
+  USDT_FEED_ID,
+  USDT_MINT,
 } from '../constants';
 import {
   expectAnchorError,
@@ -89,7 +98,9 @@ describe('completeOrder', () => {
         acceptedMints: [
           {
             mint: USDC_MINT,
-            priceUpdateV2: USDC_PRICE_UPDATE_V2,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
           },
         ],
         admin: admin.publicKey,
@@ -128,6 +139,8 @@ describe('completeOrder', () => {
     await program.methods
       .listItem({
         price: new BN(itemPrice),
+        pricingMode: { oracle: {} },
+        referralBps: 0,
         inventoryCount: initInventoryCount,
         name: itemName,
         image: 'https://example.com/item.png',
@@ -146,7 +159,7 @@ describe('completeOrder', () => {
     orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
 
     await program.methods
-      .createOrder(1, new BN(unixTimestamp))
+      .createOrder(1, new BN(unixTimestamp), new BN(initShopperAtaBal), null)
       .accountsPartial({
         authority: shopperAuthority.publicKey,
         store: storePda,
@@ -167,13 +180,13 @@ describe('completeOrder', () => {
     const [taskPda] = taskKey(taskQueuePda, taskId);
     const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
       taskQueuePda,
-      admin.publicKey
+      getConfigPda()
     );
 
     await program.methods
       .shipOrder(taskId)
       .accountsPartial({
-        admin: admin.publicKey,
+        shipper: admin.publicKey,
         order: orderPda,
         authority: shopperAuthority.publicKey,
         item: itemPda,
@@ -191,6 +204,67 @@ describe('completeOrder', () => {
       .rpc();
   });
 
+  async function createAndShipOrder(
+    referrer: PublicKey | null = null
+  ): Promise<PublicKey> {
+    const clock = litesvm.getClock();
+    clock.unixTimestamp += 60n;
+    litesvm.setClock(clock);
+
+    const timestamp = new BN(clock.unixTimestamp.toString());
+    const orderPda = getOrderPda(shopperPda, itemPda, timestamp);
+
+    await program.methods
+      .createOrder(1, timestamp, new BN(initShopperAtaBal), null)
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+        order: orderPda,
+        priceUpdateV2: USDC_PRICE_UPDATE_V2,
+        paymentMint,
+        referrer,
+        tokenProgram,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    const taskQueueAcc = await fetchTaskQueueAcc(tuktukProgram, taskQueuePda);
+    const taskId = nextAvailableTaskIds(taskQueueAcc.taskBitmap, 1, false)[0];
+    const [taskPda] = taskKey(taskQueuePda, taskId);
+    const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
+      taskQueuePda,
+      getConfigPda()
+    );
+
+    await program.methods
+      .shipOrder(taskId)
+      .accountsPartial({
+        shipper: admin.publicKey,
+        order: orderPda,
+        authority: shopperAuthority.publicKey,
+        item: itemPda,
+        orderTokenAccount: getAssociatedTokenAddressSync(
+          paymentMint,
+          orderPda,
+          true,
+          tokenProgram
+        ),
+        paymentMint,
+        shopper: shopperPda,
+        store: storePda,
+        task: taskPda,
+        taskQueue: taskQueuePda,
+        taskQueueAuthority: taskQueueAuthorityPda,
+        tokenProgram,
+        tuktuk: TUKTUK_PROGRAM_ID,
+      })
+      .signers([admin])
+      .rpc();
+
+    return orderPda;
+  }
+
   test('complete order', async () => {
     let orderAcc = await fetchOrderAcc(program, orderPda);
 
@@ -205,7 +279,7 @@ describe('completeOrder', () => {
     await program.methods
       .completeOrder()
       .accountsPartial({
-        admin: admin.publicKey,
+        operator: admin.publicKey,
         shopper: shopperPda,
         store: storePda,
         item: itemPda,
@@ -229,11 +303,351 @@ describe('completeOrder', () => {
 
     expect(storeUsdcAtaAcc.amount).toBe(orderAtaAcc.amount);
 
+    const storeAcc = await fetchStoreAcc(program, storePda);
+
+    expect(storeAcc.completedVolume.toNumber()).toBe(
+      orderAcc.paymentSubtotal.toNumber()
+    );
+
     const orderAtaRent = litesvm.getBalance(orderAta);
 
     expect(orderAtaRent).toBe(null);
   });
 
+  test('complete order with payout recipients', async () => {
+    const partner = Keypair.generate().publicKey;
+    const payoutRecipients = [
+      { recipient: storeAuthority.publicKey, shareBps: 7_000 },
+      { recipient: partner, shareBps: 3_000 },
+    ];
+
+    await program.methods
+      .setPayoutRecipients(payoutRecipients)
+      .accounts({
+        authority: storeAuthority.publicKey,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    const orderPda = await createAndShipOrder();
+    const payoutAtas = payoutRecipients.map(({ recipient }) => {
+      return getAssociatedTokenAddressSync(
+        paymentMint,
+        recipient,
+        false,
+        tokenProgram
+      );
+    });
+
+    const orderAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      orderPda,
+      true,
+      tokenProgram
+    );
+    const orderAtaAcc = await getAccount(provider.connection, orderAta);
+
+    await program.methods
+      .completeOrder()
+      .accountsPartial({
+        operator: admin.publicKey,
+        shopper: shopperPda,
+        store: storePda,
+        item: itemPda,
+        order: orderPda,
+        tokenProgram,
+      })
+      .remainingAccounts(
+        payoutRecipients.flatMap(({ recipient }, i) => [
+          { pubkey: recipient, isSigner: false, isWritable: false },
+          { pubkey: payoutAtas[i], isSigner: false, isWritable: true },
+        ])
+      )
+      .signers([admin])
+      .rpc();
+
+    const [storeAuthorityAtaAcc, partnerAtaAcc] = await Promise.all(
+      payoutAtas.map((ata) => getAccount(provider.connection, ata))
+    );
+    const storeAuthorityShare = (orderAtaAcc.amount * 7_000n) / 10_000n;
+
+    expect(storeAuthorityAtaAcc.amount).toBe(storeAuthorityShare);
+    expect(partnerAtaAcc.amount).toBe(orderAtaAcc.amount - storeAuthorityShare);
+  });
+
+  test('pays payout recipients snapshotted when shipped', async () => {
+    await program.methods
+      .setPayoutRecipients([
+        { recipient: storeAuthority.publicKey, shareBps: 10_000 },
+      ])
+      .accounts({
+        authority: storeAuthority.publicKey,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    const orderAtaAcc = await getAccount(provider.connection, orderAta);
+
+    await program.methods
+      .completeOrder()
+      .accountsPartial({
+        operator: admin.publicKey,
+        shopper: shopperPda,
+        store: storePda,
+        item: itemPda,
+        order: orderPda,
+        tokenProgram,
+      })
+      .signers([admin])
+      .rpc();
+
+    const storeUsdcAta = getAssociatedTokenAddressSync(
+      USDC_MINT,
+      storePda,
+      true,
+      tokenProgram
+    );
+    const storeUsdcAtaAcc = await getAccount(provider.connection, storeUsdcAta);
+
+    expect(storeUsdcAtaAcc.amount).toBe(orderAtaAcc.amount);
+  });
+
+  test('throws if payout token accounts are missing', async () => {
+    await program.methods
+      .setPayoutRecipients([
+        { recipient: storeAuthority.publicKey, shareBps: 10_000 },
+      ])
+      .accounts({
+        authority: storeAuthority.publicKey,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    const orderPda = await createAndShipOrder();
+
+    try {
+      await program.methods
+        .completeOrder()
+        .accountsPartial({
+          operator: admin.publicKey,
+          shopper: shopperPda,
+          store: storePda,
+          item: itemPda,
+          order: orderPda,
+          tokenProgram,
+        })
+        .signers([admin])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidPayoutTokenAccounts');
+    }
+  });
+
+  test('complete order with referrer', async () => {
+    await program.methods
+      .updateItem({
+        price: null,
+        pricingMode: null,
+        referralBps: 1_000,
+        inventoryCount: null,
+      })
+      .accountsPartial({
+        authority: storeAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    const referrer = Keypair.generate().publicKey;
+    const orderPda = await createAndShipOrder(referrer);
+
+    initAta(litesvm, paymentMint, referrer);
+    const referrerAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      referrer,
+      false,
+      tokenProgram
+    );
+    const orderAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      orderPda,
+      true,
+      tokenProgram
+    );
+    const orderAtaAcc = await getAccount(provider.connection, orderAta);
+    let orderAcc = await fetchOrderAcc(program, orderPda);
+    const referralFee = BigInt(orderAcc.referralFee.toString());
+
+    expect(referralFee).toBeGreaterThan(0n);
+
+    await program.methods
+      .completeOrder()
+      .accountsPartial({
+        operator: admin.publicKey,
+        shopper: shopperPda,
+        store: storePda,
+        item: itemPda,
+        order: orderPda,
+        referrer,
+        referrerTokenAccount: referrerAta,
+        tokenProgram,
+      })
+      .signers([admin])
+      .rpc();
+
+    orderAcc = await fetchOrderAcc(program, orderPda);
+
+    expect(orderAcc.status).toStrictEqual({ completed: {} });
+
+    const referrerAtaAcc = await getAccount(provider.connection, referrerAta);
+
+    expect(referrerAtaAcc.amount).toBe(referralFee);
+
+    const storeUsdcAta = getAssociatedTokenAddressSync(
+      USDC_MINT,
+      storePda,
+      true,
+      tokenProgram
+    );
+    const storeUsdcAtaAcc = await getAccount(provider.connection, storeUsdcAta);
+
+    expect(storeUsdcAtaAcc.amount).toBe(orderAtaAcc.amount - referralFee);
+  });
+
+  test('complete order with referrer without a token account', async () => {
+    await program.methods
+      .updateItem({
+        price: null,
+        pricingMode: null,
+        referralBps: 1_000,
+        inventoryCount: null,
+      })
+      .accountsPartial({
+        authority: storeAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    const referrer = Keypair.generate().publicKey;
+    const orderPda = await createAndShipOrder(referrer);
+
+    const referrerAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      referrer,
+      false,
+      tokenProgram
+    );
+    const orderAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      orderPda,
+      true,
+      tokenProgram
+    );
+    const orderAtaAcc = await getAccount(provider.connection, orderAta);
+    let orderAcc = await fetchOrderAcc(program, orderPda);
+    const referralFee = BigInt(orderAcc.referralFee.toString());
+
+    expect(referralFee).toBeGreaterThan(0n);
+
+    await program.methods
+      .completeOrder()
+      .accountsPartial({
+        operator: admin.publicKey,
+        shopper: shopperPda,
+        store: storePda,
+        item: itemPda,
+        order: orderPda,
+        referrer,
+        referrerTokenAccount: referrerAta,
+        tokenProgram,
+      })
+      .signers([admin])
+      .rpc();
+
+    orderAcc = await fetchOrderAcc(program, orderPda);
+
+    expect(orderAcc.status).toStrictEqual({ completed: {} });
+
+    const referrerAtaAcc = await getAccount(provider.connection, referrerAta);
+
+    expect(referrerAtaAcc.amount).toBe(referralFee);
+
+    const storeUsdcAta = getAssociatedTokenAddressSync(
+      USDC_MINT,
+      storePda,
+      true,
+      tokenProgram
+    );
+    const storeUsdcAtaAcc = await getAccount(provider.connection, storeUsdcAta);
+
+    expect(storeUsdcAtaAcc.amount).toBe(orderAtaAcc.amount - referralFee);
+  });
+
+  test('complete order in a delisted mint', async () => {
+    await program.methods
+      .updateConfig({
+        acceptedMints: [
+          {
+            mint: USDT_MINT,
+            feedId: USDT_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+          },
+        ],
+        pausedOperations: null,
+        newAdmin: null,
+        orderFeeBps: null,
+        maxOrderFeeBps: null,
+        shopperCancelWindowSecs: null,
+        completionDelaySecs: null,
+        shippingDeadlineSecs: null,
+        feeTiers: null,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const orderAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      orderPda,
+      true,
+      tokenProgram
+    );
+    const orderAtaAcc = await getAccount(provider.connection, orderAta);
+
+    await program.methods
+      .completeOrder()
+      .accountsPartial({
+        operator: admin.publicKey,
+        shopper: shopperPda,
+        store: storePda,
+        item: itemPda,
+        order: orderPda,
+        tokenProgram,
+      })
+      .signers([admin])
+      .rpc();
+
+    const orderAcc = await fetchOrderAcc(program, orderPda);
+
+    expect(orderAcc.status).toStrictEqual({ completed: {} });
+
+    const storeUsdcAta = getAssociatedTokenAddressSync(
+      USDC_MINT,
+      storePda,
+      true,
+      tokenProgram
+    );
+    const storeUsdcAtaAcc = await getAccount(provider.connection, storeUsdcAta);
+
+    expect(storeUsdcAtaAcc.amount).toBe(orderAtaAcc.amount);
+  });
+
   test('throws if order status is not shipping', async () => {
     const clock = litesvm.getClock();
     const newTimestamp = clock.unixTimestamp + 60n;
@@ -243,7 +657,7 @@ describe('completeOrder', () => {
     const orderPda = getOrderPda(shopperPda, itemPda, new BN(newTimestamp));
 
     await program.methods
-      .createOrder(1, new BN(newTimestamp))
+      .createOrder(1, new BN(newTimestamp), new BN(initShopperAtaBal), null)
       .accountsPartial({
         authority: shopperAuthority.publicKey,
         store: storePda,
@@ -260,7 +674,7 @@ describe('completeOrder', () => {
       await program.methods
         .completeOrder()
         .accountsPartial({
-          admin: admin.publicKey,
+          operator: admin.publicKey,
           shopper: shopperPda,
           store: storePda,
           item: itemPda,
@@ -278,7 +692,7 @@ describe('completeOrder', () => {
     await program.methods
       .completeOrder()
       .accountsPartial({
-        admin: admin.publicKey,
+        operator: admin.publicKey,
         shopper: shopperPda,
         store: storePda,
         item: itemPda,
@@ -292,7 +706,7 @@ describe('completeOrder', () => {
       await program.methods
         .completeOrder()
         .accountsPartial({
-          admin: admin.publicKey,
+          operator: admin.publicKey,
           shopper: shopperPda,
           store: storePda,
           item: itemPda,
@@ -304,12 +718,12 @@ describe('completeOrder', () => {
     }).toThrow();
   });
 
-  test('throws if signed by unauthorized admin', async () => {
+  test('throws if signed by unauthorized operator', async () => {
     try {
       await program.methods
         .completeOrder()
         .accountsPartial({
-          admin: shopperAuthority.publicKey,
+          operator: shopperAuthority.publicKey,
           shopper: shopperPda,
           store: storePda,
           item: itemPda,
@@ -319,7 +733,7 @@ describe('completeOrder', () => {
         .signers([shopperAuthority])
         .rpc();
     } catch (err) {
-      expectAnchorError(err, 'UnauthorizedAdmin');
+      expectAnchorError(err, 'UnauthorizedOperator');
     }
   });
 });
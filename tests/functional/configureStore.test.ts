@@ -0,0 +1,176 @@
+import { beforeEach, describe, expect, test } from 'bun:test';
+import { Keypair } from '@solana/web3.js';
+import { Splurge } from '../../target/types/splurge';
+import { Program } from '@coral-xyz/anchor';
+import { fetchStoreAcc } from '../accounts';
+import { LiteSVM } from 'litesvm';
+import { LiteSVMProvider } from 'anchor-litesvm';
+import { expectAnchorError, fundedSystemAccountInfo, getSetup } from '../setup';
+import {
+  ORACLE_CONFIG,
+  ORDER_FEE_TIMELOCK_SECS,
+  USDC_FEED_ID,
+  USDC_MINT,
+} from '../constants';
+import { getStorePda } from '../pda';
+
+describe('configureStore', () => {
+  let { litesvm, provider, program } = {} as {
+    litesvm: LiteSVM;
+    provider: LiteSVMProvider;
+    program: Program<Splurge>;
+  };
+
+  const [admin, storeAuthority] = Array.from({ length: 2 }, Keypair.generate);
+
+  const storePda = getStorePda(storeAuthority.publicKey);
+
+  beforeEach(async () => {
+    ({ litesvm, provider, program } = await getSetup([
+      ...[admin, storeAuthority].map((kp) => {
+        return {
+          pubkey: kp.publicKey,
+          account: fundedSystemAccountInfo(),
+        };
+      }),
+    ]));
+
+    await program.methods
+      .initializeConfig({
+        acceptedMints: [
+          {
+            mint: USDC_MINT,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+          },
+        ],
+        admin: admin.publicKey,
+        orderFeeBps: 250,
+      })
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .initializeStore({
+        name: 'Store A',
+        image: 'https://example.com/image.png',
+        about: 'about',
+      })
+      .accounts({
+        authority: storeAuthority.publicKey,
+      })
+      .signers([storeAuthority])
+      .rpc();
+  });
+
+  test('configures a store', async () => {
+    const completionDelaySecs = 604800;
+    const orderFeeBps = 100;
+
+    await program.methods
+      .configureStore({
+        completionDelaySecs,
+        orderFeeBps,
+        isSuspended: true,
+      })
+      .accountsPartial({
+        admin: admin.publicKey,
+        store: storePda,
+      })
+      .signers([admin])
+      .rpc();
+
+    const storeAcc = await fetchStoreAcc(program, storePda);
+
+    expect(storeAcc.completionDelaySecs).toBe(completionDelaySecs);
+    expect(storeAcc.orderFeeBps).toBe(orderFeeBps);
+    expect(storeAcc.isSuspended).toBe(true);
+  });
+
+  test('stages order fee override increase behind timelock', async () => {
+    const orderFeeBps = 500;
+
+    await program.methods
+      .configureStore({
+        completionDelaySecs: null,
+        orderFeeBps,
+        isSuspended: false,
+      })
+      .accountsPartial({
+        admin: admin.publicKey,
+        store: storePda,
+      })
+      .signers([admin])
+      .rpc();
+
+    const storeAcc = await fetchStoreAcc(program, storePda);
+    const { unixTimestamp } = litesvm.getClock();
+
+    expect(storeAcc.orderFeeBps).toBeNull();
+    expect(storeAcc.pendingOrderFee.orderFeeBps).toBe(orderFeeBps);
+    expect(storeAcc.pendingOrderFee.effectiveTimestamp.toNumber()).toBe(
+      Number(unixTimestamp) + ORDER_FEE_TIMELOCK_SECS
+    );
+  });
+
+  test('throws if completion delay is out of bounds', async () => {
+    try {
+      await program.methods
+        .configureStore({
+          completionDelaySecs: 1,
+          orderFeeBps: null,
+          isSuspended: false,
+        })
+        .accountsPartial({
+          admin: admin.publicKey,
+          store: storePda,
+        })
+        .signers([admin])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidCompletionDelay');
+    }
+  });
+
+  test('throws if order fee exceeds maximum', async () => {
+    try {
+      await program.methods
+        .configureStore({
+          completionDelaySecs: null,
+          orderFeeBps: 10001,
+          isSuspended: false,
+        })
+        .accountsPartial({
+          admin: admin.publicKey,
+          store: storePda,
+        })
+        .signers([admin])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidFeeBps');
+    }
+  });
+
+  test('throws if configuring as unauthorized admin', async () => {
+    try {
+      await program.methods
+        .configureStore({
+          completionDelaySecs: null,
+          orderFeeBps: null,
+          isSuspended: false,
+        })
+        .accountsPartial({
+          admin: storeAuthority.publicKey,
+          store: storePda,
+        })
+        .signers([storeAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'UnauthorizedAdmin');
+    }
+  });
+});
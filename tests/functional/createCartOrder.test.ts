@@ -0,0 +1,382 @@
+import { beforeEach, describe, expect, test } from 'bun:test';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  getAccount,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
+import { Splurge } from '../../target/types/splurge';
+import { BN, Program } from '@coral-xyz/anchor';
+import {
+  getCartOrderPda,
+  getItemPda,
+  getShopperPda,
+  getStorePda,
+  getTreasuryPda,
+} from '../pda';
+import { fetchCartOrderAcc, fetchItemAcc } from '../accounts';
+import { LiteSVM } from 'litesvm';
+import { LiteSVMProvider } from 'anchor-litesvm';
+import {
+  ORACLE_CONFIG,
+  USDC_FEED_ID,
+  USDC_MINT,
+  USDC_PRICE_UPDATE_V2,
+} from '../constants';
+import {
+  expectAnchorError,
+  fundedSystemAccountInfo,
+  getSetup,
+  initAta,
+} from '../setup';
+
+describe('createCartOrder', () => {
+  let { litesvm, provider, program } = {} as {
+    litesvm: LiteSVM;
+    provider: LiteSVMProvider;
+    program: Program<Splurge>;
+  };
+
+  const [admin, shopperAuthority, storeAuthorityA, storeAuthorityB] =
+    Array.from({ length: 4 }, Keypair.generate);
+  const treasury = getTreasuryPda();
+
+  const itemName = 'Item A';
+  const itemPrice = 1e6; // $1
+  const initInventoryCount = 10;
+  const initShopperAtaBal = 1e8; // $100
+  const paymentMint = USDC_MINT;
+  const tokenProgram = TOKEN_PROGRAM_ID;
+
+  const shopperPda = getShopperPda(shopperAuthority.publicKey);
+  const storePdaA = getStorePda(storeAuthorityA.publicKey);
+  const storePdaB = getStorePda(storeAuthorityB.publicKey);
+  const itemPdaA = getItemPda(storePdaA, itemName);
+  const itemPdaB = getItemPda(storePdaB, itemName);
+
+  beforeEach(async () => {
+    ({ litesvm, provider, program } = await getSetup([
+      ...[admin, shopperAuthority, storeAuthorityA, storeAuthorityB].map(
+        (kp) => {
+          return {
+            pubkey: kp.publicKey,
+            account: fundedSystemAccountInfo(),
+          };
+        }
+      ),
+    ]));
+
+    initAta(litesvm, USDC_MINT, treasury);
+    initAta(litesvm, USDC_MINT, shopperAuthority.publicKey, initShopperAtaBal);
+
+    await program.methods
+      .initializeConfig({
+        acceptedMints: [
+          {
+            mint: USDC_MINT,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+          },
+        ],
+        admin: admin.publicKey,
+        orderFeeBps: 250,
+      })
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .initializeShopper({
+        name: 'Shopper A',
+        image: 'https://example.com/image.png',
+        address: 'address',
+      })
+      .accounts({
+        authority: shopperAuthority.publicKey,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    for (const storeAuthority of [storeAuthorityA, storeAuthorityB]) {
+      await program.methods
+        .initializeStore({
+          name: 'Store',
+          image: 'https://example.com/image.png',
+          about: 'about',
+        })
+        .accounts({
+          authority: storeAuthority.publicKey,
+        })
+        .signers([storeAuthority])
+        .rpc();
+
+      await program.methods
+        .listItem({
+          price: new BN(itemPrice),
+          pricingMode: { oracle: {} },
+          referralBps: 0,
+          inventoryCount: initInventoryCount,
+          name: itemName,
+          image: 'https://example.com/item.png',
+          description: 'description',
+        })
+        .accounts({
+          authority: storeAuthority.publicKey,
+        })
+        .signers([storeAuthority])
+        .rpc();
+    }
+  });
+
+  test('creates a cart order', async () => {
+    const amounts = [2, 3];
+    const { unixTimestamp } = litesvm.getClock();
+    const cartOrderPda = getCartOrderPda(shopperPda, new BN(unixTimestamp));
+
+    await program.methods
+      .createCartOrder(
+        amounts,
+        new BN(unixTimestamp),
+        new BN(initShopperAtaBal)
+      )
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        cartOrder: cartOrderPda,
+        priceUpdateV2: USDC_PRICE_UPDATE_V2,
+        paymentMint,
+        tokenProgram,
+      })
+      .remainingAccounts(
+        [
+          [itemPdaA, storePdaA],
+          [itemPdaB, storePdaB],
+        ].flatMap(([itemPda, storePda]) => [
+          { pubkey: itemPda, isSigner: false, isWritable: true },
+          { pubkey: storePda, isSigner: false, isWritable: false },
+        ])
+      )
+      .signers([shopperAuthority])
+      .rpc();
+
+    const cartOrderAcc = await fetchCartOrderAcc(program, cartOrderPda);
+
+    expect(cartOrderAcc.shopper).toStrictEqual(shopperPda);
+    expect(cartOrderAcc.status).toStrictEqual({ pending: {} });
+    expect(cartOrderAcc.lineItems.length).toBe(amounts.length);
+    expect(cartOrderAcc.lineItems[0].store).toStrictEqual(storePdaA);
+    expect(cartOrderAcc.lineItems[1].store).toStrictEqual(storePdaB);
+    expect(cartOrderAcc.paymentSubtotal.toNumber()).toBe(
+      cartOrderAcc.lineItems.reduce(
+        (acc, { paymentSubtotal }) => acc + paymentSubtotal.toNumber(),
+        0
+      )
+    );
+    expect(cartOrderAcc.platformFee.toNumber()).toBe(
+      cartOrderAcc.lineItems.reduce(
+        (acc, { platformFee }) => acc + platformFee.toNumber(),
+        0
+      )
+    );
+    expect(cartOrderAcc.lineItems[0].orderFeeBps).toBe(250);
+
+    const cartOrderAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      cartOrderPda,
+      true,
+      tokenProgram
+    );
+    const cartOrderAtaBal = (
+      await getAccount(provider.connection, cartOrderAta)
+    ).amount;
+
+    expect(Number(cartOrderAtaBal)).toBe(
+      cartOrderAcc.paymentSubtotal.toNumber()
+    );
+
+    const itemAccA = await fetchItemAcc(program, itemPdaA);
+    const itemAccB = await fetchItemAcc(program, itemPdaB);
+
+    expect(itemAccA.inventoryCount).toBe(initInventoryCount - amounts[0]);
+    expect(itemAccB.inventoryCount).toBe(initInventoryCount - amounts[1]);
+  });
+
+  test('throws if cart contains duplicate items', async () => {
+    const { unixTimestamp } = litesvm.getClock();
+    const cartOrderPda = getCartOrderPda(shopperPda, new BN(unixTimestamp));
+
+    try {
+      await program.methods
+        .createCartOrder(
+          [1, 1],
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal)
+        )
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          cartOrder: cartOrderPda,
+          priceUpdateV2: USDC_PRICE_UPDATE_V2,
+          paymentMint,
+          tokenProgram,
+        })
+        .remainingAccounts(
+          [
+            [itemPdaA, storePdaA],
+            [itemPdaA, storePdaA],
+          ].flatMap(([itemPda, storePda]) => [
+            { pubkey: itemPda, isSigner: false, isWritable: true },
+            { pubkey: storePda, isSigner: false, isWritable: false },
+          ])
+        )
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'DuplicateCartItem');
+    }
+  });
+
+  test('throws if store does not match item', async () => {
+    const { unixTimestamp } = litesvm.getClock();
+    const cartOrderPda = getCartOrderPda(shopperPda, new BN(unixTimestamp));
+
+    try {
+      await program.methods
+        .createCartOrder(
+          [1, 1],
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal)
+        )
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          cartOrder: cartOrderPda,
+          priceUpdateV2: USDC_PRICE_UPDATE_V2,
+          paymentMint,
+          tokenProgram,
+        })
+        .remainingAccounts(
+          [
+            [itemPdaA, storePdaB],
+            [itemPdaB, storePdaA],
+          ].flatMap(([itemPda, storePda]) => [
+            { pubkey: itemPda, isSigner: false, isWritable: true },
+            { pubkey: storePda, isSigner: false, isWritable: false },
+          ])
+        )
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidCartStore');
+    }
+  });
+
+  test('throws if a store is suspended', async () => {
+    await program.methods
+      .configureStore({
+        completionDelaySecs: null,
+        orderFeeBps: null,
+        isSuspended: true,
+      })
+      .accountsPartial({
+        admin: admin.publicKey,
+        store: storePdaB,
+      })
+      .signers([admin])
+      .rpc();
+
+    const { unixTimestamp } = litesvm.getClock();
+    const cartOrderPda = getCartOrderPda(shopperPda, new BN(unixTimestamp));
+
+    try {
+      await program.methods
+        .createCartOrder(
+          [1, 1],
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal)
+        )
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          cartOrder: cartOrderPda,
+          priceUpdateV2: USDC_PRICE_UPDATE_V2,
+          paymentMint,
+          tokenProgram,
+        })
+        .remainingAccounts(
+          [
+            [itemPdaA, storePdaA],
+            [itemPdaB, storePdaB],
+          ].flatMap(([itemPda, storePda]) => [
+            { pubkey: itemPda, isSigner: false, isWritable: true },
+            { pubkey: storePda, isSigner: false, isWritable: false },
+          ])
+        )
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'StoreSuspended');
+    }
+  });
+
+  test('throws if item has insufficient inventory', async () => {
+    const { unixTimestamp } = litesvm.getClock();
+    const cartOrderPda = getCartOrderPda(shopperPda, new BN(unixTimestamp));
+
+    try {
+      await program.methods
+        .createCartOrder(
+          [1, initInventoryCount + 1],
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal)
+        )
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          cartOrder: cartOrderPda,
+          priceUpdateV2: USDC_PRICE_UPDATE_V2,
+          paymentMint,
+          tokenProgram,
+        })
+        .remainingAccounts(
+          [
+            [itemPdaA, storePdaA],
+            [itemPdaB, storePdaB],
+          ].flatMap(([itemPda, storePda]) => [
+            { pubkey: itemPda, isSigner: false, isWritable: true },
+            { pubkey: storePda, isSigner: false, isWritable: false },
+          ])
+        )
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InsufficientInventory');
+    }
+  });
+
+  test('throws if amounts do not match items', async () => {
+    const { unixTimestamp } = litesvm.getClock();
+    const cartOrderPda = getCartOrderPda(shopperPda, new BN(unixTimestamp));
+
+    try {
+      await program.methods
+        .createCartOrder(
+          [1, 1],
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal)
+        )
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          cartOrder: cartOrderPda,
+          priceUpdateV2: USDC_PRICE_UPDATE_V2,
+          paymentMint,
+          tokenProgram,
+        })
+        .remainingAccounts([
+          { pubkey: itemPdaA, isSigner: false, isWritable: true },
+        ])
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'CartAccountsMismatch');
+    }
+  });
+});
@@ -21,8 +21,14 @@ import { LiteSVM } from 'litesvm';
 import { LiteSVMProvider } from 'anchor-litesvm';
 import {
   MINT_DECIMALS,
+  ORACLE_CONFIG,
+  ORDER_FEE_TIMELOCK_SECS,
+  PAUSE_ORDERING,
+  USDC_FEED_ID,
   USDC_MINT,
+  USDC_PARTIAL_PRICE_UPDATE_V2,
   USDC_PRICE_UPDATE_V2,
+  USDT_FEED_ID,
   USDT_MINT,
   USDT_PRICE_UPDATE_V2,
 } from '../constants';
@@ -80,7 +86,9 @@ describe('createOrder', () => {
         acceptedMints: [
           {
             mint: USDC_MINT,
-            priceUpdateV2: USDC_PRICE_UPDATE_V2,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
           },
         ],
         admin: admin.publicKey,
@@ -119,6 +127,8 @@ describe('createOrder', () => {
     await program.methods
       .listItem({
         price: new BN(itemPrice),
+        pricingMode: { oracle: {} },
+        referralBps: 0,
         inventoryCount: initInventoryCount,
         name: itemName,
         image: 'https://example.com/item.png',
@@ -152,7 +162,12 @@ describe('createOrder', () => {
     const orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
 
     await program.methods
-      .createOrder(amount, new BN(unixTimestamp))
+      .createOrder(
+        amount,
+        new BN(unixTimestamp),
+        new BN(initShopperAtaBal),
+        null
+      )
       .accountsPartial({
         authority: shopperAuthority.publicKey,
         store: storePda,
@@ -199,6 +214,8 @@ describe('createOrder', () => {
     const configPda = getConfigPda();
     const { orderFeeBps } = await fetchConfigAcc(program, configPda);
 
+    expect(orderAcc.orderFeeBps).toBe(orderFeeBps);
+
     const postTreasuryAtaBal = (
       await getAccount(provider.connection, treasuryAta)
     ).amount;
@@ -215,6 +232,379 @@ describe('createOrder', () => {
     expect(initInventoryCount).toBe(itemAcc.inventoryCount + amount);
   });
 
+  test('charges fixed-price item without oracle', async () => {
+    const amount = 2;
+    const paymentMint = USDC_MINT;
+
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+    const shopperPda = getShopperPda(shopperAuthority.publicKey);
+    const { unixTimestamp } = litesvm.getClock();
+    const orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+    await program.methods
+      .updateItem({
+        price: new BN(itemPrice),
+        pricingMode: { fixed: { priceMint: paymentMint } },
+        referralBps: null,
+        inventoryCount: null,
+      })
+      .accountsPartial({
+        authority: storeAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    await program.methods
+      .createOrder(
+        amount,
+        new BN(unixTimestamp),
+        new BN(initShopperAtaBal),
+        null
+      )
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+        order: orderPda,
+        priceUpdateV2: null,
+        paymentMint,
+        tokenProgram,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    const orderAcc = await fetchOrderAcc(program, orderPda);
+
+    expect(orderAcc.paymentSubtotal.toNumber()).toBe(itemPrice * amount);
+  });
+
+  test('throws if fixed-price item is paid in another mint', async () => {
+    const amount = 1;
+    const paymentMint = USDC_MINT;
+
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+    const shopperPda = getShopperPda(shopperAuthority.publicKey);
+    const { unixTimestamp } = litesvm.getClock();
+    const orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+    await program.methods
+      .updateConfig({
+        acceptedMints: [
+          {
+            mint: USDC_MINT,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+          },
+          {
+            mint: USDT_MINT,
+            feedId: USDT_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+          },
+        ],
+        pausedOperations: null,
+        newAdmin: null,
+        orderFeeBps: null,
+        maxOrderFeeBps: null,
+        shopperCancelWindowSecs: null,
+        completionDelaySecs: null,
+        shippingDeadlineSecs: null,
+        feeTiers: null,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .updateItem({
+        price: new BN(itemPrice),
+        pricingMode: { fixed: { priceMint: USDT_MINT } },
+        referralBps: null,
+        inventoryCount: null,
+      })
+      .accountsPartial({
+        authority: storeAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    try {
+      await program.methods
+        .createOrder(
+          amount,
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal),
+          null
+        )
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          store: storePda,
+          item: itemPda,
+          order: orderPda,
+          priceUpdateV2: USDC_PRICE_UPDATE_V2,
+          paymentMint,
+          tokenProgram,
+        })
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'FixedPriceMintMismatch');
+    }
+  });
+
+  test('records referral fee for referred order', async () => {
+    const amount = 2;
+    const referralBps = 1_000;
+    const paymentMint = USDC_MINT;
+    const referrer = Keypair.generate().publicKey;
+
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+    const shopperPda = getShopperPda(shopperAuthority.publicKey);
+    const { unixTimestamp } = litesvm.getClock();
+    const orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+    await program.methods
+      .updateItem({
+        price: new BN(itemPrice),
+        pricingMode: { fixed: { priceMint: paymentMint } },
+        referralBps,
+        inventoryCount: null,
+      })
+      .accountsPartial({
+        authority: storeAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    await program.methods
+      .createOrder(
+        amount,
+        new BN(unixTimestamp),
+        new BN(initShopperAtaBal),
+        null
+      )
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+        order: orderPda,
+        priceUpdateV2: null,
+        paymentMint,
+        referrer,
+        tokenProgram,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    const orderAcc = await fetchOrderAcc(program, orderPda);
+
+    expect(orderAcc.referrer).toStrictEqual(referrer);
+    expect(orderAcc.referralFee.toNumber()).toBe(
+      (itemPrice * amount * referralBps) / MAX_FEE_BASIS_POINTS
+    );
+  });
+
+  test('throws if shopper refers themselves', async () => {
+    const amount = 1;
+    const paymentMint = USDC_MINT;
+
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+    const shopperPda = getShopperPda(shopperAuthority.publicKey);
+    const { unixTimestamp } = litesvm.getClock();
+    const orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+    try {
+      await program.methods
+        .createOrder(
+          amount,
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal),
+          null
+        )
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          store: storePda,
+          item: itemPda,
+          order: orderPda,
+          priceUpdateV2: USDC_PRICE_UPDATE_V2,
+          paymentMint,
+          referrer: shopperAuthority.publicKey,
+          tokenProgram,
+        })
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'SelfReferral');
+    }
+  });
+
+  test('charges store fee override', async () => {
+    const amount = 1;
+    const paymentMint = USDC_MINT;
+    const orderFeeBps = 100;
+
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+    const shopperPda = getShopperPda(shopperAuthority.publicKey);
+    const { unixTimestamp } = litesvm.getClock();
+    const orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+    await program.methods
+      .configureStore({
+        completionDelaySecs: null,
+        orderFeeBps,
+        isSuspended: false,
+      })
+      .accountsPartial({
+        admin: admin.publicKey,
+        store: storePda,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .createOrder(
+        amount,
+        new BN(unixTimestamp),
+        new BN(initShopperAtaBal),
+        null
+      )
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+        order: orderPda,
+        priceUpdateV2: USDC_PRICE_UPDATE_V2,
+        paymentMint,
+        tokenProgram,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    const orderAcc = await fetchOrderAcc(program, orderPda);
+
+    expect(orderAcc.orderFeeBps).toBe(orderFeeBps);
+    expect(orderAcc.platformFee.toNumber()).toBe(
+      Math.ceil(
+        (orderAcc.paymentSubtotal.toNumber() * orderFeeBps) /
+          MAX_FEE_BASIS_POINTS
+      )
+    );
+  });
+
+  test('applies staged fee increase after timelock', async () => {
+    const amount = 1;
+    const paymentMint = USDC_MINT;
+    const orderFeeBps = 500;
+
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+    const shopperPda = getShopperPda(shopperAuthority.publicKey);
+
+    await program.methods
+      .updateConfig({
+        acceptedMints: null,
+        pausedOperations: null,
+        newAdmin: null,
+        orderFeeBps,
+        maxOrderFeeBps: null,
+        shopperCancelWindowSecs: null,
+        completionDelaySecs: null,
+        shippingDeadlineSecs: null,
+        feeTiers: null,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const createOrder = async () => {
+      const { unixTimestamp } = litesvm.getClock();
+      const orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+      await program.methods
+        .createOrder(
+          amount,
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal),
+          null
+        )
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          store: storePda,
+          item: itemPda,
+          order: orderPda,
+          priceUpdateV2: USDC_PRICE_UPDATE_V2,
+          paymentMint,
+          tokenProgram,
+        })
+        .signers([shopperAuthority])
+        .rpc();
+
+      return fetchOrderAcc(program, orderPda);
+    };
+
+    const orderAcc = await createOrder();
+
+    expect(orderAcc.orderFeeBps).toBe(250);
+
+    const clock = litesvm.getClock();
+    clock.unixTimestamp += BigInt(ORDER_FEE_TIMELOCK_SECS);
+    litesvm.setClock(clock);
+
+    const postTimelockOrderAcc = await createOrder();
+
+    expect(postTimelockOrderAcc.orderFeeBps).toBe(orderFeeBps);
+  });
+
+  test('throws if oracle-priced item has no price update', async () => {
+    const amount = 1;
+    const paymentMint = USDC_MINT;
+
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+    const shopperPda = getShopperPda(shopperAuthority.publicKey);
+    const { unixTimestamp } = litesvm.getClock();
+    const orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+    try {
+      await program.methods
+        .createOrder(
+          amount,
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal),
+          null
+        )
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          store: storePda,
+          item: itemPda,
+          order: orderPda,
+          priceUpdateV2: null,
+          paymentMint,
+          tokenProgram,
+        })
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'MissingPriceUpdate');
+    }
+  });
+
   test('throws if payment mint is not accepted', async () => {
     const amount = 1;
     const paymentMint = USDT_MINT;
@@ -227,7 +617,12 @@ describe('createOrder', () => {
 
     try {
       await program.methods
-        .createOrder(amount, new BN(unixTimestamp))
+        .createOrder(
+          amount,
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal),
+          null
+        )
         .accountsPartial({
           authority: shopperAuthority.publicKey,
           store: storePda,
@@ -244,16 +639,238 @@ describe('createOrder', () => {
     }
   });
 
-  test('throws if platform is locked', async () => {
+  test('throws if oracle price is outside accepted band', async () => {
+    await program.methods
+      .updateConfig({
+        acceptedMints: [
+          {
+            mint: USDC_MINT,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+            maxPrice: new BN(990_000),
+          },
+        ],
+        pausedOperations: null,
+        newAdmin: null,
+        orderFeeBps: null,
+        maxOrderFeeBps: null,
+        shopperCancelWindowSecs: null,
+        completionDelaySecs: null,
+        shippingDeadlineSecs: null,
+        feeTiers: null,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const amount = 1;
+    const paymentMint = USDC_MINT;
+
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+    const shopperPda = getShopperPda(shopperAuthority.publicKey);
+    const { unixTimestamp } = litesvm.getClock();
+    const orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+    try {
+      await program.methods
+        .createOrder(
+          amount,
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal),
+          null
+        )
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          store: storePda,
+          item: itemPda,
+          order: orderPda,
+          priceUpdateV2: USDC_PRICE_UPDATE_V2,
+          paymentMint,
+          tokenProgram,
+        })
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'PriceAboveMaximum');
+    }
+  });
+
+  test('throws if oracle confidence is too wide', async () => {
+    await program.methods
+      .updateConfig({
+        acceptedMints: [
+          {
+            mint: USDC_MINT,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+            maxConfBps: 1,
+          },
+        ],
+        pausedOperations: null,
+        newAdmin: null,
+        orderFeeBps: null,
+        maxOrderFeeBps: null,
+        shopperCancelWindowSecs: null,
+        completionDelaySecs: null,
+        shippingDeadlineSecs: null,
+        feeTiers: null,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const amount = 1;
+    const paymentMint = USDC_MINT;
+
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+    const shopperPda = getShopperPda(shopperAuthority.publicKey);
+    const { unixTimestamp } = litesvm.getClock();
+    const orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+    try {
+      await program.methods
+        .createOrder(
+          amount,
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal),
+          null
+        )
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          store: storePda,
+          item: itemPda,
+          order: orderPda,
+          priceUpdateV2: USDC_PRICE_UPDATE_V2,
+          paymentMint,
+          tokenProgram,
+        })
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'PriceConfidenceTooWide');
+    }
+  });
+
+  test('throws if payment exceeds max payment', async () => {
+    const amount = 1;
+    const paymentMint = USDC_MINT;
+
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+    const shopperPda = getShopperPda(shopperAuthority.publicKey);
+    const { unixTimestamp } = litesvm.getClock();
+    const orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+    try {
+      await program.methods
+        .createOrder(amount, new BN(unixTimestamp), new BN(itemPrice), null)
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          store: storePda,
+          item: itemPda,
+          order: orderPda,
+          priceUpdateV2: USDC_PRICE_UPDATE_V2,
+          paymentMint,
+          tokenProgram,
+        })
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'SlippageExceeded');
+    }
+  });
+
+  test('throws if price update feed does not match mint', async () => {
+    const amount = 1;
+    const paymentMint = USDC_MINT;
+
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+    const shopperPda = getShopperPda(shopperAuthority.publicKey);
+    const { unixTimestamp } = litesvm.getClock();
+    const orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+    try {
+      await program.methods
+        .createOrder(
+          amount,
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal),
+          null
+        )
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          store: storePda,
+          item: itemPda,
+          order: orderPda,
+          priceUpdateV2: USDT_PRICE_UPDATE_V2,
+          paymentMint,
+          tokenProgram,
+        })
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidPriceFeedId');
+    }
+  });
+
+  test('throws if price update is not fully verified', async () => {
+    const amount = 1;
+    const paymentMint = USDC_MINT;
+
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+    const shopperPda = getShopperPda(shopperAuthority.publicKey);
+    const { unixTimestamp } = litesvm.getClock();
+    const orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+    try {
+      await program.methods
+        .createOrder(
+          amount,
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal),
+          null
+        )
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          store: storePda,
+          item: itemPda,
+          order: orderPda,
+          priceUpdateV2: USDC_PARTIAL_PRICE_UPDATE_V2,
+          paymentMint,
+          tokenProgram,
+        })
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InsufficientVerificationLevel');
+    }
+  });
+
+  test('throws if ordering is paused', async () => {
     const configPda = getConfigPda();
     const configAcc = await fetchConfigAcc(program, configPda);
 
     await program.methods
       .updateConfig({
         acceptedMints: null,
-        isPaused: true,
+        pausedOperations: PAUSE_ORDERING,
         newAdmin: null,
         orderFeeBps: null,
+        maxOrderFeeBps: null,
+        shopperCancelWindowSecs: null,
+        completionDelaySecs: null,
+        shippingDeadlineSecs: null,
+        feeTiers: null,
       })
       .accounts({
         admin: admin.publicKey,
@@ -272,7 +889,102 @@ describe('createOrder', () => {
 
     try {
       await program.methods
-        .createOrder(amount, new BN(unixTimestamp))
+        .createOrder(
+          amount,
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal),
+          null
+        )
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          store: storePda,
+          item: itemPda,
+          order: orderPda,
+          priceUpdateV2: USDC_PRICE_UPDATE_V2,
+          paymentMint,
+          tokenProgram,
+        })
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'OrderingPaused');
+    }
+  });
+
+  test('throws if payment mint is paused', async () => {
+    await program.methods
+      .setMintPaused(USDC_MINT, true)
+      .accounts({
+        pauser: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const amount = 1;
+    const paymentMint = USDC_MINT;
+
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+    const shopperPda = getShopperPda(shopperAuthority.publicKey);
+    const { unixTimestamp } = litesvm.getClock();
+    const orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+    try {
+      await program.methods
+        .createOrder(
+          amount,
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal),
+          null
+        )
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          store: storePda,
+          item: itemPda,
+          order: orderPda,
+          priceUpdateV2: USDC_PRICE_UPDATE_V2,
+          paymentMint,
+          tokenProgram,
+        })
+        .signers([shopperAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'PaymentMintPaused');
+    }
+  });
+
+  test('throws if store is suspended', async () => {
+    const storePda = getStorePda(storeAuthority.publicKey);
+
+    await program.methods
+      .configureStore({
+        completionDelaySecs: null,
+        orderFeeBps: null,
+        isSuspended: true,
+      })
+      .accountsPartial({
+        admin: admin.publicKey,
+        store: storePda,
+      })
+      .signers([admin])
+      .rpc();
+
+    const amount = 1;
+    const paymentMint = USDC_MINT;
+
+    const itemPda = getItemPda(storePda, itemName);
+    const shopperPda = getShopperPda(shopperAuthority.publicKey);
+    const { unixTimestamp } = litesvm.getClock();
+    const orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+    try {
+      await program.methods
+        .createOrder(
+          amount,
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal),
+          null
+        )
         .accountsPartial({
           authority: shopperAuthority.publicKey,
           store: storePda,
@@ -285,7 +997,7 @@ describe('createOrder', () => {
         .signers([shopperAuthority])
         .rpc();
     } catch (err) {
-      expectAnchorError(err, 'PlatformPaused');
+      expectAnchorError(err, 'StoreSuspended');
     }
   });
 
@@ -295,6 +1007,8 @@ describe('createOrder', () => {
     await program.methods
       .listItem({
         price: new BN(itemPrice),
+        pricingMode: { oracle: {} },
+        referralBps: 0,
         inventoryCount: 0,
         name: itemName,
         image: 'https://example.com/item.png',
@@ -317,7 +1031,12 @@ describe('createOrder', () => {
 
     try {
       await program.methods
-        .createOrder(amount, new BN(unixTimestamp))
+        .createOrder(
+          amount,
+          new BN(unixTimestamp),
+          new BN(initShopperAtaBal),
+          null
+        )
         .accountsPartial({
           authority: shopperAuthority.publicKey,
           store: storePda,
@@ -7,6 +7,7 @@ import {
 import { Splurge } from '../../target/types/splurge';
 import { BN, Program } from '@coral-xyz/anchor';
 import {
+  getConfigPda,
   getItemPda,
   getOrderPda,
   getReviewPda,
@@ -18,7 +19,9 @@ import { fetchReviewAcc, fetchTaskQueueAcc } from '../accounts';
 import { LiteSVM } from 'litesvm';
 import { LiteSVMProvider } from 'anchor-litesvm';
 import {
+  ORACLE_CONFIG,
   TUKTUK_PROGRAM_ID,
+  USDC_FEED_ID,
   USDC_MINT,
   USDC_PRICE_UPDATE_V2,
 } from '../constants';
@@ -89,7 +92,9 @@ describe('createReview', () => {
         acceptedMints: [
           {
             mint: USDC_MINT,
-            priceUpdateV2: USDC_PRICE_UPDATE_V2,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
           },
         ],
         admin: admin.publicKey,
@@ -128,6 +133,8 @@ describe('createReview', () => {
     await program.methods
       .listItem({
         price: new BN(itemPrice),
+        pricingMode: { oracle: {} },
+        referralBps: 0,
         inventoryCount: initInventoryCount,
         name: itemName,
         image: 'https://example.com/item.png',
@@ -146,7 +153,7 @@ describe('createReview', () => {
     orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
 
     await program.methods
-      .createOrder(1, new BN(unixTimestamp))
+      .createOrder(1, new BN(unixTimestamp), new BN(initShopperAtaBal), null)
       .accountsPartial({
         authority: shopperAuthority.publicKey,
         store: storePda,
@@ -167,13 +174,13 @@ describe('createReview', () => {
     const [taskPda] = taskKey(taskQueuePda, taskId);
     const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
       taskQueuePda,
-      admin.publicKey
+      getConfigPda()
     );
 
     await program.methods
       .shipOrder(taskId)
       .accountsPartial({
-        admin: admin.publicKey,
+        shipper: admin.publicKey,
         order: orderPda,
         authority: shopperAuthority.publicKey,
         item: itemPda,
@@ -195,7 +202,7 @@ describe('createReview', () => {
     await program.methods
       .completeOrder()
       .accountsPartial({
-        admin: admin.publicKey,
+        operator: admin.publicKey,
         shopper: shopperPda,
         store: storePda,
         item: itemPda,
@@ -256,7 +263,7 @@ describe('createReview', () => {
     await program.methods
       .completeOrder()
       .accountsPartial({
-        admin: admin.publicKey,
+        operator: admin.publicKey,
         shopper: shopperPda,
         store: storePda,
         item: itemPda,
@@ -290,7 +297,7 @@ describe('createReview', () => {
     await program.methods
       .completeOrder()
       .accountsPartial({
-        admin: admin.publicKey,
+        operator: admin.publicKey,
         shopper: shopperPda,
         store: storePda,
         item: itemPda,
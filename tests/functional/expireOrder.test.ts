@@ -0,0 +1,365 @@
+import { beforeEach, describe, expect, test } from 'bun:test';
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import {
+  getAccount,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
+import { Splurge } from '../../target/types/splurge';
+import { BN, Program } from '@coral-xyz/anchor';
+import {
+  getConfigPda,
+  getItemPda,
+  getOrderPda,
+  getShopperPda,
+  getStorePda,
+  getTreasuryPda,
+} from '../pda';
+import { fetchItemAcc, fetchOrderAcc, fetchTaskQueueAcc } from '../accounts';
+import { LiteSVM } from 'litesvm';
+import { LiteSVMProvider } from 'anchor-litesvm';
+import {
+  ORACLE_CONFIG,
+  TUKTUK_PROGRAM_ID,
+  USDC_FEED_ID,
+  USDC_MINT,
+  USDC_PRICE_UPDATE_V2,
+} from '../constants';
+import {
+  expectAnchorError,
+  fundedSystemAccountInfo,
+  getSetup,
+  initAta,
+  initTaskQueue,
+} from '../setup';
+import {
+  nextAvailableTaskIds,
+  taskKey,
+  taskQueueAuthorityKey,
+  TaskQueueV0,
+} from '@helium/tuktuk-sdk';
+import { Tuktuk } from '@helium/tuktuk-idls/lib/types/tuktuk.js';
+
+describe('expireOrder', () => {
+  let { litesvm, provider, program, tuktukProgram, taskQueuePda } = {} as {
+    litesvm: LiteSVM;
+    provider: LiteSVMProvider;
+    program: Program<Splurge>;
+    tuktukProgram: Program<Tuktuk>;
+    taskQueuePda: PublicKey;
+  };
+
+  const [admin, shopperAuthority, storeAuthority] = Array.from(
+    { length: 3 },
+    Keypair.generate
+  );
+  const treasury = getTreasuryPda();
+
+  const itemName = 'Item A';
+  const itemPrice = 1e6; // $1
+  const initInventoryCount = 10;
+  const initShopperAtaBal = 1e8; // $100
+  const paymentMint = USDC_MINT;
+  const tokenProgram = TOKEN_PROGRAM_ID;
+  const shippingDeadlineSecs = 86400;
+  let orderPda: PublicKey;
+  let taskQueueAcc: TaskQueueV0;
+  let taskId: number;
+
+  const shopperPda = getShopperPda(shopperAuthority.publicKey);
+  const storePda = getStorePda(storeAuthority.publicKey);
+  const itemPda = getItemPda(storePda, itemName);
+
+  beforeEach(async () => {
+    ({ litesvm, provider, program, tuktukProgram, taskQueuePda } =
+      await getSetup([
+        ...[admin, shopperAuthority, storeAuthority].map((kp) => {
+          return {
+            pubkey: kp.publicKey,
+            account: fundedSystemAccountInfo(LAMPORTS_PER_SOL * 5),
+          };
+        }),
+      ]));
+
+    await initTaskQueue(tuktukProgram, admin, taskQueuePda);
+    taskQueueAcc = await fetchTaskQueueAcc(tuktukProgram, taskQueuePda);
+    taskId = nextAvailableTaskIds(taskQueueAcc.taskBitmap, 1, false)[0];
+
+    initAta(litesvm, USDC_MINT, treasury);
+    initAta(litesvm, USDC_MINT, shopperAuthority.publicKey, initShopperAtaBal);
+
+    await program.methods
+      .initializeConfig({
+        acceptedMints: [
+          {
+            mint: USDC_MINT,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+          },
+        ],
+        admin: admin.publicKey,
+        orderFeeBps: 250,
+      })
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .initializeShopper({
+        name: 'Shopper A',
+        image: 'https://example.com/image.png',
+        address: 'address',
+      })
+      .accounts({
+        authority: shopperAuthority.publicKey,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    await program.methods
+      .initializeStore({
+        name: 'Store A',
+        image: 'https://example.com/image.png',
+        about: 'about',
+      })
+      .accounts({
+        authority: storeAuthority.publicKey,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    await program.methods
+      .listItem({
+        price: new BN(itemPrice),
+        pricingMode: { oracle: {} },
+        referralBps: 0,
+        inventoryCount: initInventoryCount,
+        name: itemName,
+        image: 'https://example.com/item.png',
+        description: 'description',
+      })
+      .accounts({
+        authority: storeAuthority.publicKey,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    await program.methods
+      .updateConfig({
+        acceptedMints: null,
+        pausedOperations: null,
+        newAdmin: null,
+        orderFeeBps: null,
+        maxOrderFeeBps: null,
+        shopperCancelWindowSecs: null,
+        completionDelaySecs: null,
+        shippingDeadlineSecs,
+        feeTiers: null,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const { unixTimestamp } = litesvm.getClock();
+    orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+    await program.methods
+      .createOrder(1, new BN(unixTimestamp), new BN(initShopperAtaBal), taskId)
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+        order: orderPda,
+        priceUpdateV2: USDC_PRICE_UPDATE_V2,
+        paymentMint,
+        tokenProgram,
+        tuktuk: TUKTUK_PROGRAM_ID,
+        taskQueue: taskQueuePda,
+        task: taskKey(taskQueuePda, taskId)[0],
+        taskQueueAuthority: taskQueueAuthorityKey(
+          taskQueuePda,
+          getConfigPda()
+        )[0],
+      })
+      .signers([shopperAuthority])
+      .rpc();
+  });
+
+  test('expires an order after the shipping deadline', async () => {
+    const clock = litesvm.getClock();
+    clock.unixTimestamp += BigInt(shippingDeadlineSecs);
+    litesvm.setClock(clock);
+
+    const shopperAuthorityAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      shopperAuthority.publicKey,
+      false,
+      tokenProgram
+    );
+    const preShopperAuthorityAta = await getAccount(
+      provider.connection,
+      shopperAuthorityAta
+    );
+
+    await program.methods
+      .expireOrder()
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        shopper: shopperPda,
+        item: itemPda,
+        order: orderPda,
+        paymentMint,
+        tokenProgram,
+      })
+      .rpc();
+
+    const orderAcc = await fetchOrderAcc(program, orderPda);
+
+    expect(orderAcc.status).toStrictEqual({ cancelled: {} });
+
+    const postShopperAuthorityAta = await getAccount(
+      provider.connection,
+      shopperAuthorityAta
+    );
+
+    expect(Number(postShopperAuthorityAta.amount)).toBe(
+      Number(preShopperAuthorityAta.amount) +
+        orderAcc.paymentSubtotal.toNumber() +
+        orderAcc.platformFee.toNumber()
+    );
+
+    const itemAcc = await fetchItemAcc(program, itemPda);
+
+    expect(itemAcc.inventoryCount).toBe(initInventoryCount);
+  });
+
+  test('does nothing if order is no longer pending', async () => {
+    const orderAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      orderPda,
+      !PublicKey.isOnCurve(orderPda)
+    );
+    const [taskPda] = taskKey(taskQueuePda, taskId + 1);
+    const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
+      taskQueuePda,
+      getConfigPda()
+    );
+
+    await program.methods
+      .shipOrder(taskId + 1)
+      .accountsPartial({
+        shipper: storeAuthority.publicKey,
+        order: orderPda,
+        authority: shopperAuthority.publicKey,
+        item: itemPda,
+        orderTokenAccount: orderAta,
+        paymentMint,
+        shopper: shopperPda,
+        store: storePda,
+        task: taskPda,
+        taskQueue: taskQueuePda,
+        taskQueueAuthority: taskQueueAuthorityPda,
+        tokenProgram,
+        tuktuk: TUKTUK_PROGRAM_ID,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    const clock = litesvm.getClock();
+    clock.unixTimestamp += BigInt(shippingDeadlineSecs);
+    litesvm.setClock(clock);
+
+    await program.methods
+      .expireOrder()
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        shopper: shopperPda,
+        item: itemPda,
+        order: orderPda,
+        paymentMint,
+        tokenProgram,
+      })
+      .rpc();
+
+    const orderAcc = await fetchOrderAcc(program, orderPda);
+
+    expect(orderAcc.status).toStrictEqual({ shipping: {} });
+  });
+
+  test('throws if shipping deadline has not elapsed', async () => {
+    try {
+      await program.methods
+        .expireOrder()
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          shopper: shopperPda,
+          item: itemPda,
+          order: orderPda,
+          paymentMint,
+          tokenProgram,
+        })
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'OrderNotExpired');
+    }
+  });
+
+  test('throws if backdated order is expired early', async () => {
+    const clock = litesvm.getClock();
+    clock.unixTimestamp += BigInt(shippingDeadlineSecs * 2);
+    litesvm.setClock(clock);
+
+    const timestamp = new BN(
+      (clock.unixTimestamp - BigInt(shippingDeadlineSecs)).toString()
+    );
+    const backdatedOrderPda = getOrderPda(shopperPda, itemPda, timestamp);
+
+    await program.methods
+      .createOrder(1, timestamp, new BN(initShopperAtaBal), taskId + 1)
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+        order: backdatedOrderPda,
+        priceUpdateV2: USDC_PRICE_UPDATE_V2,
+        paymentMint,
+        tokenProgram,
+        tuktuk: TUKTUK_PROGRAM_ID,
+        taskQueue: taskQueuePda,
+        task: taskKey(taskQueuePda, taskId + 1)[0],
+        taskQueueAuthority: taskQueueAuthorityKey(
+          taskQueuePda,
+          getConfigPda()
+        )[0],
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    const orderAcc = await fetchOrderAcc(program, backdatedOrderPda);
+
+    expect(orderAcc.shippingDeadline.toString()).toBe(
+      (clock.unixTimestamp + BigInt(shippingDeadlineSecs)).toString()
+    );
+
+    try {
+      await program.methods
+        .expireOrder()
+        .accountsPartial({
+          authority: shopperAuthority.publicKey,
+          shopper: shopperPda,
+          item: itemPda,
+          order: backdatedOrderPda,
+          paymentMint,
+          tokenProgram,
+        })
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'OrderNotExpired');
+    }
+  });
+});
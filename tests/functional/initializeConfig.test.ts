@@ -6,7 +6,7 @@ import { fetchConfigAcc } from '../accounts';
 import { LiteSVM } from 'litesvm';
 import { LiteSVMProvider } from 'anchor-litesvm';
 import { expectAnchorError, fundedSystemAccountInfo, getSetup } from '../setup';
-import { USDC_MINT, USDC_PRICE_UPDATE_V2 } from '../constants';
+import { ORACLE_CONFIG, USDC_FEED_ID, USDC_MINT } from '../constants';
 import { getConfigPda } from '../pda';
 
 describe('initializeConfig', () => {
@@ -31,7 +31,9 @@ describe('initializeConfig', () => {
     const acceptedMints = [
       {
         mint: USDC_MINT,
-        priceUpdateV2: USDC_PRICE_UPDATE_V2,
+        feedId: USDC_FEED_ID,
+        ...ORACLE_CONFIG,
+        isPaused: false,
       },
     ];
     const orderFeeBps = 250;
@@ -52,16 +54,24 @@ describe('initializeConfig', () => {
     const configAcc = await fetchConfigAcc(program, configPda);
 
     expect(configAcc.admin).toStrictEqual(admin.publicKey);
-    expect(configAcc.isPaused).toBe(false);
+    expect(configAcc.pausedOperations).toBe(0);
     expect(configAcc.orderFeeBps).toBe(orderFeeBps);
-    expect(configAcc.acceptedMints).toStrictEqual(acceptedMints);
+    expect(configAcc.acceptedMints.map(({ mint }) => mint)).toStrictEqual(
+      acceptedMints.map(({ mint }) => mint)
+    );
+    expect(configAcc.acceptedMints[0].maxConfBps).toBe(
+      ORACLE_CONFIG.maxConfBps
+    );
+    expect(configAcc.completionDelaySecs).toBe(60);
   });
 
   test('throws if a mint is default PublicKey', async () => {
     const acceptedMints = [
       {
         mint: PublicKey.default,
-        priceUpdateV2: USDC_PRICE_UPDATE_V2,
+        feedId: USDC_FEED_ID,
+        ...ORACLE_CONFIG,
+        isPaused: false,
       },
     ];
 
@@ -101,4 +111,32 @@ describe('initializeConfig', () => {
       expectAnchorError(err, 'EmptyAcceptedMints');
     }
   });
+
+  test('throws if oracle price band is invalid', async () => {
+    const acceptedMints = [
+      {
+        mint: USDC_MINT,
+        feedId: USDC_FEED_ID,
+        ...ORACLE_CONFIG,
+        isPaused: false,
+        minPrice: ORACLE_CONFIG.maxPrice.addn(1),
+      },
+    ];
+
+    try {
+      await program.methods
+        .initializeConfig({
+          acceptedMints,
+          admin: admin.publicKey,
+          orderFeeBps: 250,
+        })
+        .accounts({
+          authority: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidOracleConfig');
+    }
+  });
 });
@@ -4,8 +4,11 @@ import { Splurge } from '../../target/types/splurge';
 import { BN, Program } from '@coral-xyz/anchor';
 import {
   MAX_STORE_ITEM_NAME_LEN,
+  ORACLE_CONFIG,
+  PAUSE_LISTING,
+  USDC_FEED_ID,
   USDC_MINT,
-  USDC_PRICE_UPDATE_V2,
+  USDT_MINT,
 } from '../constants';
 import { fetchItemAcc } from '../accounts';
 import { LiteSVM } from 'litesvm';
@@ -37,7 +40,9 @@ describe('listItem', () => {
         acceptedMints: [
           {
             mint: USDC_MINT,
-            priceUpdateV2: USDC_PRICE_UPDATE_V2,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
           },
         ],
         admin: admin.publicKey,
@@ -72,6 +77,8 @@ describe('listItem', () => {
     await program.methods
       .listItem({
         price: new BN(price),
+        pricingMode: { oracle: {} },
+        referralBps: 0,
         inventoryCount,
         name,
         image,
@@ -89,6 +96,8 @@ describe('listItem', () => {
 
     expect(itemAcc.store).toStrictEqual(storePda);
     expect(itemAcc.price.toNumber()).toBe(price);
+    expect(itemAcc.priceMint).toBeNull();
+    expect(itemAcc.referralBps).toBe(0);
     expect(itemAcc.inventoryCount).toBe(inventoryCount);
     expect(itemAcc.name).toBe(name);
     expect(itemAcc.image).toBe(image);
@@ -106,6 +115,8 @@ describe('listItem', () => {
       await program.methods
         .listItem({
           price: new BN(price),
+          pricingMode: { oracle: {} },
+          referralBps: 0,
           inventoryCount,
           name,
           image,
@@ -121,6 +132,58 @@ describe('listItem', () => {
     }
   });
 
+  test('throws if price mint is not accepted', async () => {
+    try {
+      await program.methods
+        .listItem({
+          price: new BN(1e6),
+          pricingMode: { fixed: { priceMint: USDT_MINT } },
+          referralBps: 0,
+          inventoryCount: 10,
+          name: 'Item A',
+          image: 'https://example.com/item.png',
+          description: 'description',
+        })
+        .accounts({
+          authority: storeAuthority.publicKey,
+        })
+        .signers([storeAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'PaymentMintNotAccepted');
+    }
+  });
+
+  test('throws if listing is paused', async () => {
+    await program.methods
+      .setPaused(PAUSE_LISTING)
+      .accounts({
+        pauser: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    try {
+      await program.methods
+        .listItem({
+          price: new BN(1e6),
+          pricingMode: { oracle: {} },
+          referralBps: 0,
+          inventoryCount: 10,
+          name: 'item',
+          image: 'https://example.com/item.png',
+          description: 'description',
+        })
+        .accounts({
+          authority: storeAuthority.publicKey,
+        })
+        .signers([storeAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'ListingPaused');
+    }
+  });
+
   test('throws if item name is too long', async () => {
     const price = 1e6; // $1
     const inventoryCount = 10;
@@ -132,6 +195,8 @@ describe('listItem', () => {
       await program.methods
         .listItem({
           price: new BN(price),
+          pricingMode: { oracle: {} },
+          referralBps: 0,
           inventoryCount,
           name,
           image,
@@ -0,0 +1,233 @@
+import { beforeEach, describe, expect, test } from 'bun:test';
+import { Keypair } from '@solana/web3.js';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Splurge } from '../../target/types/splurge';
+import { BN, Program } from '@coral-xyz/anchor';
+import {
+  getItemPda,
+  getOrderPda,
+  getShopperPda,
+  getStorePda,
+  getTreasuryPda,
+} from '../pda';
+import { fetchOrderAcc } from '../accounts';
+import { LiteSVM } from 'litesvm';
+import { LiteSVMProvider } from 'anchor-litesvm';
+import {
+  ORACLE_CONFIG,
+  USDC_FEED_ID,
+  USDC_MINT,
+  USDC_PRICE_UPDATE_V2,
+  USDT_MINT,
+  USDT_PRICE_UPDATE_V2,
+} from '../constants';
+import {
+  expectAnchorError,
+  fundedSystemAccountInfo,
+  getSetup,
+  initAta,
+} from '../setup';
+
+describe('quoteOrder', () => {
+  let { litesvm, provider, program } = {} as {
+    litesvm: LiteSVM;
+    provider: LiteSVMProvider;
+    program: Program<Splurge>;
+  };
+
+  const [admin, shopperAuthority, storeAuthority] = Array.from(
+    { length: 3 },
+    Keypair.generate
+  );
+  const treasury = getTreasuryPda();
+
+  const itemName = 'Item A';
+  const itemPrice = 1e6; // $1
+  const initInventoryCount = 10;
+
+  const initShopperAtaBal = 1e8; // $100
+
+  const tokenProgram = TOKEN_PROGRAM_ID;
+
+  beforeEach(async () => {
+    ({ litesvm, provider, program } = await getSetup([
+      ...[admin, shopperAuthority, storeAuthority].map((kp) => {
+        return {
+          pubkey: kp.publicKey,
+          account: fundedSystemAccountInfo(),
+        };
+      }),
+    ]));
+
+    initAta(litesvm, USDC_MINT, treasury);
+    initAta(litesvm, USDC_MINT, shopperAuthority.publicKey, initShopperAtaBal);
+    initAta(litesvm, USDT_MINT, treasury);
+    initAta(litesvm, USDT_MINT, shopperAuthority.publicKey, initShopperAtaBal);
+
+    await program.methods
+      .initializeConfig({
+        acceptedMints: [
+          {
+            mint: USDC_MINT,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+          },
+        ],
+        admin: admin.publicKey,
+        orderFeeBps: 250,
+      })
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .initializeShopper({
+        name: 'Shopper A',
+        image: 'https://example.com/image.png',
+        address: 'address',
+      })
+      .accounts({
+        authority: shopperAuthority.publicKey,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    await program.methods
+      .initializeStore({
+        name: 'Store A',
+        image: 'https://example.com/image.png',
+        about: 'about',
+      })
+      .accounts({
+        authority: storeAuthority.publicKey,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    await program.methods
+      .listItem({
+        price: new BN(itemPrice),
+        pricingMode: { oracle: {} },
+        referralBps: 0,
+        inventoryCount: initInventoryCount,
+        name: itemName,
+        image: 'https://example.com/item.png',
+        description: 'description',
+      })
+      .accounts({
+        authority: storeAuthority.publicKey,
+      })
+      .signers([storeAuthority])
+      .rpc();
+  });
+
+  test('quotes the exact order charge', async () => {
+    const amount = 3;
+    const paymentMint = USDC_MINT;
+
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+    const shopperPda = getShopperPda(shopperAuthority.publicKey);
+    const { unixTimestamp } = litesvm.getClock();
+    const orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
+
+    const quote = await program.methods
+      .quoteOrder(amount)
+      .accountsPartial({
+        store: storePda,
+        item: itemPda,
+        paymentMint,
+        priceUpdateV2: USDC_PRICE_UPDATE_V2,
+      })
+      .view();
+
+    expect(quote.oraclePrice.toNumber()).toBeGreaterThan(0);
+    expect(quote.oracleExponent).toBeLessThan(0);
+
+    await program.methods
+      .createOrder(
+        amount,
+        new BN(unixTimestamp),
+        new BN(initShopperAtaBal),
+        null
+      )
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+        order: orderPda,
+        priceUpdateV2: USDC_PRICE_UPDATE_V2,
+        paymentMint,
+        tokenProgram,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    const orderAcc = await fetchOrderAcc(program, orderPda);
+
+    expect(orderAcc.paymentSubtotal.toNumber()).toBe(
+      quote.paymentSubtotal.toNumber()
+    );
+    expect(orderAcc.platformFee.toNumber()).toBe(quote.platformFee.toNumber());
+    expect(orderAcc.orderFeeBps).toBe(quote.orderFeeBps);
+  });
+
+  test('quotes fixed-price item without oracle', async () => {
+    const amount = 2;
+    const paymentMint = USDC_MINT;
+
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+
+    await program.methods
+      .updateItem({
+        price: new BN(itemPrice),
+        pricingMode: { fixed: { priceMint: paymentMint } },
+        referralBps: null,
+        inventoryCount: null,
+      })
+      .accountsPartial({
+        authority: storeAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    const quote = await program.methods
+      .quoteOrder(amount)
+      .accountsPartial({
+        store: storePda,
+        item: itemPda,
+        paymentMint,
+        priceUpdateV2: null,
+      })
+      .view();
+
+    expect(quote.paymentSubtotal.toNumber()).toBe(itemPrice * amount);
+    expect(quote.oraclePrice.toNumber()).toBe(0);
+    expect(quote.oracleExponent).toBe(0);
+  });
+
+  test('throws if payment mint is not accepted', async () => {
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+
+    try {
+      await program.methods
+        .quoteOrder(1)
+        .accountsPartial({
+          store: storePda,
+          item: itemPda,
+          paymentMint: USDT_MINT,
+          priceUpdateV2: USDT_PRICE_UPDATE_V2,
+        })
+        .view();
+    } catch (err) {
+      expectAnchorError(err, 'PaymentMintNotAccepted');
+    }
+  });
+});
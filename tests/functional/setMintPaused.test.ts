@@ -0,0 +1,124 @@
+import { beforeEach, describe, expect, test } from 'bun:test';
+import { Keypair } from '@solana/web3.js';
+import { Splurge } from '../../target/types/splurge';
+import { Program } from '@coral-xyz/anchor';
+import { fetchConfigAcc } from '../accounts';
+import { LiteSVM } from 'litesvm';
+import { LiteSVMProvider } from 'anchor-litesvm';
+import { expectAnchorError, fundedSystemAccountInfo, getSetup } from '../setup';
+import {
+  ORACLE_CONFIG,
+  USDC_FEED_ID,
+  USDC_MINT,
+  USDT_MINT,
+} from '../constants';
+import { getConfigPda } from '../pda';
+
+describe('setMintPaused', () => {
+  let { litesvm, provider, program } = {} as {
+    litesvm: LiteSVM;
+    provider: LiteSVMProvider;
+    program: Program<Splurge>;
+  };
+
+  const [admin, pauser] = Array.from({ length: 2 }, Keypair.generate);
+
+  beforeEach(async () => {
+    ({ litesvm, provider, program } = await getSetup([
+      ...[admin, pauser].map((kp) => {
+        return {
+          pubkey: kp.publicKey,
+          account: fundedSystemAccountInfo(),
+        };
+      }),
+    ]));
+
+    await program.methods
+      .initializeConfig({
+        acceptedMints: [
+          {
+            mint: USDC_MINT,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+          },
+        ],
+        admin: admin.publicKey,
+        orderFeeBps: 250,
+      })
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .updateRoles({
+        operator: null,
+        treasurer: null,
+        pauser: pauser.publicKey,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+  });
+
+  test('pauses and unpauses an accepted mint', async () => {
+    const configPda = getConfigPda();
+
+    await program.methods
+      .setMintPaused(USDC_MINT, true)
+      .accounts({
+        pauser: pauser.publicKey,
+      })
+      .signers([pauser])
+      .rpc();
+
+    let configAcc = await fetchConfigAcc(program, configPda);
+
+    expect(configAcc.acceptedMints[0].mint).toStrictEqual(USDC_MINT);
+    expect(configAcc.acceptedMints[0].isPaused).toBe(true);
+
+    await program.methods
+      .setMintPaused(USDC_MINT, false)
+      .accounts({
+        pauser: pauser.publicKey,
+      })
+      .signers([pauser])
+      .rpc();
+
+    configAcc = await fetchConfigAcc(program, configPda);
+
+    expect(configAcc.acceptedMints[0].isPaused).toBe(false);
+  });
+
+  test('throws if mint is not accepted', async () => {
+    try {
+      await program.methods
+        .setMintPaused(USDT_MINT, true)
+        .accounts({
+          pauser: pauser.publicKey,
+        })
+        .signers([pauser])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'PaymentMintNotAccepted');
+    }
+  });
+
+  test('throws if pausing as unauthorized pauser', async () => {
+    try {
+      await program.methods
+        .setMintPaused(USDC_MINT, true)
+        .accounts({
+          pauser: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'UnauthorizedPauser');
+    }
+  });
+});
@@ -0,0 +1,126 @@
+import { beforeEach, describe, expect, test } from 'bun:test';
+import { Keypair } from '@solana/web3.js';
+import { Splurge } from '../../target/types/splurge';
+import { Program } from '@coral-xyz/anchor';
+import { fetchConfigAcc } from '../accounts';
+import { LiteSVM } from 'litesvm';
+import { LiteSVMProvider } from 'anchor-litesvm';
+import { expectAnchorError, fundedSystemAccountInfo, getSetup } from '../setup';
+import {
+  ORACLE_CONFIG,
+  PAUSE_ORDERING,
+  PAUSE_SHIPPING,
+  PAUSE_WITHDRAWALS,
+  USDC_FEED_ID,
+  USDC_MINT,
+} from '../constants';
+import { getConfigPda } from '../pda';
+
+describe('setPaused', () => {
+  let { litesvm, provider, program } = {} as {
+    litesvm: LiteSVM;
+    provider: LiteSVMProvider;
+    program: Program<Splurge>;
+  };
+
+  const [admin, pauser] = Array.from({ length: 2 }, Keypair.generate);
+
+  beforeEach(async () => {
+    ({ litesvm, provider, program } = await getSetup([
+      ...[admin, pauser].map((kp) => {
+        return {
+          pubkey: kp.publicKey,
+          account: fundedSystemAccountInfo(),
+        };
+      }),
+    ]));
+
+    await program.methods
+      .initializeConfig({
+        acceptedMints: [
+          {
+            mint: USDC_MINT,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+          },
+        ],
+        admin: admin.publicKey,
+        orderFeeBps: 250,
+      })
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .updateRoles({
+        operator: null,
+        treasurer: null,
+        pauser: pauser.publicKey,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+  });
+
+  test('pauses and unpauses operations', async () => {
+    const configPda = getConfigPda();
+    const pausedOperations = PAUSE_SHIPPING | PAUSE_WITHDRAWALS;
+
+    await program.methods
+      .setPaused(pausedOperations)
+      .accounts({
+        pauser: pauser.publicKey,
+      })
+      .signers([pauser])
+      .rpc();
+
+    let configAcc = await fetchConfigAcc(program, configPda);
+
+    expect(configAcc.pausedOperations).toBe(pausedOperations);
+
+    await program.methods
+      .setPaused(0)
+      .accounts({
+        pauser: pauser.publicKey,
+      })
+      .signers([pauser])
+      .rpc();
+
+    configAcc = await fetchConfigAcc(program, configPda);
+
+    expect(configAcc.pausedOperations).toBe(0);
+  });
+
+  test('throws if pause flags are unknown', async () => {
+    try {
+      await program.methods
+        .setPaused(1 << 7)
+        .accounts({
+          pauser: pauser.publicKey,
+        })
+        .signers([pauser])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidPauseFlags');
+    }
+  });
+
+  test('throws if pausing as unauthorized pauser', async () => {
+    try {
+      await program.methods
+        .setPaused(PAUSE_ORDERING)
+        .accounts({
+          pauser: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'UnauthorizedPauser');
+    }
+  });
+});
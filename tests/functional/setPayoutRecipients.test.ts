@@ -0,0 +1,132 @@
+import { beforeEach, describe, expect, test } from 'bun:test';
+import { Keypair } from '@solana/web3.js';
+import { Splurge } from '../../target/types/splurge';
+import { Program } from '@coral-xyz/anchor';
+import { fetchStoreAcc } from '../accounts';
+import { LiteSVM } from 'litesvm';
+import { LiteSVMProvider } from 'anchor-litesvm';
+import { expectAnchorError, fundedSystemAccountInfo, getSetup } from '../setup';
+import { ORACLE_CONFIG, USDC_FEED_ID, USDC_MINT } from '../constants';
+import { getStorePda } from '../pda';
+
+describe('setPayoutRecipients', () => {
+  let { litesvm, provider, program } = {} as {
+    litesvm: LiteSVM;
+    provider: LiteSVMProvider;
+    program: Program<Splurge>;
+  };
+
+  const [admin, storeAuthority, partner] = Array.from(
+    { length: 3 },
+    Keypair.generate
+  );
+
+  const storePda = getStorePda(storeAuthority.publicKey);
+
+  beforeEach(async () => {
+    ({ litesvm, provider, program } = await getSetup([
+      ...[admin, storeAuthority, partner].map((kp) => {
+        return {
+          pubkey: kp.publicKey,
+          account: fundedSystemAccountInfo(),
+        };
+      }),
+    ]));
+
+    await program.methods
+      .initializeConfig({
+        acceptedMints: [
+          {
+            mint: USDC_MINT,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+          },
+        ],
+        admin: admin.publicKey,
+        orderFeeBps: 250,
+      })
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .initializeStore({
+        name: 'Store A',
+        image: 'https://example.com/image.png',
+        about: 'about',
+      })
+      .accounts({
+        authority: storeAuthority.publicKey,
+      })
+      .signers([storeAuthority])
+      .rpc();
+  });
+
+  test('sets payout recipients', async () => {
+    const payoutRecipients = [
+      { recipient: storeAuthority.publicKey, shareBps: 7_000 },
+      { recipient: partner.publicKey, shareBps: 3_000 },
+    ];
+
+    await program.methods
+      .setPayoutRecipients(payoutRecipients)
+      .accounts({
+        authority: storeAuthority.publicKey,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    let storeAcc = await fetchStoreAcc(program, storePda);
+
+    expect(storeAcc.payoutRecipients).toStrictEqual(payoutRecipients);
+
+    await program.methods
+      .setPayoutRecipients([])
+      .accounts({
+        authority: storeAuthority.publicKey,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    storeAcc = await fetchStoreAcc(program, storePda);
+
+    expect(storeAcc.payoutRecipients).toStrictEqual([]);
+  });
+
+  test('throws if shares do not sum to 10000', async () => {
+    try {
+      await program.methods
+        .setPayoutRecipients([
+          { recipient: storeAuthority.publicKey, shareBps: 7_000 },
+          { recipient: partner.publicKey, shareBps: 2_000 },
+        ])
+        .accounts({
+          authority: storeAuthority.publicKey,
+        })
+        .signers([storeAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidPayoutRecipients');
+    }
+  });
+
+  test('throws if recipients are duplicated', async () => {
+    try {
+      await program.methods
+        .setPayoutRecipients([
+          { recipient: partner.publicKey, shareBps: 5_000 },
+          { recipient: partner.publicKey, shareBps: 5_000 },
+        ])
+        .accounts({
+          authority: storeAuthority.publicKey,
+        })
+        .signers([storeAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidPayoutRecipients');
+    }
+  });
+});
@@ -0,0 +1,280 @@
+import { beforeEach, describe, expect, test } from 'bun:test';
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Splurge } from '../../target/types/splurge';
+import { BN, Program } from '@coral-xyz/anchor';
+import {
+  getCartOrderPda,
+  getConfigPda,
+  getItemPda,
+  getShopperPda,
+  getStorePda,
+  getTreasuryPda,
+} from '../pda';
+import { fetchCartOrderAcc, fetchTaskQueueAcc } from '../accounts';
+import { LiteSVM } from 'litesvm';
+import { LiteSVMProvider } from 'anchor-litesvm';
+import {
+  ORACLE_CONFIG,
+  TUKTUK_PROGRAM_ID,
+  USDC_FEED_ID,
+  USDC_MINT,
+  USDC_PRICE_UPDATE_V2,
+} from '../constants';
+import {
+  expectAnchorError,
+  fundedSystemAccountInfo,
+  getSetup,
+  initAta,
+  initTaskQueue,
+} from '../setup';
+import {
+  nextAvailableTaskIds,
+  taskKey,
+  taskQueueAuthorityKey,
+} from '@helium/tuktuk-sdk';
+import { Tuktuk } from '@helium/tuktuk-idls/lib/types/tuktuk.js';
+
+describe('shipCartOrder', () => {
+  let { litesvm, provider, program, tuktukProgram, taskQueuePda } = {} as {
+    litesvm: LiteSVM;
+    provider: LiteSVMProvider;
+    program: Program<Splurge>;
+    tuktukProgram: Program<Tuktuk>;
+    taskQueuePda: PublicKey;
+  };
+
+  const [admin, shopperAuthority, storeAuthorityA, storeAuthorityB] =
+    Array.from({ length: 4 }, Keypair.generate);
+  const treasury = getTreasuryPda();
+
+  const itemName = 'Item A';
+  const itemPrice = 1e6; // $1
+  const initInventoryCount = 10;
+  const initShopperAtaBal = 1e8; // $100
+  const paymentMint = USDC_MINT;
+  const tokenProgram = TOKEN_PROGRAM_ID;
+  const amounts = [2, 3];
+  let cartOrderPda: PublicKey;
+  let taskId: number;
+
+  const shopperPda = getShopperPda(shopperAuthority.publicKey);
+  const storePdaA = getStorePda(storeAuthorityA.publicKey);
+  const storePdaB = getStorePda(storeAuthorityB.publicKey);
+  const itemPdaA = getItemPda(storePdaA, itemName);
+  const itemPdaB = getItemPda(storePdaB, itemName);
+  const storePdas = [storePdaA, storePdaB];
+
+  beforeEach(async () => {
+    ({ litesvm, provider, program, tuktukProgram, taskQueuePda } =
+      await getSetup([
+        ...[admin, shopperAuthority, storeAuthorityA, storeAuthorityB].map(
+          (kp) => {
+            return {
+              pubkey: kp.publicKey,
+              account: fundedSystemAccountInfo(LAMPORTS_PER_SOL * 5),
+            };
+          }
+        ),
+      ]));
+
+    await initTaskQueue(tuktukProgram, admin, taskQueuePda);
+    const taskQueueAcc = await fetchTaskQueueAcc(tuktukProgram, taskQueuePda);
+    taskId = nextAvailableTaskIds(taskQueueAcc.taskBitmap, 1, false)[0];
+
+    initAta(litesvm, USDC_MINT, treasury);
+    initAta(litesvm, USDC_MINT, shopperAuthority.publicKey, initShopperAtaBal);
+
+    await program.methods
+      .initializeConfig({
+        acceptedMints: [
+          {
+            mint: USDC_MINT,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+          },
+        ],
+        admin: admin.publicKey,
+        orderFeeBps: 250,
+      })
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .initializeShopper({
+        name: 'Shopper A',
+        image: 'https://example.com/image.png',
+        address: 'address',
+      })
+      .accounts({
+        authority: shopperAuthority.publicKey,
+      })
+      .signers([shopperAuthority])
+      .rpc();
+
+    for (const storeAuthority of [storeAuthorityA, storeAuthorityB]) {
+      await program.methods
+        .initializeStore({
+          name: 'Store',
+          image: 'https://example.com/image.png',
+          about: 'about',
+        })
+        .accounts({
+          authority: storeAuthority.publicKey,
+        })
+        .signers([storeAuthority])
+        .rpc();
+
+      await program.methods
+        .listItem({
+          price: new BN(itemPrice),
+          pricingMode: { oracle: {} },
+          referralBps: 0,
+          inventoryCount: initInventoryCount,
+          name: itemName,
+          image: 'https://example.com/item.png',
+          description: 'description',
+        })
+        .accounts({
+          authority: storeAuthority.publicKey,
+        })
+        .signers([storeAuthority])
+        .rpc();
+    }
+
+    const { unixTimestamp } = litesvm.getClock();
+    cartOrderPda = getCartOrderPda(shopperPda, new BN(unixTimestamp));
+
+    await program.methods
+      .createCartOrder(
+        amounts,
+        new BN(unixTimestamp),
+        new BN(initShopperAtaBal)
+      )
+      .accountsPartial({
+        authority: shopperAuthority.publicKey,
+        cartOrder: cartOrderPda,
+        priceUpdateV2: USDC_PRICE_UPDATE_V2,
+        paymentMint,
+        tokenProgram,
+      })
+      .remainingAccounts(
+        [
+          [itemPdaA, storePdaA],
+          [itemPdaB, storePdaB],
+        ].flatMap(([itemPda, storePda]) => [
+          { pubkey: itemPda, isSigner: false, isWritable: true },
+          { pubkey: storePda, isSigner: false, isWritable: false },
+        ])
+      )
+      .signers([shopperAuthority])
+      .rpc();
+  });
+
+  test('ships a cart order', async () => {
+    const [taskPda] = taskKey(taskQueuePda, taskId);
+    const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
+      taskQueuePda,
+      getConfigPda()
+    );
+
+    await program.methods
+      .shipCartOrder(taskId)
+      .accountsPartial({
+        operator: admin.publicKey,
+        authority: shopperAuthority.publicKey,
+        shopper: shopperPda,
+        cartOrder: cartOrderPda,
+        paymentMint,
+        task: taskPda,
+        taskQueue: taskQueuePda,
+        taskQueueAuthority: taskQueueAuthorityPda,
+        tokenProgram,
+        tuktuk: TUKTUK_PROGRAM_ID,
+      })
+      .remainingAccounts(
+        storePdas.map((pubkey) => {
+          return { pubkey, isSigner: false, isWritable: false };
+        })
+      )
+      .signers([admin])
+      .rpc();
+
+    const cartOrderAcc = await fetchCartOrderAcc(program, cartOrderPda);
+
+    expect(cartOrderAcc.status).toStrictEqual({ shipping: {} });
+    expect(cartOrderAcc.task).toStrictEqual(taskPda);
+  });
+
+  test('throws if store accounts do not match line items', async () => {
+    const [taskPda] = taskKey(taskQueuePda, taskId);
+    const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
+      taskQueuePda,
+      getConfigPda()
+    );
+
+    try {
+      await program.methods
+        .shipCartOrder(taskId)
+        .accountsPartial({
+          operator: admin.publicKey,
+          authority: shopperAuthority.publicKey,
+          shopper: shopperPda,
+          cartOrder: cartOrderPda,
+          paymentMint,
+          task: taskPda,
+          taskQueue: taskQueuePda,
+          taskQueueAuthority: taskQueueAuthorityPda,
+          tokenProgram,
+          tuktuk: TUKTUK_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          [...storePdas].reverse().map((pubkey) => {
+            return { pubkey, isSigner: false, isWritable: false };
+          })
+        )
+        .signers([admin])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidCartStore');
+    }
+  });
+
+  test('throws if shipping as unauthorized operator', async () => {
+    const [taskPda] = taskKey(taskQueuePda, taskId);
+    const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
+      taskQueuePda,
+      getConfigPda()
+    );
+
+    try {
+      await program.methods
+        .shipCartOrder(taskId)
+        .accountsPartial({
+          operator: storeAuthorityA.publicKey,
+          authority: shopperAuthority.publicKey,
+          shopper: shopperPda,
+          cartOrder: cartOrderPda,
+          paymentMint,
+          task: taskPda,
+          taskQueue: taskQueuePda,
+          taskQueueAuthority: taskQueueAuthorityPda,
+          tokenProgram,
+          tuktuk: TUKTUK_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          storePdas.map((pubkey) => {
+            return { pubkey, isSigner: false, isWritable: false };
+          })
+        )
+        .signers([storeAuthorityA])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'UnauthorizedOperator');
+    }
+  });
+});
@@ -7,6 +7,7 @@ import {
 import { Splurge } from '../../target/types/splurge';
 import { BN, Program } from '@coral-xyz/anchor';
 import {
+  getConfigPda,
   getItemPda,
   getOrderPda,
   getShopperPda,
@@ -17,7 +18,9 @@ import { fetchOrderAcc, fetchTaskQueueAcc } from '../accounts';
 import { LiteSVM } from 'litesvm';
 import { LiteSVMProvider } from 'anchor-litesvm';
 import {
+  ORACLE_CONFIG,
   TUKTUK_PROGRAM_ID,
+  USDC_FEED_ID,
   USDC_MINT,
   USDC_PRICE_UPDATE_V2,
   USDT_MINT,
@@ -90,7 +93,9 @@ describe('shipOrder', () => {
         acceptedMints: [
           {
             mint: USDC_MINT,
-            priceUpdateV2: USDC_PRICE_UPDATE_V2,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
           },
         ],
         admin: admin.publicKey,
@@ -129,6 +134,8 @@ describe('shipOrder', () => {
     await program.methods
       .listItem({
         price: new BN(itemPrice),
+        pricingMode: { oracle: {} },
+        referralBps: 0,
         inventoryCount: initInventoryCount,
         name: itemName,
         image: 'https://example.com/item.png',
@@ -144,7 +151,7 @@ describe('shipOrder', () => {
     orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
 
     await program.methods
-      .createOrder(1, new BN(unixTimestamp))
+      .createOrder(1, new BN(unixTimestamp), new BN(initShopperAtaBal), null)
       .accountsPartial({
         authority: shopperAuthority.publicKey,
         store: storePda,
@@ -168,13 +175,13 @@ describe('shipOrder', () => {
     const [taskPda] = taskKey(taskQueuePda, taskId);
     const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
       taskQueuePda,
-      admin.publicKey
+      getConfigPda()
     );
 
     await program.methods
       .shipOrder(taskId)
       .accountsPartial({
-        admin: admin.publicKey,
+        shipper: admin.publicKey,
         order: orderPda,
         authority: shopperAuthority.publicKey,
         item: itemPda,
@@ -202,8 +209,9 @@ describe('shipOrder', () => {
     await program.methods
       .cancelOrder()
       .accountsPartial({
-        admin: admin.publicKey,
+        operator: admin.publicKey,
         order: orderPda,
+        item: itemPda,
         paymentMint,
         shopper: shopperPda,
         tokenProgram,
@@ -220,13 +228,13 @@ describe('shipOrder', () => {
       const [taskPda] = taskKey(taskQueuePda, taskId);
       const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
         taskQueuePda,
-        admin.publicKey
+        getConfigPda()
       );
 
       await program.methods
         .shipOrder(taskId)
         .accountsPartial({
-          admin: admin.publicKey,
+          shipper: admin.publicKey,
           order: orderPda,
           authority: shopperAuthority.publicKey,
           item: itemPda,
@@ -245,7 +253,45 @@ describe('shipOrder', () => {
     }).toThrow();
   });
 
-  test('throws if updating as unauthorized admin', async () => {
+  test('updates an order as store authority', async () => {
+    const paymentMint = USDC_MINT;
+    const orderAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      orderPda,
+      !PublicKey.isOnCurve(orderPda)
+    );
+    const [taskPda] = taskKey(taskQueuePda, taskId);
+    const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
+      taskQueuePda,
+      getConfigPda()
+    );
+
+    await program.methods
+      .shipOrder(taskId)
+      .accountsPartial({
+        shipper: storeAuthority.publicKey,
+        order: orderPda,
+        authority: shopperAuthority.publicKey,
+        item: itemPda,
+        orderTokenAccount: orderAta,
+        paymentMint,
+        shopper: shopperPda,
+        store: storePda,
+        task: taskPda,
+        taskQueue: taskQueuePda,
+        taskQueueAuthority: taskQueueAuthorityPda,
+        tokenProgram,
+        tuktuk: TUKTUK_PROGRAM_ID,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    const orderAcc = await fetchOrderAcc(program, orderPda);
+
+    expect(orderAcc.status).toStrictEqual({ shipping: {} });
+  });
+
+  test('throws if updating as unauthorized shipper', async () => {
     try {
       const paymentMint = USDC_MINT;
       const orderAta = getAssociatedTokenAddressSync(
@@ -256,13 +302,13 @@ describe('shipOrder', () => {
       const [taskPda] = taskKey(taskQueuePda, taskId);
       const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
         taskQueuePda,
-        admin.publicKey
+        getConfigPda()
       );
 
       await program.methods
         .shipOrder(taskId)
         .accountsPartial({
-          admin: shopperAuthority.publicKey,
+          shipper: shopperAuthority.publicKey,
           order: orderPda,
           authority: shopperAuthority.publicKey,
           item: itemPda,
@@ -279,7 +325,45 @@ describe('shipOrder', () => {
         .signers([shopperAuthority])
         .rpc();
     } catch (err) {
-      expectAnchorError(err, 'UnauthorizedAdmin');
+      expectAnchorError(err, 'UnauthorizedShipper');
+    }
+  });
+
+  test('throws if tuktuk program is invalid', async () => {
+    try {
+      const paymentMint = USDC_MINT;
+      const orderAta = getAssociatedTokenAddressSync(
+        paymentMint,
+        orderPda,
+        !PublicKey.isOnCurve(orderPda)
+      );
+      const [taskPda] = taskKey(taskQueuePda, taskId);
+      const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
+        taskQueuePda,
+        getConfigPda()
+      );
+
+      await program.methods
+        .shipOrder(taskId)
+        .accountsPartial({
+          shipper: admin.publicKey,
+          order: orderPda,
+          authority: shopperAuthority.publicKey,
+          item: itemPda,
+          orderTokenAccount: orderAta,
+          paymentMint,
+          shopper: shopperPda,
+          store: storePda,
+          task: taskPda,
+          taskQueue: taskQueuePda,
+          taskQueueAuthority: taskQueueAuthorityPda,
+          tokenProgram,
+          tuktuk: tokenProgram,
+        })
+        .signers([admin])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidProgramId');
     }
   });
 });
@@ -2,7 +2,7 @@ import { beforeEach, describe, expect, test } from 'bun:test';
 import { Keypair } from '@solana/web3.js';
 import { Splurge } from '../../target/types/splurge';
 import { BN, Program } from '@coral-xyz/anchor';
-import { USDC_MINT, USDC_PRICE_UPDATE_V2 } from '../constants';
+import { ORACLE_CONFIG, USDC_FEED_ID, USDC_MINT } from '../constants';
 import { LiteSVM } from 'litesvm';
 import { LiteSVMProvider } from 'anchor-litesvm';
 import { fundedSystemAccountInfo, getSetup } from '../setup';
@@ -34,7 +34,9 @@ describe('unlistItem', () => {
         acceptedMints: [
           {
             mint: USDC_MINT,
-            priceUpdateV2: USDC_PRICE_UPDATE_V2,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
           },
         ],
         admin: admin.publicKey,
@@ -61,6 +63,8 @@ describe('unlistItem', () => {
     await program.methods
       .listItem({
         price: new BN(1e6), // $1
+        pricingMode: { oracle: {} },
+        referralBps: 0,
         inventoryCount: 10,
         name: itemName,
         image: 'https://example.com/item.png',
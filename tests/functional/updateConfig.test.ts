@@ -1,16 +1,21 @@
 import { beforeEach, describe, expect, test } from 'bun:test';
 import { Keypair } from '@solana/web3.js';
 import { Splurge } from '../../target/types/splurge';
-import { Program } from '@coral-xyz/anchor';
+import { BN, Program } from '@coral-xyz/anchor';
 import { fetchConfigAcc } from '../accounts';
 import { LiteSVM } from 'litesvm';
 import { LiteSVMProvider } from 'anchor-litesvm';
 import { expectAnchorError, fundedSystemAccountInfo, getSetup } from '../setup';
 import {
+  DEFAULT_MAX_ORDER_FEE_BPS,
+  ORACLE_CONFIG,
+  ORDER_FEE_TIMELOCK_SECS,
+  PAUSE_ORDERING,
+  PAUSE_SHIPPING,
+  USDC_FEED_ID,
   USDC_MINT,
-  USDC_PRICE_UPDATE_V2,
+  USDT_FEED_ID,
   USDT_MINT,
-  USDT_PRICE_UPDATE_V2,
 } from '../constants';
 import { getConfigPda } from '../pda';
 
@@ -26,7 +31,9 @@ describe('updateConfig', () => {
   let acceptedMints = [
     {
       mint: USDC_MINT,
-      priceUpdateV2: USDC_PRICE_UPDATE_V2,
+      feedId: USDC_FEED_ID,
+      ...ORACLE_CONFIG,
+      isPaused: false,
     },
   ];
 
@@ -56,17 +63,32 @@ describe('updateConfig', () => {
   test('updates a config', async () => {
     acceptedMints.push({
       mint: USDT_MINT,
-      priceUpdateV2: USDT_PRICE_UPDATE_V2,
+      feedId: USDT_FEED_ID,
+      ...ORACLE_CONFIG,
+      isPaused: false,
     });
-    const isPaused = true;
-    const orderFeeBps = 500;
+    const pausedOperations = PAUSE_ORDERING | PAUSE_SHIPPING;
+    const orderFeeBps = 200;
+    const maxOrderFeeBps = 800;
+    const shopperCancelWindowSecs = 3600;
+    const completionDelaySecs = 86400;
+    const shippingDeadlineSecs = 604800;
+    const feeTiers = [
+      { minVolume: new BN(1_000_000_000), orderFeeBps: 200 },
+      { minVolume: new BN(10_000_000_000), orderFeeBps: 150 },
+    ];
 
     await program.methods
       .updateConfig({
         acceptedMints,
-        isPaused,
+        pausedOperations,
         newAdmin: newAdmin.publicKey,
         orderFeeBps,
+        maxOrderFeeBps,
+        shopperCancelWindowSecs,
+        completionDelaySecs,
+        shippingDeadlineSecs,
+        feeTiers,
       })
       .accounts({
         admin: admin.publicKey,
@@ -77,26 +99,171 @@ describe('updateConfig', () => {
     const configPda = getConfigPda();
     const configAcc = await fetchConfigAcc(program, configPda);
 
-    expect(configAcc.admin).toStrictEqual(newAdmin.publicKey);
+    expect(configAcc.admin).toStrictEqual(admin.publicKey);
+    expect(configAcc.pendingAdmin).toStrictEqual(newAdmin.publicKey);
+    expect(configAcc.pausedOperations).toBe(pausedOperations);
     expect(configAcc.orderFeeBps).toBe(orderFeeBps);
-    expect(configAcc.acceptedMints).toStrictEqual(acceptedMints);
+    expect(configAcc.maxOrderFeeBps).toBe(maxOrderFeeBps);
+    expect(configAcc.pendingOrderFee).toBeNull();
+    expect(configAcc.acceptedMints.map(({ mint }) => mint)).toStrictEqual(
+      acceptedMints.map(({ mint }) => mint)
+    );
+    expect(configAcc.acceptedMints[0].maxConfBps).toBe(
+      ORACLE_CONFIG.maxConfBps
+    );
+    expect(configAcc.shopperCancelWindowSecs).toBe(shopperCancelWindowSecs);
+    expect(configAcc.completionDelaySecs).toBe(completionDelaySecs);
+    expect(configAcc.shippingDeadlineSecs).toBe(shippingDeadlineSecs);
+    expect(configAcc.feeTiers.length).toBe(feeTiers.length);
+    expect(configAcc.feeTiers[1].orderFeeBps).toBe(feeTiers[1].orderFeeBps);
+  });
+
+  test('stages order fee increase behind timelock', async () => {
+    const orderFeeBps = 500;
+
+    await program.methods
+      .updateConfig({
+        acceptedMints: null,
+        pausedOperations: null,
+        newAdmin: null,
+        orderFeeBps,
+        maxOrderFeeBps: null,
+        shopperCancelWindowSecs: null,
+        completionDelaySecs: null,
+        shippingDeadlineSecs: null,
+        feeTiers: null,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const configPda = getConfigPda();
+    const configAcc = await fetchConfigAcc(program, configPda);
+    const { unixTimestamp } = litesvm.getClock();
+
+    expect(configAcc.orderFeeBps).toBe(250);
+    expect(configAcc.pendingOrderFee.orderFeeBps).toBe(orderFeeBps);
+    expect(configAcc.pendingOrderFee.effectiveTimestamp.toNumber()).toBe(
+      Number(unixTimestamp) + ORDER_FEE_TIMELOCK_SECS
+    );
+  });
+
+  test('stages fee tier increase behind timelock', async () => {
+    const feeTiers = [{ minVolume: new BN(0), orderFeeBps: 400 }];
+
+    await program.methods
+      .updateConfig({
+        acceptedMints: null,
+        pausedOperations: null,
+        newAdmin: null,
+        orderFeeBps: null,
+        maxOrderFeeBps: null,
+        shopperCancelWindowSecs: null,
+        completionDelaySecs: null,
+        shippingDeadlineSecs: null,
+        feeTiers,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const configPda = getConfigPda();
+    const configAcc = await fetchConfigAcc(program, configPda);
+    const { unixTimestamp } = litesvm.getClock();
+
+    expect(configAcc.feeTiers.length).toBe(0);
+    expect(configAcc.pendingFeeTiers.feeTiers[0].orderFeeBps).toBe(
+      feeTiers[0].orderFeeBps
+    );
+    expect(configAcc.pendingFeeTiers.effectiveTimestamp.toNumber()).toBe(
+      Number(unixTimestamp) + ORDER_FEE_TIMELOCK_SECS
+    );
+  });
+
+  test('stages max order fee increase behind timelock', async () => {
+    const maxOrderFeeBps = 2000;
+    const orderFeeBps = 1500;
+
+    await program.methods
+      .updateConfig({
+        acceptedMints: null,
+        pausedOperations: null,
+        newAdmin: null,
+        orderFeeBps,
+        maxOrderFeeBps,
+        shopperCancelWindowSecs: null,
+        completionDelaySecs: null,
+        shippingDeadlineSecs: null,
+        feeTiers: null,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const configPda = getConfigPda();
+    const configAcc = await fetchConfigAcc(program, configPda);
+    const { unixTimestamp } = litesvm.getClock();
+
+    expect(configAcc.maxOrderFeeBps).toBe(DEFAULT_MAX_ORDER_FEE_BPS);
+    expect(configAcc.pendingMaxOrderFee.maxOrderFeeBps).toBe(maxOrderFeeBps);
+    expect(configAcc.pendingMaxOrderFee.effectiveTimestamp.toNumber()).toBe(
+      Number(unixTimestamp) + ORDER_FEE_TIMELOCK_SECS
+    );
+    expect(configAcc.pendingOrderFee.orderFeeBps).toBe(orderFeeBps);
+  });
+
+  test('throws if order fee exceeds maximum', async () => {
+    try {
+      await program.methods
+        .updateConfig({
+          acceptedMints: null,
+          pausedOperations: null,
+          newAdmin: null,
+          orderFeeBps: 2000,
+          maxOrderFeeBps: null,
+          shopperCancelWindowSecs: null,
+          completionDelaySecs: null,
+          shippingDeadlineSecs: null,
+          feeTiers: null,
+        })
+        .accounts({
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidFeeBps');
+    }
   });
 
   test('throws if updating as unauthorized admin', async () => {
     acceptedMints.push({
       mint: USDT_MINT,
-      priceUpdateV2: USDT_PRICE_UPDATE_V2,
+      feedId: USDT_FEED_ID,
+      ...ORACLE_CONFIG,
+      isPaused: false,
     });
-    const isPaused = true;
+    const pausedOperations = PAUSE_ORDERING;
     const orderFeeBps = 500;
 
     try {
       await program.methods
         .updateConfig({
           acceptedMints,
-          isPaused,
+          pausedOperations,
           newAdmin: newAdmin.publicKey,
           orderFeeBps,
+          maxOrderFeeBps: null,
+          shopperCancelWindowSecs: null,
+          completionDelaySecs: null,
+          shippingDeadlineSecs: null,
+          feeTiers: null,
         })
         .accounts({
           admin: newAdmin.publicKey,
@@ -107,4 +274,55 @@ describe('updateConfig', () => {
       expectAnchorError(err, 'UnauthorizedAdmin');
     }
   });
+
+  test('throws if completion delay is out of bounds', async () => {
+    try {
+      await program.methods
+        .updateConfig({
+          acceptedMints: null,
+          pausedOperations: null,
+          newAdmin: null,
+          orderFeeBps: null,
+          maxOrderFeeBps: null,
+          shopperCancelWindowSecs: null,
+          completionDelaySecs: 0,
+          shippingDeadlineSecs: null,
+          feeTiers: null,
+        })
+        .accounts({
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidCompletionDelay');
+    }
+  });
+
+  test('throws if fee tiers are not ascending', async () => {
+    try {
+      await program.methods
+        .updateConfig({
+          acceptedMints: null,
+          pausedOperations: null,
+          newAdmin: null,
+          orderFeeBps: null,
+          maxOrderFeeBps: null,
+          shopperCancelWindowSecs: null,
+          completionDelaySecs: null,
+          shippingDeadlineSecs: null,
+          feeTiers: [
+            { minVolume: new BN(10_000_000_000), orderFeeBps: 150 },
+            { minVolume: new BN(1_000_000_000), orderFeeBps: 200 },
+          ],
+        })
+        .accounts({
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidFeeTiers');
+    }
+  });
 });
@@ -2,10 +2,15 @@ import { beforeEach, describe, expect, test } from 'bun:test';
 import { Keypair } from '@solana/web3.js';
 import { Splurge } from '../../target/types/splurge';
 import { BN, Program } from '@coral-xyz/anchor';
-import { USDC_MINT, USDC_PRICE_UPDATE_V2 } from '../constants';
+import {
+  ORACLE_CONFIG,
+  USDC_FEED_ID,
+  USDC_MINT,
+  USDT_MINT,
+} from '../constants';
 import { LiteSVM } from 'litesvm';
 import { LiteSVMProvider } from 'anchor-litesvm';
-import { fundedSystemAccountInfo, getSetup } from '../setup';
+import { expectAnchorError, fundedSystemAccountInfo, getSetup } from '../setup';
 import { getItemPda, getStorePda } from '../pda';
 import { fetchItemAcc } from '../accounts';
 
@@ -35,7 +40,9 @@ describe('updateItem', () => {
         acceptedMints: [
           {
             mint: USDC_MINT,
-            priceUpdateV2: USDC_PRICE_UPDATE_V2,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
           },
         ],
         admin: admin.publicKey,
@@ -62,6 +69,8 @@ describe('updateItem', () => {
     await program.methods
       .listItem({
         price: new BN(1e6), // $1
+        pricingMode: { oracle: {} },
+        referralBps: 0,
         inventoryCount: 10,
         name: itemName,
         image: 'https://example.com/item.png',
@@ -76,6 +85,7 @@ describe('updateItem', () => {
 
   test('updates an item', async () => {
     const price = 20e6; // $2
+    const referralBps = 500;
     const inventoryCount = 5;
 
     const storePda = getStorePda(storeAuthority.publicKey);
@@ -84,6 +94,8 @@ describe('updateItem', () => {
     await program.methods
       .updateItem({
         price: new BN(price),
+        pricingMode: null,
+        referralBps,
         inventoryCount,
       })
       .accountsPartial({
@@ -97,6 +109,98 @@ describe('updateItem', () => {
     const itemAcc = await fetchItemAcc(program, itemPda);
 
     expect(itemAcc.price.toNumber()).toBe(price);
+    expect(itemAcc.referralBps).toBe(referralBps);
     expect(itemAcc.inventoryCount).toBe(inventoryCount);
   });
+
+  test('sets fixed pricing mode', async () => {
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+
+    await program.methods
+      .updateItem({
+        price: new BN(1e6),
+        pricingMode: { fixed: { priceMint: USDC_MINT } },
+        referralBps: null,
+        inventoryCount: null,
+      })
+      .accountsPartial({
+        authority: storeAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    let itemAcc = await fetchItemAcc(program, itemPda);
+
+    expect(itemAcc.priceMint).toStrictEqual(USDC_MINT);
+
+    await program.methods
+      .updateItem({
+        price: new BN(1e6), // $1
+        pricingMode: { oracle: {} },
+        referralBps: null,
+        inventoryCount: null,
+      })
+      .accountsPartial({
+        authority: storeAuthority.publicKey,
+        store: storePda,
+        item: itemPda,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    itemAcc = await fetchItemAcc(program, itemPda);
+
+    expect(itemAcc.priceMint).toBeNull();
+  });
+
+  test('throws if pricing mode changes without a price', async () => {
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+
+    try {
+      await program.methods
+        .updateItem({
+          price: null,
+          pricingMode: { fixed: { priceMint: USDC_MINT } },
+          referralBps: null,
+          inventoryCount: null,
+        })
+        .accountsPartial({
+          authority: storeAuthority.publicKey,
+          store: storePda,
+          item: itemPda,
+        })
+        .signers([storeAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'PriceRequired');
+    }
+  });
+
+  test('throws if price mint is not accepted', async () => {
+    const storePda = getStorePda(storeAuthority.publicKey);
+    const itemPda = getItemPda(storePda, itemName);
+
+    try {
+      await program.methods
+        .updateItem({
+          price: new BN(1e6),
+          pricingMode: { fixed: { priceMint: USDT_MINT } },
+          referralBps: null,
+          inventoryCount: null,
+        })
+        .accountsPartial({
+          authority: storeAuthority.publicKey,
+          store: storePda,
+          item: itemPda,
+        })
+        .signers([storeAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'PaymentMintNotAccepted');
+    }
+  });
 });
@@ -0,0 +1,124 @@
+import { beforeEach, describe, expect, test } from 'bun:test';
+import { Keypair } from '@solana/web3.js';
+import { Splurge } from '../../target/types/splurge';
+import { Program } from '@coral-xyz/anchor';
+import { fetchConfigAcc } from '../accounts';
+import { LiteSVM } from 'litesvm';
+import { LiteSVMProvider } from 'anchor-litesvm';
+import { expectAnchorError, fundedSystemAccountInfo, getSetup } from '../setup';
+import { ORACLE_CONFIG, USDC_FEED_ID, USDC_MINT } from '../constants';
+import { getConfigPda } from '../pda';
+
+describe('updateRoles', () => {
+  let { litesvm, provider, program } = {} as {
+    litesvm: LiteSVM;
+    provider: LiteSVMProvider;
+    program: Program<Splurge>;
+  };
+
+  const [admin, operator, treasurer, pauser] = Array.from(
+    { length: 4 },
+    Keypair.generate
+  );
+
+  beforeEach(async () => {
+    ({ litesvm, provider, program } = await getSetup([
+      ...[admin, operator, treasurer, pauser].map((kp) => {
+        return {
+          pubkey: kp.publicKey,
+          account: fundedSystemAccountInfo(),
+        };
+      }),
+    ]));
+
+    await program.methods
+      .initializeConfig({
+        acceptedMints: [
+          {
+            mint: USDC_MINT,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+          },
+        ],
+        admin: admin.publicKey,
+        orderFeeBps: 250,
+      })
+      .accounts({
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+  });
+
+  test('initializes roles to admin', async () => {
+    const configPda = getConfigPda();
+    const configAcc = await fetchConfigAcc(program, configPda);
+
+    expect(configAcc.operator).toStrictEqual(admin.publicKey);
+    expect(configAcc.treasurer).toStrictEqual(admin.publicKey);
+    expect(configAcc.pauser).toStrictEqual(admin.publicKey);
+  });
+
+  test('updates roles', async () => {
+    await program.methods
+      .updateRoles({
+        operator: operator.publicKey,
+        treasurer: treasurer.publicKey,
+        pauser: pauser.publicKey,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const configPda = getConfigPda();
+    const configAcc = await fetchConfigAcc(program, configPda);
+
+    expect(configAcc.admin).toStrictEqual(admin.publicKey);
+    expect(configAcc.operator).toStrictEqual(operator.publicKey);
+    expect(configAcc.treasurer).toStrictEqual(treasurer.publicKey);
+    expect(configAcc.pauser).toStrictEqual(pauser.publicKey);
+  });
+
+  test('leaves unset roles unchanged', async () => {
+    await program.methods
+      .updateRoles({
+        operator: operator.publicKey,
+        treasurer: null,
+        pauser: null,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const configPda = getConfigPda();
+    const configAcc = await fetchConfigAcc(program, configPda);
+
+    expect(configAcc.operator).toStrictEqual(operator.publicKey);
+    expect(configAcc.treasurer).toStrictEqual(admin.publicKey);
+    expect(configAcc.pauser).toStrictEqual(admin.publicKey);
+  });
+
+  test('throws if updating roles as unauthorized admin', async () => {
+    try {
+      await program.methods
+        .updateRoles({
+          operator: operator.publicKey,
+          treasurer: null,
+          pauser: null,
+        })
+        .accounts({
+          admin: operator.publicKey,
+        })
+        .signers([operator])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'UnauthorizedAdmin');
+    }
+  });
+});
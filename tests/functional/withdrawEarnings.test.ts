@@ -8,6 +8,7 @@ import {
 import { Splurge } from '../../target/types/splurge';
 import { BN, Program } from '@coral-xyz/anchor';
 import {
+  getConfigPda,
   getItemPda,
   getOrderPda,
   getShopperPda,
@@ -24,9 +25,13 @@ import {
   initTaskQueue,
 } from '../setup';
 import {
+  ORACLE_CONFIG,
   TUKTUK_PROGRAM_ID,
+  USDC_FEED_ID,
   USDC_MINT,
   USDC_PRICE_UPDATE_V2,
+  USDT_FEED_ID,
+  USDT_MINT,
 } from '../constants';
 import { Tuktuk } from '@helium/tuktuk-idls/lib/types/tuktuk.js';
 import {
@@ -89,7 +94,9 @@ describe('withdrawEarnings', () => {
         acceptedMints: [
           {
             mint: USDC_MINT,
-            priceUpdateV2: USDC_PRICE_UPDATE_V2,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
           },
         ],
         admin: admin.publicKey,
@@ -128,6 +135,8 @@ describe('withdrawEarnings', () => {
     await program.methods
       .listItem({
         price: new BN(itemPrice),
+        pricingMode: { oracle: {} },
+        referralBps: 0,
         inventoryCount: initInventoryCount,
         name: itemName,
         image: 'https://example.com/item.png',
@@ -146,7 +155,7 @@ describe('withdrawEarnings', () => {
     orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
 
     await program.methods
-      .createOrder(1, new BN(unixTimestamp))
+      .createOrder(1, new BN(unixTimestamp), new BN(initShopperAtaBal), null)
       .accountsPartial({
         authority: shopperAuthority.publicKey,
         store: storePda,
@@ -167,13 +176,13 @@ describe('withdrawEarnings', () => {
     const [taskPda] = taskKey(taskQueuePda, taskId);
     const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
       taskQueuePda,
-      admin.publicKey
+      getConfigPda()
     );
 
     await program.methods
       .shipOrder(taskId)
       .accountsPartial({
-        admin: admin.publicKey,
+        shipper: admin.publicKey,
         order: orderPda,
         authority: shopperAuthority.publicKey,
         item: itemPda,
@@ -193,7 +202,7 @@ describe('withdrawEarnings', () => {
     await program.methods
       .completeOrder()
       .accountsPartial({
-        admin: admin.publicKey,
+        operator: admin.publicKey,
         shopper: shopperPda,
         store: storePda,
         item: itemPda,
@@ -215,24 +224,27 @@ describe('withdrawEarnings', () => {
       provider.connection,
       storeUsdcAta
     );
+    const storeAuthorityUsdcAta = getAssociatedTokenAddressSync(
+      USDC_MINT,
+      storeAuthority.publicKey,
+      false,
+      tokenProgram
+    );
+
+    initAta(litesvm, USDC_MINT, storeAuthority.publicKey, 0);
 
     await program.methods
-      .withdrawEarnings()
+      .withdrawEarnings(null)
       .accountsPartial({
         authority: storeAuthority.publicKey,
         store: storePda,
         paymentMint: USDC_MINT,
+        destinationTokenAccount: storeAuthorityUsdcAta,
         tokenProgram,
       })
       .signers([storeAuthority])
       .rpc();
 
-    const storeAuthorityUsdcAta = getAssociatedTokenAddressSync(
-      USDC_MINT,
-      storeAuthority.publicKey,
-      false,
-      tokenProgram
-    );
     const storeAuthorityUsdcAtaAcc = await getAccount(
       provider.connection,
       storeAuthorityUsdcAta
@@ -251,14 +263,175 @@ describe('withdrawEarnings', () => {
     expect(postStoreUsdcAtaAcc.amount).toBe(0n);
   });
 
+  test('withdraw partial earnings to another destination', async () => {
+    const vault = Keypair.generate().publicKey;
+    const amount = 100_000;
+    const storeUsdcAta = getAssociatedTokenAddressSync(
+      USDC_MINT,
+      storePda,
+      true,
+      tokenProgram
+    );
+    const preStoreUsdcAtaAcc = await getAccount(
+      provider.connection,
+      storeUsdcAta
+    );
+    const vaultUsdcAta = getAssociatedTokenAddressSync(
+      USDC_MINT,
+      vault,
+      false,
+      tokenProgram
+    );
+
+    initAta(litesvm, USDC_MINT, vault, 0);
+
+    await program.methods
+      .withdrawEarnings(new BN(amount))
+      .accountsPartial({
+        authority: storeAuthority.publicKey,
+        store: storePda,
+        paymentMint: USDC_MINT,
+        destinationTokenAccount: vaultUsdcAta,
+        tokenProgram,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    const vaultUsdcAtaAcc = await getAccount(
+      provider.connection,
+      vaultUsdcAta
+    );
+    const postStoreUsdcAtaAcc = await getAccount(
+      provider.connection,
+      storeUsdcAta
+    );
+
+    expect(vaultUsdcAtaAcc.amount).toBe(BigInt(amount));
+    expect(postStoreUsdcAtaAcc.amount).toBe(
+      preStoreUsdcAtaAcc.amount - BigInt(amount)
+    );
+  });
+
+  test('withdraw earnings in a delisted mint', async () => {
+    await program.methods
+      .updateConfig({
+        acceptedMints: [
+          {
+            mint: USDT_MINT,
+            feedId: USDT_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
+          },
+        ],
+        pausedOperations: null,
+        newAdmin: null,
+        orderFeeBps: null,
+        maxOrderFeeBps: null,
+        shopperCancelWindowSecs: null,
+        completionDelaySecs: null,
+        shippingDeadlineSecs: null,
+        feeTiers: null,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const storeUsdcAta = getAssociatedTokenAddressSync(
+      USDC_MINT,
+      storePda,
+      true,
+      tokenProgram
+    );
+    const preStoreUsdcAtaAcc = await getAccount(
+      provider.connection,
+      storeUsdcAta
+    );
+    const storeAuthorityUsdcAta = getAssociatedTokenAddressSync(
+      USDC_MINT,
+      storeAuthority.publicKey,
+      false,
+      tokenProgram
+    );
+
+    initAta(litesvm, USDC_MINT, storeAuthority.publicKey, 0);
+
+    await program.methods
+      .withdrawEarnings(null)
+      .accountsPartial({
+        authority: storeAuthority.publicKey,
+        store: storePda,
+        paymentMint: USDC_MINT,
+        destinationTokenAccount: storeAuthorityUsdcAta,
+        tokenProgram,
+      })
+      .signers([storeAuthority])
+      .rpc();
+
+    const storeAuthorityUsdcAtaAcc = await getAccount(
+      provider.connection,
+      storeAuthorityUsdcAta
+    );
+
+    expect(storeAuthorityUsdcAtaAcc.amount).toBe(preStoreUsdcAtaAcc.amount);
+  });
+
+  test('throws if withdrawal amount exceeds balance', async () => {
+    const storeUsdcAta = getAssociatedTokenAddressSync(
+      USDC_MINT,
+      storePda,
+      true,
+      tokenProgram
+    );
+    const storeUsdcAtaAcc = await getAccount(
+      provider.connection,
+      storeUsdcAta
+    );
+    const storeAuthorityUsdcAta = getAssociatedTokenAddressSync(
+      USDC_MINT,
+      storeAuthority.publicKey,
+      false,
+      tokenProgram
+    );
+
+    initAta(litesvm, USDC_MINT, storeAuthority.publicKey, 0);
+
+    try {
+      await program.methods
+        .withdrawEarnings(new BN((storeUsdcAtaAcc.amount + 1n).toString()))
+        .accountsPartial({
+          authority: storeAuthority.publicKey,
+          store: storePda,
+          paymentMint: USDC_MINT,
+          destinationTokenAccount: storeAuthorityUsdcAta,
+          tokenProgram,
+        })
+        .signers([storeAuthority])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidWithdrawalAmount');
+    }
+  });
+
   test('throws if withdrawing as unauthorized store authority', async () => {
+    const storeAuthorityUsdcAta = getAssociatedTokenAddressSync(
+      USDC_MINT,
+      storeAuthority.publicKey,
+      false,
+      tokenProgram
+    );
+
+    initAta(litesvm, USDC_MINT, storeAuthority.publicKey, 0);
+
     try {
       await program.methods
-        .withdrawEarnings()
+        .withdrawEarnings(null)
         .accountsPartial({
           authority: storeAuthority.publicKey,
           store: storePda,
           paymentMint: USDC_MINT,
+          destinationTokenAccount: storeAuthorityUsdcAta,
           tokenProgram,
         })
         .signers([storeAuthority])
@@ -8,6 +8,7 @@ import {
 import { Splurge } from '../../target/types/splurge';
 import { BN, Program } from '@coral-xyz/anchor';
 import {
+  getConfigPda,
   getItemPda,
   getOrderPda,
   getShopperPda,
@@ -18,7 +19,9 @@ import { fetchTaskQueueAcc } from '../accounts';
 import { LiteSVM } from 'litesvm';
 import { LiteSVMProvider } from 'anchor-litesvm';
 import {
+  ORACLE_CONFIG,
   TUKTUK_PROGRAM_ID,
+  USDC_FEED_ID,
   USDC_MINT,
   USDC_PRICE_UPDATE_V2,
 } from '../constants';
@@ -89,7 +92,9 @@ describe('withdrawTreasury', () => {
         acceptedMints: [
           {
             mint: USDC_MINT,
-            priceUpdateV2: USDC_PRICE_UPDATE_V2,
+            feedId: USDC_FEED_ID,
+            ...ORACLE_CONFIG,
+            isPaused: false,
           },
         ],
         admin: admin.publicKey,
@@ -128,6 +133,8 @@ describe('withdrawTreasury', () => {
     await program.methods
       .listItem({
         price: new BN(itemPrice),
+        pricingMode: { oracle: {} },
+        referralBps: 0,
         inventoryCount: initInventoryCount,
         name: itemName,
         image: 'https://example.com/item.png',
@@ -146,7 +153,7 @@ describe('withdrawTreasury', () => {
     orderPda = getOrderPda(shopperPda, itemPda, new BN(unixTimestamp));
 
     await program.methods
-      .createOrder(1, new BN(unixTimestamp))
+      .createOrder(1, new BN(unixTimestamp), new BN(initShopperAtaBal), null)
       .accountsPartial({
         authority: shopperAuthority.publicKey,
         store: storePda,
@@ -167,13 +174,13 @@ describe('withdrawTreasury', () => {
     const [taskPda] = taskKey(taskQueuePda, taskId);
     const [taskQueueAuthorityPda] = taskQueueAuthorityKey(
       taskQueuePda,
-      admin.publicKey
+      getConfigPda()
     );
 
     await program.methods
       .shipOrder(taskId)
       .accountsPartial({
-        admin: admin.publicKey,
+        shipper: admin.publicKey,
         order: orderPda,
         authority: shopperAuthority.publicKey,
         item: itemPda,
@@ -193,7 +200,7 @@ describe('withdrawTreasury', () => {
     await program.methods
       .completeOrder()
       .accountsPartial({
-        admin: admin.publicKey,
+        operator: admin.publicKey,
         shopper: shopperPda,
         store: storePda,
         item: itemPda,
@@ -215,12 +222,66 @@ describe('withdrawTreasury', () => {
       provider.connection,
       treasuryAta
     );
+    const adminAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      admin.publicKey,
+      !PublicKey.isOnCurve(admin.publicKey),
+      tokenProgram
+    );
+
+    initAta(litesvm, paymentMint, admin.publicKey, 0);
 
     await program.methods
-      .withdrawTreasury()
+      .withdrawTreasury(null)
       .accountsPartial({
-        admin: admin.publicKey,
+        treasurer: admin.publicKey,
+        paymentMint,
+        destinationTokenAccount: adminAta,
+        tokenProgram,
+      })
+      .signers([admin])
+      .rpc();
+
+    const postTreasuryAtaAcc = await getAccount(
+      provider.connection,
+      treasuryAta
+    );
+    const adminAtaAcc = await getAccount(provider.connection, adminAta);
+
+    expect(postTreasuryAtaAcc.amount).toBe(0n);
+    expect(preTreasuryAtaAcc.amount).toBe(
+      postTreasuryAtaAcc.amount + adminAtaAcc.amount
+    );
+  });
+
+  test('withdraw partial amount to another destination', async () => {
+    const vault = Keypair.generate().publicKey;
+    const amount = 1_000;
+    const treasuryAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      treasury,
+      !PublicKey.isOnCurve(treasury),
+      tokenProgram
+    );
+    const preTreasuryAtaAcc = await getAccount(
+      provider.connection,
+      treasuryAta
+    );
+    const vaultAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      vault,
+      false,
+      tokenProgram
+    );
+
+    initAta(litesvm, paymentMint, vault, 0);
+
+    await program.methods
+      .withdrawTreasury(new BN(amount))
+      .accountsPartial({
+        treasurer: admin.publicKey,
         paymentMint,
+        destinationTokenAccount: vaultAta,
         tokenProgram,
       })
       .signers([admin])
@@ -230,33 +291,63 @@ describe('withdrawTreasury', () => {
       provider.connection,
       treasuryAta
     );
+    const vaultAtaAcc = await getAccount(provider.connection, vaultAta);
+
+    expect(vaultAtaAcc.amount).toBe(BigInt(amount));
+    expect(postTreasuryAtaAcc.amount).toBe(
+      preTreasuryAtaAcc.amount - BigInt(amount)
+    );
+  });
 
+  test('throws if withdrawal amount is zero', async () => {
     const adminAta = getAssociatedTokenAddressSync(
       paymentMint,
       admin.publicKey,
       !PublicKey.isOnCurve(admin.publicKey),
       tokenProgram
     );
-    const adminAtaAcc = await getAccount(provider.connection, adminAta);
 
-    expect(preTreasuryAtaAcc.amount).toBe(
-      postTreasuryAtaAcc.amount + adminAtaAcc.amount
-    );
+    initAta(litesvm, paymentMint, admin.publicKey, 0);
+
+    try {
+      await program.methods
+        .withdrawTreasury(new BN(0))
+        .accountsPartial({
+          treasurer: admin.publicKey,
+          paymentMint,
+          destinationTokenAccount: adminAta,
+          tokenProgram,
+        })
+        .signers([admin])
+        .rpc();
+    } catch (err) {
+      expectAnchorError(err, 'InvalidWithdrawalAmount');
+    }
   });
 
-  test('throws if withdrawing as unauthorized admin', async () => {
+  test('throws if withdrawing as unauthorized treasurer', async () => {
+    const storeAuthorityAta = getAssociatedTokenAddressSync(
+      paymentMint,
+      storeAuthority.publicKey,
+      false,
+      tokenProgram
+    );
+
+    initAta(litesvm, paymentMint, storeAuthority.publicKey, 0);
+
     try {
       await program.methods
-        .withdrawTreasury()
+        .withdrawTreasury(null)
         .accountsPartial({
-          admin: storeAuthority.publicKey,
+          treasurer: storeAuthority.publicKey,
           paymentMint,
+          destinationTokenAccount: storeAuthorityAta,
           tokenProgram,
         })
         .signers([storeAuthority])
         .rpc();
     } catch (err) {
-      expectAnchorError(err, 'UnauthorizedAdmin');
+      expectAnchorError(err, 'UnauthorizedTreasurer');
     }
   });
 });
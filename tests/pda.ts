@@ -53,6 +53,17 @@ export function getOrderPda(
   )[0];
 }
 
+export function getCartOrderPda(shopperPda: PublicKey, timestamp: BN) {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('cart_order'),
+      shopperPda.toBuffer(),
+      timestamp.toArrayLike(Buffer, 'le', 8),
+    ],
+    SPLURGE_PROGRAM_ID
+  )[0];
+}
+
 export function getReviewPda(orderPda: PublicKey) {
   return PublicKey.findProgramAddressSync(
     [Buffer.from('review'), orderPda.toBuffer()],
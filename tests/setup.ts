@@ -27,11 +27,13 @@ import {
 import { expect } from 'bun:test';
 import usdcPriceUpdateV2AccInfo from './fixtures/usdc_price_update_v2.json';
 import usdtPriceUpdateV2AccInfo from './fixtures/usdt_price_update_v2.json';
+import usdcPartialPriceUpdateV2AccInfo from './fixtures/usdc_partial_price_update_v2.json';
 import tuktukIdl from './fixtures/tuktuk.json';
 import { Tuktuk } from '@helium/tuktuk-idls/lib/types/tuktuk.js';
 import tuktukConfigV0 from './fixtures/tuktuk_config_v0.json';
 import { taskQueueKey, taskQueueNameMappingKey } from '@helium/tuktuk-sdk';
 import { fetchConfigV0Acc } from './accounts';
+import { getConfigPda } from './pda';
 
 export async function getSetup(
   accounts: { pubkey: PublicKey; account: AccountInfoBytes }[] = []
@@ -48,6 +50,7 @@ export async function getSetup(
   initMint(litesvm, USDT_MINT);
   initDataAcc(litesvm, usdcPriceUpdateV2AccInfo);
   initDataAcc(litesvm, usdtPriceUpdateV2AccInfo);
+  initDataAcc(litesvm, usdcPartialPriceUpdateV2AccInfo);
 
   for (const { pubkey, account } of accounts) {
     litesvm.setAccount(new PublicKey(pubkey), {
@@ -207,7 +210,7 @@ export async function initTaskQueue(
     .accountsPartial({
       payer: payer.publicKey,
       updateAuthority: payer.publicKey,
-      queueAuthority: payer.publicKey,
+      queueAuthority: getConfigPda(),
       taskQueue: taskQueuePda,
     })
     .signers([payer])